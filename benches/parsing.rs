@@ -0,0 +1,54 @@
+//! Parsing benchmarks on a synthetic GGUF file with many metadata keys.
+//!
+//! Run with `cargo bench --bench parsing --features test-util`; reports are
+//! written to `target/criterion/`. The input is generated in memory by
+//! `inspector_gguf::format::make_test_gguf`, so no model file is needed.
+
+use std::hint::black_box;
+
+use candle::quantized::gguf_file::Value;
+use criterion::{criterion_group, criterion_main, Criterion};
+use inspector_gguf::format::{load_gguf_metadata_from_bytes, load_gguf_metadata_sync, make_test_gguf, GgufReader};
+
+/// Number of metadata keys in the synthetic file.
+const KEY_COUNT: usize = 10_000;
+
+/// Builds a GGUF buffer mixing the value types of real models: numbers, strings and token lists.
+fn synthetic_gguf() -> Vec<u8> {
+    let keys: Vec<String> = (0..KEY_COUNT).map(|i| format!("bench.block.{}.value", i)).collect();
+    let mut metadata: Vec<(&str, Value)> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let value = match i % 3 {
+                0 => Value::U32(i as u32),
+                1 => Value::F32(i as f32 / 7.0),
+                _ => Value::String(format!("value number {}", i)),
+            };
+            (key.as_str(), value)
+        })
+        .collect();
+    let tokens = (0..32_000).map(|i| Value::String(format!("<tok{}>", i))).collect();
+    metadata.push(("tokenizer.ggml.tokens", Value::Array(tokens)));
+    make_test_gguf(&metadata)
+}
+
+fn bench_parsing(c: &mut Criterion) {
+    let buf = synthetic_gguf();
+    let dir = tempfile::tempdir().expect("Should create temp dir");
+    let path = dir.path().join("synthetic.gguf");
+    std::fs::write(&path, &buf).expect("Should write synthetic GGUF");
+
+    c.bench_function("load_gguf_metadata_sync", |b| {
+        b.iter(|| load_gguf_metadata_sync(black_box(&path)).expect("Synthetic GGUF should load"))
+    });
+    c.bench_function("load_gguf_metadata_from_bytes", |b| {
+        b.iter(|| load_gguf_metadata_from_bytes(black_box(&buf)).expect("Synthetic GGUF should load"))
+    });
+    c.bench_function("GgufReader::open", |b| {
+        b.iter(|| GgufReader::open(black_box(&path)).expect("Synthetic GGUF should open"))
+    });
+}
+
+criterion_group!(benches, bench_parsing);
+criterion_main!(benches);
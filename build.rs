@@ -1,8 +1,62 @@
 #[cfg(target_os = "windows")]
 extern crate winres;
 
+/// Asks cargo to rerun this script when the checked-out commit changes.
+///
+/// Watches `.git/HEAD` and, on a branch, the ref file it points to. Once any
+/// `rerun-if-changed` is printed cargo stops watching the whole package, so
+/// `build.rs` itself is listed too.
+fn emit_git_rerun_triggers() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "--git-dir"])
+        .output();
+    let Ok(output) = output else { return };
+    if !output.status.success() {
+        return;
+    }
+    let git_dir = std::path::PathBuf::from(String::from_utf8_lossy(&output.stdout).trim());
+    let head = git_dir.join("HEAD");
+    println!("cargo:rerun-if-changed={}", head.display());
+
+    // Missing paths would make cargo rerun on every build, e.g. for packed refs
+    if let Ok(contents) = std::fs::read_to_string(&head)
+        && let Some(reference) = contents.trim().strip_prefix("ref: ")
+    {
+        let ref_file = git_dir.join(reference);
+        if ref_file.exists() {
+            println!("cargo:rerun-if-changed={}", ref_file.display());
+        }
+        let packed = git_dir.join("packed-refs");
+        if packed.exists() {
+            println!("cargo:rerun-if-changed={}", packed.display());
+        }
+    }
+}
+
+/// Exposes the short git commit hash as `INSPECTOR_GGUF_GIT_HASH` when available.
+fn emit_git_hash() {
+    emit_git_rerun_triggers();
+
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output();
+    if let Ok(output) = output
+        && output.status.success()
+    {
+        let hash = String::from_utf8_lossy(&output.stdout);
+        let hash = hash.trim();
+        if !hash.is_empty() {
+            println!("cargo:rustc-env=INSPECTOR_GGUF_GIT_HASH={}", hash);
+        }
+    }
+}
+
 #[cfg(target_os = "windows")]
 fn main() {
+    emit_git_hash();
+
     let mut res = winres::WindowsResource::new();
     res.set("FileDescription", "Inspector GGUF");
     res.set("ProductName", "Inspector GGUF");
@@ -10,6 +64,7 @@ fn main() {
     res.set("FileVersion", env!("CARGO_PKG_VERSION"));
     res.set("ProductVersion", env!("CARGO_PKG_VERSION"));
     res.set_icon("assets/icons/icon_new.ico");
+    println!("cargo:rerun-if-changed=assets/icons/icon_new.ico");
 
     // Устанавливаем Windows subsystem для GUI приложения (без консольного окна)
     res.set("Subsystem", "WINDOWS");
@@ -21,5 +76,6 @@ fn main() {
 
 #[cfg(not(target_os = "windows"))]
 fn main() {
-    // На других платформах ничего не делаем
+    // На других платформах ресурсы не нужны, только хеш коммита
+    emit_git_hash();
 }
@@ -0,0 +1,762 @@
+//! Command-line error reporting and input helpers.
+//!
+//! The `gguf-inspector` binary routes every failure through [`CliError`] so that
+//! scripts can rely on a stable shape: with `--json-errors` the error is printed
+//! to stderr as a single JSON object `{"error": "...", "kind": "..."}`, otherwise
+//! as a plain `Error: ...` line. Either way the process exits with a nonzero code.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use inspector_gguf::cli::CliError;
+//! use inspector_gguf::format::GgufError;
+//!
+//! let err = CliError::from(GgufError::Empty);
+//! assert_eq!(err.kind(), "format");
+//! assert_eq!(err.to_json()["error"], "File is empty");
+//! ```
+
+use crate::format::{check_gguf_size, diff_metadata, gguf_files_in, has_extension, load_gguf_metadata_from_bytes_checked, load_gguf_metadata_sync, CountMismatch, GgufError, MetadataDiff};
+use crate::gui::export::{export_json_value, export_sqlite, filter_keys, key_matches, ExportOptions};
+use std::collections::HashSet;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Errors surfaced by the command-line interface.
+#[derive(Debug, Error)]
+pub enum CliError {
+    /// Invalid combination of command-line arguments
+    #[error("{0}")]
+    Usage(String),
+
+    /// Input/output error while reading models or writing exports
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+
+    /// Empty, truncated, or otherwise malformed GGUF header
+    #[error("{0}")]
+    Format(#[from] GgufError),
+
+    /// Failure while parsing GGUF content
+    #[error("{0}")]
+    Parse(String),
+
+    /// Metadata differs from the reference given with `--compare-ref`, or keys
+    /// requested with `--metadata-key --strict` are absent
+    #[error("{0}")]
+    Mismatch(String),
+
+    /// Any other failure
+    #[error("{0}")]
+    Other(String),
+}
+
+impl CliError {
+    /// Returns the stable machine-readable error category.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            CliError::Usage(_) => "usage",
+            CliError::Io(_) => "io",
+            CliError::Format(_) => "format",
+            CliError::Parse(_) => "parse",
+            CliError::Mismatch(_) => "mismatch",
+            CliError::Other(_) => "other",
+        }
+    }
+
+    /// Returns the `{"error": "...", "kind": "..."}` object printed by `--json-errors`.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({ "error": self.to_string(), "kind": self.kind() })
+    }
+
+    /// Formats the error as printed on stderr.
+    ///
+    /// `json` selects the single-line JSON object over the human-readable form.
+    pub fn render(&self, json: bool) -> String {
+        if json {
+            self.to_json().to_string()
+        } else {
+            format!("Error: {}", self)
+        }
+    }
+}
+
+impl From<candle::Error> for CliError {
+    fn from(e: candle::Error) -> Self {
+        CliError::Parse(e.to_string())
+    }
+}
+
+impl From<serde_json::Error> for CliError {
+    fn from(e: serde_json::Error) -> Self {
+        CliError::Other(e.to_string())
+    }
+}
+
+impl From<serde_yaml::Error> for CliError {
+    fn from(e: serde_yaml::Error) -> Self {
+        CliError::Parse(e.to_string())
+    }
+}
+
+impl From<&str> for CliError {
+    fn from(message: &str) -> Self {
+        CliError::Other(message.to_string())
+    }
+}
+
+impl From<String> for CliError {
+    fn from(message: String) -> Self {
+        CliError::Other(message)
+    }
+}
+
+/// Classifies errors returned by the library's `Box<dyn Error>` APIs.
+impl From<Box<dyn std::error::Error>> for CliError {
+    fn from(e: Box<dyn std::error::Error>) -> Self {
+        let e = match e.downcast::<GgufError>() {
+            Ok(format) => return CliError::Format(*format),
+            Err(e) => e,
+        };
+        let e = match e.downcast::<std::io::Error>() {
+            Ok(io) => return CliError::Io(*io),
+            Err(e) => e,
+        };
+        match e.downcast::<candle::Error>() {
+            Ok(parse) => CliError::Parse(parse.to_string()),
+            Err(e) => CliError::Other(e.to_string()),
+        }
+    }
+}
+
+/// Classifies errors returned by the `Send + Sync` loaders in [`crate::format`].
+impl From<crate::format::BoxError> for CliError {
+    fn from(e: crate::format::BoxError) -> Self {
+        let e: Box<dyn std::error::Error> = e;
+        CliError::from(e)
+    }
+}
+
+/// Reads a complete GGUF file from `reader` and builds the JSON export document.
+///
+/// Used by `--stdin`: pipes cannot seek, so the whole input is buffered before it
+/// is handed to [`load_gguf_metadata_from_bytes_checked`]. The document has the same
+/// shape as the file-based export (see [`export_json_value`]); it is returned with
+/// the header count mismatch, if any, for the caller to report.
+///
+/// # Errors
+///
+/// Returns [`CliError::Io`] if reading fails, [`CliError::Format`] for empty or
+/// truncated input, and [`CliError::Parse`] if the GGUF content is malformed.
+pub fn export_json_from_reader<R: Read>(mut reader: R) -> Result<(serde_json::Value, Option<CountMismatch>), CliError> {
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf)?;
+    let (metadata, mismatch) = load_gguf_metadata_from_bytes_checked(&buf)?;
+    let metadata_refs: Vec<_> = metadata.iter().map(|(k, v, _)| (k, v)).collect();
+    Ok((export_json_value(&metadata_refs), mismatch))
+}
+
+/// Returns whether a CLI input is an `http://` or `https://` URL rather than a path.
+pub fn is_http_url(input: &str) -> bool {
+    input.starts_with("http://") || input.starts_with("https://")
+}
+
+/// Default export path for a URL input: the file name from the URL's last path
+/// segment with `.metadata.json` appended, in the current directory.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::cli::url_output_path;
+/// use std::path::PathBuf;
+///
+/// assert_eq!(
+///     url_output_path("https://cdn.example.com/qwen/model.gguf?download=true"),
+///     PathBuf::from("model.gguf.metadata.json")
+/// );
+/// ```
+pub fn url_output_path(url: &str) -> PathBuf {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    let name = path.rsplit('/').next().filter(|name| !name.is_empty()).unwrap_or("model");
+    Path::new(name).with_extension("gguf.metadata.json")
+}
+
+/// Returns the sorted metadata keys of the GGUF file at `path` (`--keys`).
+///
+/// Values are neither formatted nor copied, so this is much faster than a full
+/// export on files with large tokenizer arrays.
+///
+/// # Errors
+///
+/// Returns [`CliError::Io`] if the file cannot be read, [`CliError::Format`] if
+/// it is empty or too large, and [`CliError::Parse`] for malformed GGUF content.
+pub fn metadata_keys(path: &Path) -> Result<Vec<String>, CliError> {
+    let file = std::fs::File::open(path)?;
+    check_gguf_size(file.metadata()?.len())?;
+    let mut reader = std::io::BufReader::new(file);
+    let content = candle::quantized::gguf_file::Content::read(&mut reader)?;
+    let mut keys: Vec<String> = content.metadata.keys().cloned().collect();
+    keys.sort();
+    Ok(keys)
+}
+
+/// Formats a key list as printed by `--keys`: one key per line, or a JSON array
+/// when `json` is set.
+pub fn render_key_list(keys: &[String], json: bool) -> String {
+    if json {
+        serde_json::Value::from(keys).to_string()
+    } else {
+        keys.join("\n")
+    }
+}
+
+/// Keeps only the entries requested with `--metadata-key`.
+///
+/// Requested keys go through [`filter_keys`], so `*` wildcards work as in the GUI
+/// export patterns; the original metadata order is preserved. Requested keys that
+/// match no entry are returned as the second element.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::cli::select_metadata_keys;
+///
+/// let metadata = vec![
+///     ("general.architecture".to_string(), "qwen3".to_string()),
+///     ("general.name".to_string(), "Qwen3".to_string()),
+/// ];
+/// let metadata_refs: Vec<(&String, &String)> = metadata.iter().map(|(k, v)| (k, v)).collect();
+///
+/// let keys = vec!["general.name".to_string(), "general.license".to_string()];
+/// let (selected, missing) = select_metadata_keys(&metadata_refs, &keys);
+/// assert_eq!(selected.len(), 1);
+/// assert_eq!(missing, ["general.license"]);
+/// ```
+pub fn select_metadata_keys<'a>(
+    entries: &[(&'a String, &'a String)],
+    keys: &[String],
+) -> (Vec<(&'a String, &'a String)>, Vec<String>) {
+    let options = ExportOptions { include_keys: Some(keys.to_vec()), ..Default::default() };
+    let selected = filter_keys(entries, &options);
+    let missing = keys
+        .iter()
+        .filter(|key| !selected.iter().any(|(k, _)| key_matches(key, k)))
+        .cloned()
+        .collect();
+    (selected, missing)
+}
+
+/// Reports keys requested with `--metadata-key` that are absent from the file.
+///
+/// Each key is printed to stderr as a warning.
+///
+/// # Errors
+///
+/// With `strict` (`--strict`), returns [`CliError::Mismatch`] when any key is missing.
+pub fn report_missing_keys(missing: &[String], strict: bool) -> Result<(), CliError> {
+    for key in missing {
+        eprintln!("Warning: metadata key not found: {}", key);
+    }
+    if strict && !missing.is_empty() {
+        return Err(CliError::Mismatch(format!("{} requested metadata key(s) not found", missing.len())));
+    }
+    Ok(())
+}
+
+/// How the directory scanners (`--metadata-dir` and the default GGUF check) walk a directory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScanOptions {
+    /// Descend into subdirectories (`--recursive`).
+    pub recursive: bool,
+    /// Descend into symlinked subdirectories too (`--follow-symlinks`); only used with `recursive`.
+    pub follow_symlinks: bool,
+}
+
+/// Lists the files under `dir` whose extension is one of `extensions` (case-insensitive).
+///
+/// Paths are canonicalized, so a file reached through several symlinks or
+/// relative entries is listed once, and the result is sorted. Symlinked files
+/// are always included; symlinked directories are only entered with
+/// [`ScanOptions::follow_symlinks`]. Every directory is visited at most once,
+/// so symlink cycles terminate. Unreadable subdirectories and broken links are
+/// skipped.
+///
+/// # Errors
+///
+/// Returns an error if `dir` cannot be canonicalized or listed.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::cli::{find_files, ScanOptions};
+///
+/// let dir = tempfile::tempdir()?;
+/// std::fs::create_dir(dir.path().join("nested"))?;
+/// std::fs::write(dir.path().join("nested/model.gguf"), b"")?;
+///
+/// assert!(find_files(dir.path(), &["gguf"], ScanOptions::default())?.is_empty());
+/// let options = ScanOptions { recursive: true, ..Default::default() };
+/// assert_eq!(find_files(dir.path(), &["gguf"], options)?.len(), 1);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn find_files(dir: &Path, extensions: &[&str], options: ScanOptions) -> std::io::Result<Vec<PathBuf>> {
+    let root = dir.canonicalize()?;
+    let mut visited = HashSet::from([root.clone()]);
+    let mut pending = vec![root];
+    let mut files = HashSet::new();
+    let mut is_root = true;
+
+    while let Some(dir) = pending.pop() {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if is_root => return Err(e),
+            Err(_) => continue,
+        };
+        is_root = false;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(file_type) = entry.file_type() else { continue };
+            // Follows the link; broken links have no target and are skipped
+            let Ok(target) = std::fs::metadata(&path) else { continue };
+            if target.is_dir() {
+                if options.recursive && (!file_type.is_symlink() || options.follow_symlinks)
+                    && let Ok(canonical) = path.canonicalize()
+                    && visited.insert(canonical.clone())
+                {
+                    pending.push(canonical);
+                }
+            } else if target.is_file()
+                && has_extension(&path, extensions)
+                && let Ok(canonical) = path.canonicalize()
+            {
+                files.insert(canonical);
+            }
+        }
+    }
+
+    let mut files: Vec<PathBuf> = files.into_iter().collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Outcome of checking one YAML metadata file with [`validate_metadata_dir`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YamlValidationResult {
+    /// Path of the checked file.
+    pub path: PathBuf,
+    /// Number of top-level keys (`0` when the file could not be checked).
+    pub key_count: usize,
+    /// Whether a top-level key mentions `tokenizer`.
+    pub has_tokenizer: bool,
+    /// Whether a top-level key mentions `config`.
+    pub has_config: bool,
+    /// Why the file could not be checked: unreadable, invalid YAML, or not a mapping.
+    pub error: Option<String>,
+}
+
+impl YamlValidationResult {
+    fn failed(path: PathBuf, error: String) -> Self {
+        Self {
+            path,
+            key_count: 0,
+            has_tokenizer: false,
+            has_config: false,
+            error: Some(error),
+        }
+    }
+}
+
+/// Checks every `.yaml`/`.yml` file directly inside `dir` (`--metadata-dir`).
+///
+/// Each file must hold a YAML mapping; its top-level keys are counted and
+/// searched for tokenizer and config entries. Problems with a single file are
+/// recorded in [`YamlValidationResult::error`] rather than aborting the scan.
+/// Results are sorted by path.
+///
+/// # Errors
+///
+/// Returns an error if `dir` is not a directory or cannot be listed.
+pub fn validate_metadata_dir(dir: &Path) -> Result<Vec<YamlValidationResult>, Box<dyn std::error::Error>> {
+    validate_metadata_dir_with_options(dir, ScanOptions::default())
+}
+
+/// Checks YAML metadata files like [`validate_metadata_dir`], walking `dir` with
+/// [`find_files`] and `options`.
+pub fn validate_metadata_dir_with_options(
+    dir: &Path,
+    options: ScanOptions,
+) -> Result<Vec<YamlValidationResult>, Box<dyn std::error::Error>> {
+    if !dir.is_dir() {
+        return Err(format!("Not a directory: {}", dir.display()).into());
+    }
+
+    Ok(find_files(dir, &["yaml", "yml"], options)?
+        .into_iter()
+        .map(validate_metadata_file)
+        .collect())
+}
+
+/// Checks a single YAML metadata file.
+fn validate_metadata_file(path: PathBuf) -> YamlValidationResult {
+    let bytes = match std::fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(e) => return YamlValidationResult::failed(path, e.to_string()),
+    };
+    let yaml: serde_yaml::Value = match serde_yaml::from_slice(&bytes) {
+        Ok(yaml) => yaml,
+        Err(e) => return YamlValidationResult::failed(path, e.to_string()),
+    };
+    let Some(map) = yaml.as_mapping() else {
+        return YamlValidationResult::failed(path, "not a mapping".to_string());
+    };
+
+    let keys: Vec<&str> = map.keys().filter_map(|k| k.as_str()).collect();
+    YamlValidationResult {
+        key_count: map.len(),
+        has_tokenizer: keys.iter().any(|k| k.contains("tokenizer")),
+        has_config: keys.iter().any(|k| k.contains("config")),
+        path,
+        error: None,
+    }
+}
+
+/// Compares the metadata of a GGUF file with a reference YAML snapshot (`--compare-ref`).
+///
+/// The reference is a YAML mapping of key to value, such as a YAML export of a
+/// known-good build. Values are compared as displayed by
+/// [`load_gguf_metadata_sync`]; non-string YAML scalars are compared by their
+/// text. An empty result means the file matches the reference.
+///
+/// # Errors
+///
+/// Returns an error if the GGUF file cannot be loaded, or if the reference cannot
+/// be read or is not a YAML mapping.
+pub fn compare_to_reference(gguf: &Path, reference_yaml: &Path) -> Result<Vec<MetadataDiff>, CliError> {
+    let actual = load_gguf_metadata_sync(gguf)?;
+    let yaml: serde_yaml::Value = serde_yaml::from_slice(&std::fs::read(reference_yaml)?)?;
+    let Some(map) = yaml.as_mapping() else {
+        return Err(format!("Reference is not a YAML mapping: {}", reference_yaml.display()).into());
+    };
+
+    let expected: Vec<(String, String)> = map
+        .iter()
+        .map(|(k, v)| (yaml_scalar_text(k), yaml_scalar_text(v)))
+        .collect();
+    Ok(diff_metadata(&expected, &actual))
+}
+
+/// Exports metadata into an SQLite database (`--sqlite`).
+///
+/// `input` is either one GGUF file or a directory, in which case every `.gguf`
+/// file directly inside it is exported in name order. Rows are tagged with each
+/// file's name and appended to the database, see [`export_sqlite`]. Returns the
+/// exported files.
+///
+/// # Errors
+///
+/// Stops at the first file that cannot be loaded or written to the database.
+pub fn export_to_sqlite(input: &Path, db: &Path) -> Result<Vec<PathBuf>, CliError> {
+    let files = if input.is_dir() {
+        gguf_files_in(input)?
+    } else {
+        vec![input.to_path_buf()]
+    };
+
+    for file in &files {
+        let metadata = load_gguf_metadata_sync(file)?;
+        let metadata_refs: Vec<_> = metadata.iter().map(|(k, v)| (k, v)).collect();
+        let source = file
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| file.display().to_string());
+        export_sqlite(&source, &metadata_refs, db)?;
+    }
+    Ok(files)
+}
+
+/// Returns the text of a YAML value as it would appear in a metadata export.
+fn yaml_scalar_text(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::String(s) => s.clone(),
+        serde_yaml::Value::Number(n) => n.to_string(),
+        serde_yaml::Value::Bool(b) => b.to_string(),
+        serde_yaml::Value::Null => String::new(),
+        other => serde_yaml::to_string(other).unwrap_or_default().trim_end().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_file_json_error() {
+        let err: CliError = crate::format::build_model_report(std::path::Path::new("no/such/model.gguf"))
+            .unwrap_err()
+            .into();
+
+        assert_eq!(err.kind(), "io");
+        let json: serde_json::Value = serde_json::from_str(&err.render(true)).expect("Should be valid JSON");
+        let object = json.as_object().expect("Should be a JSON object");
+        assert_eq!(object.len(), 2);
+        assert_eq!(json["kind"], "io");
+        assert!(json["error"].as_str().is_some_and(|msg| !msg.is_empty()));
+    }
+
+    #[test]
+    fn test_truncated_buffer_json_error() {
+        let err: CliError = crate::format::load_gguf_metadata_from_bytes(&[0u8; 12]).unwrap_err().into();
+
+        assert_eq!(err.kind(), "format");
+        assert_eq!(
+            err.to_json(),
+            serde_json::json!({
+                "error": GgufError::Truncated(12).to_string(),
+                "kind": "format",
+            })
+        );
+    }
+
+    #[test]
+    fn test_stdin_sample_model_produces_metadata() {
+        let path = std::path::Path::new("model/Qwen3-0.6B-Q5_K_M.gguf");
+        if !path.exists() {
+            eprintln!("Sample model not found at {}, skipping", path.display());
+            return;
+        }
+
+        let bytes = std::fs::read(path).expect("Sample model should be readable");
+        let (json, mismatch) = export_json_from_reader(std::io::Cursor::new(bytes)).expect("Piped model should parse");
+        assert_eq!(mismatch, None);
+
+        let keys = json["keys"].as_array().expect("Export should list keys");
+        assert!(!keys.is_empty());
+        assert!(keys.iter().any(|k| k == "general.architecture"));
+        assert!(json["raw"].as_object().is_some_and(|raw| raw.len() == keys.len()));
+    }
+
+    #[test]
+    fn test_keys_lists_sample_model_keys_without_values() {
+        let path = std::path::Path::new("model/Qwen3-0.6B-Q5_K_M.gguf");
+        if !path.exists() {
+            eprintln!("Sample model not found at {}, skipping", path.display());
+            return;
+        }
+
+        let keys = metadata_keys(path).expect("Sample model should parse");
+        let text = render_key_list(&keys, false);
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines.contains(&"general.architecture"));
+        assert!(lines.contains(&"tokenizer.ggml.tokens"));
+        assert!(!lines.contains(&"qwen3") && !text.contains("<|endoftext|>"), "Values are not printed");
+
+        let json: serde_json::Value = serde_json::from_str(&render_key_list(&keys, true)).expect("Key list should be JSON");
+        assert_eq!(json.as_array().map(Vec::len), Some(keys.len()));
+        assert!(json.as_array().unwrap().iter().all(|key| key.is_string()));
+    }
+
+    #[test]
+    fn test_render_key_list() {
+        let keys = vec!["general.architecture".to_string(), "general.name".to_string()];
+        assert_eq!(render_key_list(&keys, false), "general.architecture\ngeneral.name");
+        assert_eq!(render_key_list(&keys, true), r#"["general.architecture","general.name"]"#);
+        assert_eq!(render_key_list(&[], false), "");
+    }
+
+    #[test]
+    fn test_metadata_key_selection_exports_only_requested_keys() {
+        let metadata = [
+            ("general.architecture", "qwen3"),
+            ("general.name", "Qwen3"),
+            ("qwen3.context_length", "40960"),
+        ]
+        .map(|(k, v)| (k.to_string(), v.to_string()));
+        let metadata_refs: Vec<_> = metadata.iter().map(|(k, v)| (k, v)).collect();
+        let keys = vec!["general.name".to_string(), "general.architecture".to_string()];
+
+        let (selected, missing) = select_metadata_keys(&metadata_refs, &keys);
+        assert!(missing.is_empty());
+        let json = export_json_value(&selected);
+        assert_eq!(json["keys"], serde_json::json!(["general.architecture", "general.name"]));
+        assert_eq!(json["raw"], serde_json::json!({"general.architecture": "qwen3", "general.name": "Qwen3"}));
+
+        let keys = vec!["general.name".to_string(), "general.license".to_string()];
+        let (selected, missing) = select_metadata_keys(&metadata_refs, &keys);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(missing, ["general.license"]);
+        assert!(report_missing_keys(&missing, false).is_ok());
+        assert_eq!(report_missing_keys(&missing, true).unwrap_err().kind(), "mismatch");
+        assert!(report_missing_keys(&[], true).is_ok());
+    }
+
+    #[test]
+    fn test_stdin_empty_input_is_format_error() {
+        let err = export_json_from_reader(std::io::empty()).unwrap_err();
+        assert_eq!(err.kind(), "format");
+    }
+
+    #[test]
+    fn test_plain_render() {
+        let err = CliError::Usage("--info requires an input GGUF file".to_string());
+        assert_eq!(err.render(false), "Error: --info requires an input GGUF file");
+        assert_eq!(err.kind(), "usage");
+    }
+
+    #[test]
+    fn test_validate_metadata_dir_reports_each_file() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        std::fs::write(
+            dir.path().join("model.yaml"),
+            "general.name: llama\ntokenizer.ggml.model: gpt2\nconfig_version: 1\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("list.yml"), "- a\n- b\n").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let results = validate_metadata_dir(dir.path()).expect("Directory should be readable");
+        assert_eq!(results.len(), 2);
+
+        let list = &results[0];
+        assert!(list.path.ends_with("list.yml"));
+        assert_eq!(list.error.as_deref(), Some("not a mapping"));
+        assert_eq!(list.key_count, 0);
+
+        let model = &results[1];
+        assert!(model.path.ends_with("model.yaml"));
+        assert_eq!(model.error, None);
+        assert_eq!(model.key_count, 3);
+        assert!(model.has_tokenizer);
+        assert!(model.has_config);
+    }
+
+    #[test]
+    fn test_find_files_recursive_discovers_nested_files() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        std::fs::create_dir_all(dir.path().join("org/family")).unwrap();
+        std::fs::write(dir.path().join("top.gguf"), b"").unwrap();
+        std::fs::write(dir.path().join("org/family/nested.GGUF"), b"").unwrap();
+        std::fs::write(dir.path().join("org/notes.txt"), b"").unwrap();
+
+        let flat = find_files(dir.path(), &["gguf"], ScanOptions::default()).unwrap();
+        assert_eq!(flat.len(), 1);
+        assert!(flat[0].ends_with("top.gguf"));
+
+        let recursive = ScanOptions { recursive: true, ..Default::default() };
+        let found = find_files(&dir.path().join("org/.."), &["gguf"], recursive).unwrap();
+        assert_eq!(found.len(), 2);
+        assert!(found.iter().any(|path| path.ends_with("org/family/nested.GGUF")));
+        assert!(found.iter().all(|path| path.is_absolute() && !path.to_string_lossy().contains("..")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_find_files_symlink_cycle_terminates() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        std::fs::create_dir(dir.path().join("sub")).unwrap();
+        std::fs::write(dir.path().join("sub/model.gguf"), b"").unwrap();
+        std::os::unix::fs::symlink(dir.path(), dir.path().join("sub/loop")).unwrap();
+        std::os::unix::fs::symlink(dir.path().join("sub/model.gguf"), dir.path().join("alias.gguf")).unwrap();
+
+        let options = ScanOptions { recursive: true, follow_symlinks: true };
+        let found = find_files(dir.path(), &["gguf"], options).unwrap();
+        assert_eq!(found.len(), 1, "Linked file and cycle are listed once: {:?}", found);
+
+        let unfollowed = find_files(dir.path(), &["gguf"], ScanOptions { follow_symlinks: false, ..options }).unwrap();
+        assert_eq!(unfollowed, found);
+    }
+
+    #[test]
+    fn test_validate_metadata_dir_rejects_missing_dir() {
+        assert!(validate_metadata_dir(Path::new("no/such/dir")).is_err());
+    }
+
+    #[test]
+    fn test_stdin_export_returns_count_mismatch() {
+        use candle::quantized::gguf_file::Value;
+
+        // A repeated key collapses on parsing, so the header kv_count is one too high
+        let buf = crate::format::make_test_gguf(&[
+            ("general.name", Value::String("first".to_string())),
+            ("general.name", Value::String("second".to_string())),
+        ]);
+        let (json, mismatch) = export_json_from_reader(std::io::Cursor::new(buf)).expect("Duplicate keys still parse");
+
+        let mismatch = mismatch.expect("Inflated kv_count should be reported");
+        assert_eq!((mismatch.header_kv_count, mismatch.parsed_kv_count), (2, 1));
+        assert!(json["keys"].as_array().is_some_and(|keys| keys.iter().any(|k| k == "general.name")));
+    }
+
+    fn write_test_model(dir: &Path) -> PathBuf {
+        use candle::quantized::gguf_file::Value;
+
+        let path = dir.join("tiny.gguf");
+        let buf = crate::format::make_test_gguf(&[
+            ("general.name", Value::String("tiny-model".to_string())),
+            ("general.architecture", Value::String("llama".to_string())),
+        ]);
+        std::fs::write(&path, buf).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_compare_to_reference_reports_changed_key() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let model = write_test_model(dir.path());
+        let reference = dir.path().join("reference.yaml");
+        std::fs::write(
+            &reference,
+            "version: 3\ntensor_count: 0\nkv_count: 2\ngeneral.name: tiny-model\ngeneral.architecture: qwen3\n",
+        )
+        .unwrap();
+
+        let diffs = compare_to_reference(&model, &reference).expect("Comparison should succeed");
+        assert_eq!(
+            diffs,
+            vec![MetadataDiff::Changed {
+                key: "general.architecture".to_string(),
+                expected: "qwen3".to_string(),
+                actual: "llama".to_string(),
+            }]
+        );
+        assert_eq!(diffs[0].to_string(), "~ general.architecture: qwen3 -> llama");
+    }
+
+    #[test]
+    fn test_compare_to_reference_matching_and_missing_keys() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let model = write_test_model(dir.path());
+        let reference = dir.path().join("reference.yaml");
+
+        std::fs::write(&reference, "general.name: tiny-model\ngeneral.architecture: llama\n").unwrap();
+        let diffs = compare_to_reference(&model, &reference).expect("Comparison should succeed");
+        let keys: Vec<&str> = diffs.iter().map(|d| d.key()).collect();
+        assert_eq!(keys, ["kv_count", "tensor_count", "version"], "Only unlisted header fields differ");
+
+        std::fs::write(&reference, "- general.name\n- general.architecture\n").unwrap();
+        assert!(compare_to_reference(&model, &reference).is_err());
+    }
+
+    #[test]
+    fn test_export_to_sqlite_batches_directory() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let model = write_test_model(dir.path());
+        std::fs::copy(&model, dir.path().join("copy.gguf")).unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "not a model").unwrap();
+        let db = dir.path().join("models.db");
+
+        let exported = export_to_sqlite(dir.path(), &db).expect("Batch export should succeed");
+        assert_eq!(exported, [dir.path().join("copy.gguf"), model.clone()]);
+        export_to_sqlite(&model, &db).expect("Single-file export should succeed");
+
+        let conn = rusqlite::Connection::open(&db).expect("Should open database");
+        let names: Vec<String> = conn
+            .prepare("SELECT source FROM metadata WHERE key = 'general.name' ORDER BY source")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(names, ["copy.gguf", "tiny.gguf", "tiny.gguf"]);
+    }
+}
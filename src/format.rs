@@ -27,7 +27,7 @@
 //! for (key, value) in metadata {
 //!     println!("{}: {}", key, value);
 //! }
-//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! # Ok::<(), inspector_gguf::format::BoxError>(())
 //! ```
 //!
 //! Loading with full tokenizer content:
@@ -45,7 +45,7 @@
 //!         println!("  Full content: {}", full);
 //!     }
 //! }
-//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! # Ok::<(), inspector_gguf::format::BoxError>(())
 //! ```
 //!
 //! # GGUF Format Overview
@@ -64,6 +64,8 @@
 use candle::quantized::gguf_file;
 use std::fs::File;
 use std::io::Read;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock};
 
 /// Loads GGUF file metadata synchronously and returns key-value pairs.
 ///
@@ -150,13 +152,59 @@ use std::io::Read;
 /// ```
 ///
 /// See also [`load_gguf_metadata_with_full_content_sync`] for extended tokenizer content,
-/// [`readable_value_for_key`] for value formatting, and [`crate::gui::load_gguf_metadata_async`] 
+/// [`readable_value_for_key`] for value formatting, and `gui::load_gguf_metadata_async` 
 /// for asynchronous loading with progress tracking.
 pub fn load_gguf_metadata_sync(
     path: &std::path::Path,
-) -> Result<Vec<(String, String)>, Box<dyn std::error::Error>> {
+) -> Result<Vec<(String, String)>, BoxError> {
+    load_gguf_metadata_timed(path).map(|(metadata, _)| metadata)
+}
+
+/// Wall-clock duration of each phase of [`load_gguf_metadata_timed`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadTimings {
+    /// Opening the file and reading it into memory.
+    pub read: std::time::Duration,
+    /// Parsing the GGUF header, metadata, and tensor descriptors.
+    pub parse: std::time::Duration,
+    /// Formatting the metadata values.
+    pub process: std::time::Duration,
+}
+
+impl LoadTimings {
+    /// Sum of all phases.
+    pub fn total(&self) -> std::time::Duration {
+        self.read + self.parse + self.process
+    }
+}
+
+/// Same as [`load_gguf_metadata_sync`], also returning how long each phase took.
+///
+/// The timings are measured with [`std::time::Instant`], so they are available
+/// to library users and benchmarks without the puffin profiler.
+///
+/// # Errors
+///
+/// Returns the same errors as [`load_gguf_metadata_sync`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use inspector_gguf::format::load_gguf_metadata_timed;
+/// use std::path::Path;
+///
+/// let (metadata, timings) = load_gguf_metadata_timed(Path::new("model.gguf"))?;
+/// println!("{} entries, parsed in {:?}", metadata.len(), timings.parse);
+/// # Ok::<(), inspector_gguf::format::BoxError>(())
+/// ```
+pub fn load_gguf_metadata_timed(
+    path: &std::path::Path,
+) -> Result<(Vec<(String, String)>, LoadTimings), BoxError> {
+    use std::time::Instant;
+
     puffin::profile_scope!("load_gguf_metadata_sync");
 
+    let read_start = Instant::now();
     let mut f = {
         puffin::profile_scope!("file_open");
         File::open(path)?
@@ -167,12 +215,19 @@ pub fn load_gguf_metadata_sync(
         puffin::profile_scope!("file_reading");
         f.read_to_end(&mut buf)?;
     }
+    let read = read_start.elapsed();
+
+    check_gguf_size(buf.len() as u64)?;
 
+    let parse_start = Instant::now();
     let content = {
         puffin::profile_scope!("gguf_parsing");
         let mut cursor = std::io::Cursor::new(&buf);
         candle::quantized::gguf_file::Content::read(&mut cursor)?
     };
+    let parse = parse_start.elapsed();
+
+    let process_start = Instant::now();
 
     // Read header fields from the buffer (candle may have moved the cursor)
     let header_fields = read_gguf_header_from_buffer(&buf).unwrap_or_else(|e| {
@@ -190,14 +245,45 @@ pub fn load_gguf_metadata_sync(
         out.push(("tensor_count".to_string(), header_fields.tensor_count.to_string()));
         out.push(("kv_count".to_string(), header_fields.kv_count.to_string()));
 
-        // Add metadata
+        // Add metadata; known config keys stay unannotated for exports and --compare-ref
         for (k, v) in content.metadata.iter() {
-            let s = readable_value_for_key(k, v);
+            let s = format_value(k, v, false, DEFAULT_PREVIEW_LIMIT, DEFAULT_PREVIEW_CHARS, false);
             out.push((k.clone(), s));
         }
     }
+    let process = process_start.elapsed();
+
+    Ok((out, LoadTimings { read, parse, process }))
+}
 
-    Ok(out)
+/// Async version of [`load_gguf_metadata_sync`] for use inside a Tokio runtime.
+///
+/// The file is read and parsed on Tokio's blocking thread pool via
+/// [`tokio::task::spawn_blocking`], so the calling task's worker thread is not
+/// blocked. Requires the `tokio` feature.
+///
+/// # Errors
+///
+/// Returns the same errors as [`load_gguf_metadata_sync`], or a join error if the
+/// blocking task panicked or was cancelled.
+///
+/// # Examples
+///
+/// ```no_run
+/// use inspector_gguf::format::load_gguf_metadata;
+///
+/// # async fn run() -> Result<(), inspector_gguf::format::BoxError> {
+/// let metadata = load_gguf_metadata("model.gguf").await?;
+/// println!("{} metadata entries", metadata.len());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn load_gguf_metadata(
+    path: impl Into<std::path::PathBuf>,
+) -> Result<Vec<(String, String)>, BoxError> {
+    let path = path.into();
+    tokio::task::spawn_blocking(move || load_gguf_metadata_sync(&path)).await?
 }
 
 /// Loads GGUF file metadata with full tokenizer content support.
@@ -276,12 +362,13 @@ pub fn load_gguf_metadata_sync(
 /// - Exporting comprehensive model metadata
 ///
 /// See also [`load_gguf_metadata_sync`] for basic metadata extraction,
+/// [`load_gguf_metadata_from_bytes`] for parsing an in-memory buffer,
 /// [`get_full_tokenizer_content`] for tokenizer-specific content extraction,
-/// and [`crate::gui::loader::MetadataEntry`] for the GUI representation of metadata entries.
+/// and `gui::loader::MetadataEntry` for the GUI representation of metadata entries.
 #[allow(clippy::type_complexity)]
 pub fn load_gguf_metadata_with_full_content_sync(
     path: &std::path::Path,
-) -> Result<Vec<(String, String, Option<String>)>, Box<dyn std::error::Error>> {
+) -> Result<Vec<(String, String, Option<String>)>, BoxError> {
     puffin::profile_scope!("load_gguf_metadata_with_full_content_sync");
 
     let mut f = {
@@ -295,14 +382,67 @@ pub fn load_gguf_metadata_with_full_content_sync(
         f.read_to_end(&mut buf)?;
     }
 
+    load_gguf_metadata_from_bytes(&buf)
+}
+
+/// Parses GGUF metadata from an in-memory buffer.
+///
+/// This is the parsing core shared by [`load_gguf_metadata_with_full_content_sync`]
+/// and is useful when the file contents are already in memory (for example when
+/// they were received over the network or synthesized in tests).
+///
+/// # Arguments
+///
+/// * `buf` - Complete GGUF file contents
+///
+/// # Returns
+///
+/// The same `(key, display_value, full_content)` tuples as
+/// [`load_gguf_metadata_with_full_content_sync`], starting with the header fields.
+///
+/// # Errors
+///
+/// Returns a [`GgufError`] if the buffer is empty or shorter than the GGUF header,
+/// and a parser error if it is otherwise not a valid GGUF file.
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::load_gguf_metadata_from_bytes;
+///
+/// let result = load_gguf_metadata_from_bytes(b"definitely not a gguf file");
+/// assert!(result.is_err());
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn load_gguf_metadata_from_bytes(
+    buf: &[u8],
+) -> Result<Vec<(String, String, Option<String>)>, BoxError> {
+    load_gguf_metadata_from_bytes_checked(buf).map(|(metadata, _)| metadata)
+}
+
+/// Same as [`load_gguf_metadata_from_bytes`], also returning the result of
+/// [`check_header_counts`].
+///
+/// Nothing is printed; callers decide how to surface a [`CountMismatch`].
+///
+/// # Errors
+///
+/// Returns the same errors as [`load_gguf_metadata_from_bytes`].
+#[allow(clippy::type_complexity)]
+pub fn load_gguf_metadata_from_bytes_checked(
+    buf: &[u8],
+) -> Result<(Vec<(String, String, Option<String>)>, Option<CountMismatch>), BoxError> {
+    check_gguf_size(buf.len() as u64)?;
+
     let content = {
         puffin::profile_scope!("gguf_parsing");
-        let mut cursor = std::io::Cursor::new(&buf);
+        let mut cursor = std::io::Cursor::new(buf);
         candle::quantized::gguf_file::Content::read(&mut cursor)?
     };
+    let mismatch = check_header_counts(buf, &content);
 
     // Read header fields from the buffer (candle may have moved the cursor)
-    let header_fields = read_gguf_header_from_buffer(&buf).unwrap_or_else(|e| {
+    let header_fields = read_gguf_header_from_buffer(buf).unwrap_or_else(|e| {
         eprintln!("ERROR reading header: {}", e);
         GGufHeader { version: 0, tensor_count: 0, kv_count: 0 }
     });
@@ -317,15 +457,159 @@ pub fn load_gguf_metadata_with_full_content_sync(
         out.push(("tensor_count".to_string(), header_fields.tensor_count.to_string(), None));
         out.push(("kv_count".to_string(), header_fields.kv_count.to_string(), None));
 
-        // Add metadata
+        // Add metadata; known config keys stay unannotated for exports and --compare-ref
         for (k, v) in content.metadata.iter() {
-            let s = readable_value_for_key(k, v);
-            let full_content = get_full_tokenizer_content(k, v);
+            let s = format_value(k, v, false, DEFAULT_PREVIEW_LIMIT, DEFAULT_PREVIEW_CHARS, false);
+            let full_content = get_full_tokenizer_content(k, v).or_else(|| image_data_uri(v));
             out.push((k.clone(), s, full_content));
         }
     }
 
-    Ok(out)
+    Ok((out, mismatch))
+}
+
+/// Number of bytes [`load_gguf_metadata_from_url`] fetches first; it asks for four
+/// times as many whenever the metadata does not fit.
+#[cfg(feature = "http")]
+const HTTP_INITIAL_HEAD_BYTES: u64 = 1024 * 1024;
+
+/// Downloads at most the first `max_bytes` bytes of the file at `url`.
+///
+/// Sends an HTTP range request for the leading bytes. Servers that ignore
+/// ranges answer with the whole file, which is then downloaded in full.
+/// Requires the `http` feature.
+///
+/// # Errors
+///
+/// Returns a [`reqwest::Error`] if the request fails or the server answers
+/// with an error status, and an I/O error if reading the body fails.
+///
+/// # Examples
+///
+/// ```no_run
+/// use inspector_gguf::format::fetch_gguf_head;
+///
+/// let head = fetch_gguf_head("https://example.com/model.gguf", 1024 * 1024)?;
+/// assert!(head.len() <= 1024 * 1024);
+/// # Ok::<(), inspector_gguf::format::BoxError>(())
+/// ```
+#[cfg(feature = "http")]
+pub fn fetch_gguf_head(url: &str, max_bytes: u64) -> Result<Vec<u8>, BoxError> {
+    puffin::profile_scope!("fetch_gguf_head");
+    let mut buf = Vec::new();
+    if max_bytes == 0 {
+        return Ok(buf);
+    }
+    let mut response = reqwest::blocking::Client::new()
+        .get(url)
+        .header("User-Agent", "Inspector-GGUF-App")
+        .header(reqwest::header::RANGE, format!("bytes=0-{}", max_bytes - 1))
+        .send()?
+        .error_for_status()?;
+    // Servers that ignore the range answer 200 with the whole file
+    let limit = if response.status() == reqwest::StatusCode::PARTIAL_CONTENT { max_bytes } else { u64::MAX };
+    (&mut response).take(limit).read_to_end(&mut buf)?;
+    Ok(buf)
+}
+
+/// Loads GGUF metadata from an HTTP(S) URL without downloading the tensor data.
+///
+/// Fetches the start of the file with [`fetch_gguf_head`] and parses it with
+/// [`load_gguf_metadata_from_bytes`]. Metadata with large tokenizer arrays may not
+/// fit in the first request; the download then grows fourfold until parsing
+/// succeeds or the whole file has been read. Requires the `http` feature.
+///
+/// # Errors
+///
+/// Returns the errors of [`fetch_gguf_head`], a [`GgufError`] for empty or
+/// truncated files, and a parser error if the file is not a valid GGUF file.
+#[allow(clippy::type_complexity)]
+#[cfg(feature = "http")]
+pub fn load_gguf_metadata_from_url(url: &str) -> Result<Vec<(String, String, Option<String>)>, BoxError> {
+    load_gguf_metadata_from_url_with_head(url, HTTP_INITIAL_HEAD_BYTES)
+}
+
+#[allow(clippy::type_complexity)]
+#[cfg(feature = "http")]
+fn load_gguf_metadata_from_url_with_head(
+    url: &str,
+    mut max_bytes: u64,
+) -> Result<Vec<(String, String, Option<String>)>, BoxError> {
+    loop {
+        let buf = fetch_gguf_head(url, max_bytes)?;
+        match load_gguf_metadata_from_bytes(&buf) {
+            Ok(metadata) => return Ok(metadata),
+            // Only a body of exactly `max_bytes` may be cut short; a bad magic won't go away with more bytes
+            Err(e) if buf.len() as u64 != max_bytes || e.downcast_ref::<GgufError>() == Some(&GgufError::InvalidMagic) => {
+                return Err(e);
+            }
+            Err(_) => max_bytes = max_bytes.saturating_mul(4),
+        }
+    }
+}
+
+/// Size in bytes of the fixed GGUF header: magic, version, tensor count, and key-value count.
+pub const GGUF_HEADER_SIZE: u64 = 24;
+
+/// Magic bytes every GGUF file starts with.
+pub const GGUF_MAGIC: &[u8; 4] = b"GGUF";
+
+/// Errors detected before a GGUF file is handed to the Candle parser.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum GgufError {
+    /// The file contains no data at all
+    #[error("File is empty")]
+    Empty,
+
+    /// The file is shorter than the fixed GGUF header
+    #[error("File is truncated: {0} bytes is smaller than the {GGUF_HEADER_SIZE}-byte GGUF header")]
+    Truncated(u64),
+
+    /// The file does not start with the `GGUF` magic bytes
+    #[error("Invalid GGUF magic bytes")]
+    InvalidMagic,
+}
+
+impl GgufError {
+    /// Returns the translation key for the localized GUI message.
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            GgufError::Empty => "errors.file_empty",
+            GgufError::Truncated(_) => "errors.file_truncated",
+            GgufError::InvalidMagic => "errors.invalid_magic",
+        }
+    }
+}
+
+/// Error type returned by the file-loading functions of this module.
+///
+/// Errors are `Send + Sync`, so results can be moved across threads or returned
+/// from tasks spawned on an async runtime. Use [`Box::downcast`] to recover a
+/// [`GgufError`], [`std::io::Error`] or [`candle::Error`].
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Rejects files too small to contain a GGUF header.
+///
+/// # Errors
+///
+/// Returns [`GgufError::Empty`] for a 0-byte file and [`GgufError::Truncated`]
+/// for anything shorter than [`GGUF_HEADER_SIZE`].
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::{check_gguf_size, GgufError};
+///
+/// assert_eq!(check_gguf_size(0), Err(GgufError::Empty));
+/// assert_eq!(check_gguf_size(12), Err(GgufError::Truncated(12)));
+/// assert!(check_gguf_size(1024).is_ok());
+/// ```
+pub fn check_gguf_size(len: u64) -> Result<(), GgufError> {
+    match len {
+        0 => Err(GgufError::Empty),
+        len if len < GGUF_HEADER_SIZE => Err(GgufError::Truncated(len)),
+        _ => Ok(()),
+    }
 }
 
 #[derive(Debug)]
@@ -335,25 +619,29 @@ struct GGufHeader {
     kv_count: u64,
 }
 
-fn read_gguf_header_from_buffer(buffer: &[u8]) -> Result<GGufHeader, Box<dyn std::error::Error>> {
-    if buffer.len() < 20 {
-        return Err("Buffer too small for GGUF header".into());
-    }
+fn read_gguf_header_from_buffer(buffer: &[u8]) -> Result<GGufHeader, GgufError> {
+    check_gguf_size(buffer.len() as u64)?;
 
     // Check magic bytes "GGUF" (first 4 bytes)
-    if &buffer[0..4] != b"GGUF" {
-        return Err("Invalid GGUF magic bytes".into());
+    if &buffer[0..4] != GGUF_MAGIC {
+        return Err(GgufError::InvalidMagic);
     }
 
+    // Slice lengths are guaranteed by the size check above
     // Read version (uint32, little endian) - bytes 4-7
-    let version = u32::from_le_bytes(buffer[4..8].try_into()?);
-
-    // Read tensor count (uint64, little endian) - bytes 8-15
-    let tensor_count = u64::from_le_bytes(buffer[8..16].try_into()?);
-
-    // Read kv count (uint64, little endian) - bytes 16-23
-    let kv_count = u64::from_le_bytes(buffer[16..24].try_into()?);
+    let version = u32::from_le_bytes(buffer[4..8].try_into().expect("4-byte slice"));
 
+    let (tensor_count, kv_count) = if version == 1 {
+        // GGUF v1: tensor count and kv count are uint32 - bytes 8-11 and 12-15
+        let tensor_count = u32::from_le_bytes(buffer[8..12].try_into().expect("4-byte slice"));
+        let kv_count = u32::from_le_bytes(buffer[12..16].try_into().expect("4-byte slice"));
+        (u64::from(tensor_count), u64::from(kv_count))
+    } else {
+        // GGUF v2/v3: tensor count and kv count are uint64 - bytes 8-15 and 16-23
+        let tensor_count = u64::from_le_bytes(buffer[8..16].try_into().expect("8-byte slice"));
+        let kv_count = u64::from_le_bytes(buffer[16..24].try_into().expect("8-byte slice"));
+        (tensor_count, kv_count)
+    };
 
     Ok(GGufHeader {
         version,
@@ -362,6 +650,49 @@ fn read_gguf_header_from_buffer(buffer: &[u8]) -> Result<GGufHeader, Box<dyn std
     })
 }
 
+/// Tensor and metadata counts that disagree between a GGUF header and its parsed content.
+///
+/// The parser reads exactly as many entries as the header advertises, but
+/// entries with a repeated name collapse into one, so a difference means the
+/// file is corrupt or was written by a buggy tool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+pub struct CountMismatch {
+    /// Tensor count advertised in the header.
+    pub header_tensor_count: u64,
+    /// Number of distinct tensors actually parsed.
+    pub parsed_tensor_count: u64,
+    /// Metadata key-value count advertised in the header.
+    pub header_kv_count: u64,
+    /// Number of distinct metadata keys actually parsed.
+    pub parsed_kv_count: u64,
+}
+
+impl std::fmt::Display for CountMismatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "header declares {} tensors and {} metadata entries, but {} tensors and {} metadata entries were parsed; the file may be corrupt",
+            self.header_tensor_count, self.header_kv_count, self.parsed_tensor_count, self.parsed_kv_count
+        )
+    }
+}
+
+/// Compares the counts in the GGUF header at the start of `buffer` with the parsed `content`.
+///
+/// Returns `None` when they agree or when `buffer` does not start with a readable header.
+pub fn check_header_counts(buffer: &[u8], content: &gguf_file::Content) -> Option<CountMismatch> {
+    let header = read_gguf_header_from_buffer(buffer).ok()?;
+    let mismatch = CountMismatch {
+        header_tensor_count: header.tensor_count,
+        parsed_tensor_count: content.tensor_infos.len() as u64,
+        header_kv_count: header.kv_count,
+        parsed_kv_count: content.metadata.len() as u64,
+    };
+    let agree = mismatch.header_tensor_count == mismatch.parsed_tensor_count
+        && mismatch.header_kv_count == mismatch.parsed_kv_count;
+    (!agree).then_some(mismatch)
+}
+
 /// Converts a GGUF metadata value to a human-readable string representation.
 ///
 /// This function provides intelligent formatting for different types of GGUF metadata values,
@@ -412,6 +743,7 @@ fn read_gguf_header_from_buffer(buffer: &[u8]) -> Result<GGufHeader, Box<dyn std
 /// - **Token arrays**: `tokenizer.ggml.tokens` and `tokenizer.ggml.merges` show first few items
 /// - **Byte arrays**: Small arrays show hex representation, large ones show length
 /// - **General arrays**: Display first few elements with "…" for truncation
+/// - **Known config floats**: Keys in [`KNOWN_CONFIG_KEYS`] get a description, e.g. `10000 (rope freq base)`
 ///
 /// # Performance
 ///
@@ -420,10 +752,82 @@ fn read_gguf_header_from_buffer(buffer: &[u8]) -> Result<GGufHeader, Box<dyn std
 ///
 /// See also [`readable_value_for_key_full`] for complete content display,
 /// [`get_full_tokenizer_content`] for tokenizer-specific content,
-/// and [`crate::gui::export`] module for exporting formatted values to various file formats.
+/// and `gui::export` module for exporting formatted values to various file formats.
 #[allow(clippy::collapsible_if)]
 pub fn readable_value_for_key(key: &str, v: &gguf_file::Value) -> String {
-    readable_value_for_key_full(key, v, false)
+    readable_value_for_key_with_limit(key, v, DEFAULT_PREVIEW_LIMIT)
+}
+
+/// Number of tokenizer tokens/merges shown before a display value is truncated.
+pub const DEFAULT_PREVIEW_LIMIT: usize = 3;
+
+/// Converts a GGUF metadata value to a display string with a custom preview length.
+///
+/// Behaves like [`readable_value_for_key`], but `tokenizer.ggml.tokens` and
+/// `tokenizer.ggml.merges` show the first `preview_limit` items before the `…`
+/// marker. Lists at most two items longer than the limit are shown whole, and a
+/// limit of `0` shows every item.
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::readable_value_for_key_with_limit;
+/// use candle::quantized::gguf_file::Value;
+///
+/// let tokens = Value::Array((0..8).map(|i| Value::String(format!("t{}", i))).collect());
+///
+/// assert_eq!(readable_value_for_key_with_limit("tokenizer.ggml.tokens", &tokens, 2), "t0, t1, …");
+/// assert!(!readable_value_for_key_with_limit("tokenizer.ggml.tokens", &tokens, 0).contains('…'));
+/// ```
+pub fn readable_value_for_key_with_limit(key: &str, v: &gguf_file::Value, preview_limit: usize) -> String {
+    format_value(key, v, false, preview_limit, DEFAULT_PREVIEW_CHARS, true)
+}
+
+/// Number of characters of a short UTF-8 byte array shown before its display value is truncated.
+pub const DEFAULT_PREVIEW_CHARS: usize = 50;
+
+/// Converts a GGUF metadata value to a display string with custom preview lengths.
+///
+/// Behaves like [`readable_value_for_key_with_limit`], and additionally shows at
+/// most `preview_chars` characters of byte arrays that decode as UTF-8 text before
+/// the `…` marker. Truncation counts characters, not bytes, so multibyte text is
+/// never cut inside a character.
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::readable_value_for_key_with_limits;
+/// use candle::quantized::gguf_file::Value;
+///
+/// let bytes = Value::Array("привет мир".bytes().map(Value::U8).collect());
+///
+/// assert_eq!(readable_value_for_key_with_limits("some.bytes", &bytes, 3, 6), "привет…");
+/// assert_eq!(readable_value_for_key_with_limits("some.bytes", &bytes, 3, 20), "привет мир");
+/// ```
+pub fn readable_value_for_key_with_limits(
+    key: &str,
+    v: &gguf_file::Value,
+    preview_limit: usize,
+    preview_chars: usize,
+) -> String {
+    format_value(key, v, false, preview_limit, preview_chars, true)
+}
+
+/// Returns the first `max_chars` characters of `s`, cut on a character boundary.
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::truncate_chars;
+///
+/// assert_eq!(truncate_chars("日本語テキスト", 3), "日本語");
+/// assert_eq!(truncate_chars("short", 50), "short");
+/// ```
+pub fn truncate_chars(s: &str, max_chars: usize) -> &str {
+    match s.char_indices().nth(max_chars) {
+        Some((end, _)) => &s[..end],
+        None => s,
+    }
 }
 
 /// Converts a GGUF metadata value to string with optional full content display.
@@ -476,154 +880,456 @@ pub fn readable_value_for_key(key: &str, v: &gguf_file::Value) -> String {
 ///
 /// See also [`readable_value_for_key`] for the standard truncated version,
 /// [`get_full_tokenizer_content`] for tokenizer-specific extraction,
-/// and [`crate::gui::loader::MetadataEntry`] for the GUI representation structure.
+/// and `gui::loader::MetadataEntry` for the GUI representation structure.
 pub fn readable_value_for_key_full(key: &str, v: &gguf_file::Value, full_content: bool) -> String {
-    // Special handling for tokenizer.chat_template - decode as UTF-8 string instead of base64
-    if key == "tokenizer.chat_template"
-        && let gguf_file::Value::Array(arr) = v
-        && !arr.is_empty() && arr.iter().all(|el| matches!(el, gguf_file::Value::U8(_))) {
-        let bytes: Vec<u8> = arr.iter()
-            .filter_map(|el| {
-                if let gguf_file::Value::U8(b) = el {
-                    Some(*b)
-                } else {
-                    None
-                }
-            })
-            .collect();
-        if let Ok(s) = String::from_utf8(bytes) {
-            return s;
-        }
-    }
-
-    // Special handling for tokenizer.ggml.tokens and tokenizer.ggml.merges - decode arrays of strings
-    if (key == "tokenizer.ggml.tokens" || key == "tokenizer.ggml.merges")
-        && let gguf_file::Value::Array(arr) = v
-        && !arr.is_empty() {
-        // Try to decode as array of strings (each element should be a string value)
-        let mut strings = Vec::new();
-        for el in arr.iter() {
-            match el {
-                gguf_file::Value::String(s) => {
-                    strings.push(s.clone());
-                }
-                gguf_file::Value::Array(inner_arr) => {
-                    // Fallback: try to decode as array of bytes
-                    if !inner_arr.is_empty() && inner_arr.iter().all(|iel| matches!(iel, gguf_file::Value::U8(_))) {
-                        let bytes: Vec<u8> = inner_arr.iter()
-                            .filter_map(|iel| {
-                                if let gguf_file::Value::U8(b) = iel {
-                                    Some(*b)
-                                } else {
-                                    None
-                                }
-                            })
-                            .collect();
-                        if let Ok(s) = String::from_utf8(bytes) {
-                            strings.push(s);
-                        }
-                    }
-                }
-                _ => {
-                    // Other types - just convert to string representation
-                    strings.push(format!("{:?}", el));
-                }
-            }
-        }
-        if !strings.is_empty() {
-            if strings.len() <= 5 || full_content {
-                return strings.join(", ");
-            } else {
-                let first_few = strings.iter().take(3).cloned().collect::<Vec<_>>().join(", ");
-                return format!("{}, …", first_few);
-            }
-        }
-    }
+    format_value(key, v, full_content, DEFAULT_PREVIEW_LIMIT, DEFAULT_PREVIEW_CHARS, !full_content)
+}
 
-    // Special handling for arrays - show examples like in real.md
-    if let gguf_file::Value::Array(arr) = v {
-        // If array of bytes (U8) - for tokenizer data, show as string preview
-        if !arr.is_empty() && arr.iter().all(|el| matches!(el, gguf_file::Value::U8(_))) {
-            let bytes_len = arr.len();
-            if bytes_len <= 64 {
-                // Try to decode as UTF-8 string
-                let bytes: Vec<u8> = arr.iter()
-                    .filter_map(|el| {
-                        if let gguf_file::Value::U8(b) = el {
-                            Some(*b)
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-                if let Ok(s) = String::from_utf8(bytes.clone()) {
-                    // Show first part of the string
-                    if s.len() <= 50 {
-                        return s;
-                    } else {
-                        return format!("{}…", &s[..50]);
-                    }
-                } else {
-                    // If not valid UTF-8, show as hex
-                    let hex: String = bytes.iter().take(20).map(|b| format!("{:02x}", b)).collect();
-                    return format!("{}…", hex);
-                }
-            } else {
-                return format!("Array(len={})", bytes_len);
-            }
-        }
+/// Label prefixed to tokens whose bytes are not valid UTF-8 and were decoded lossily.
+pub const NON_UTF8_MARKER: &str = "⚠ non-UTF-8";
 
-        // For other arrays, show first few elements like in real.md
-        if arr.len() <= 10 {
-            let parts: Vec<String> = arr.iter().map(|el| format!("{:?}", el)).collect();
-            return format!("{}, …", parts.join(", "));
-        } else {
-            // Show first 3 elements and indicate there are more
-            let first_parts: Vec<String> = arr.iter().take(3).map(|el| format!("{:?}", el)).collect();
-            return format!("{}, …", first_parts.join(", "));
-        }
-    }
+/// Predicate selecting the metadata keys a custom formatter handles.
+pub type ValueFormatterPredicate = fn(&str) -> bool;
 
-    // For scalar values, try the library-provided string representation
-    if let Ok(s) = v.to_string() {
-        return s.to_string();
-    }
+/// Custom formatter producing the display string for a metadata key and value.
+pub type ValueFormatter = fn(&str, &gguf_file::Value) -> String;
 
-    // Fallback to debug representation
-    format!("{:?}", v)
-}
+static VALUE_FORMATTERS: OnceLock<Mutex<Vec<(ValueFormatterPredicate, ValueFormatter)>>> = OnceLock::new();
 
-/// Extracts full tokenizer content for tokenizer-related metadata keys.
+/// Registers a custom formatter consulted before the built-in formatting logic.
 ///
-/// This function determines if a metadata key is tokenizer-related and returns
-/// the complete, untruncated content if so. It's used internally by
-/// [`load_gguf_metadata_with_full_content_sync`] to provide full tokenizer data.
+/// Every `readable_value_for_key*` function first asks the registered formatters,
+/// in registration order; the first whose `predicate` accepts the key produces the
+/// display string. Registration is global and thread-safe.
 ///
-/// # Arguments
+/// # Examples
 ///
-/// * `key` - The metadata key name to check
-/// * `v` - The GGUF value to extract content from
+/// ```
+/// use inspector_gguf::format::{readable_value_for_key, register_value_formatter};
+/// use candle::quantized::gguf_file::Value;
 ///
-/// # Returns
+/// register_value_formatter(
+///     |key| key == "example.flag",
+///     |_, value| match value {
+///         Value::Bool(true) => "enabled".to_string(),
+///         _ => "disabled".to_string(),
+///     },
+/// );
+///
+/// assert_eq!(readable_value_for_key("example.flag", &Value::Bool(true)), "enabled");
+/// ```
+pub fn register_value_formatter(predicate: ValueFormatterPredicate, formatter: ValueFormatter) {
+    VALUE_FORMATTERS
+        .get_or_init(|| Mutex::new(Vec::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push((predicate, formatter));
+}
+
+/// Returns the first registered custom formatter accepting `key`.
+fn custom_formatter_for(key: &str) -> Option<ValueFormatter> {
+    let formatters = VALUE_FORMATTERS.get()?.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    formatters
+        .iter()
+        .find(|(predicate, _)| predicate(key))
+        .map(|(_, formatter)| *formatter)
+}
+
+/// Structured form of a GGUF metadata value, for programmatic consumers.
+///
+/// Produced by [`typed_value_for_key`]; unlike the display strings of
+/// [`readable_value_for_key`], numbers, flags and lists keep their real types.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub enum ReadableValue {
+    /// Text value (also `u64` values that do not fit in `i64`).
+    Scalar(String),
+    /// Any integer type that fits in `i64`.
+    Int(i64),
+    /// `f32` or `f64` value.
+    Float(f64),
+    /// Boolean flag.
+    Bool(bool),
+    /// List of strings, including decoded tokenizer tokens and merges.
+    StringArray(Vec<String>),
+    /// Raw byte array (`u8` elements).
+    Bytes(Vec<u8>),
+    /// Any other array, element by element.
+    Array(Vec<ReadableValue>),
+}
+
+/// Returns `true` for the tokenizer keys whose arrays are decoded as token lists.
+fn is_token_list_key(key: &str) -> bool {
+    key == "tokenizer.ggml.tokens" || key == "tokenizer.ggml.merges"
+}
+
+/// Converts a GGUF metadata value to a [`ReadableValue`].
+///
+/// Applies the same key-specific decoding as [`readable_value_for_key`], without
+/// truncation:
 ///
-/// - `Some(String)` containing full content if the key starts with "tokenizer."
-/// - `None` if the key is not tokenizer-related
+/// - `tokenizer.chat_template` byte arrays that are valid UTF-8 become [`ReadableValue::Scalar`]
+/// - `tokenizer.ggml.tokens`/`merges` become [`ReadableValue::StringArray`]; byte-level
+///   tokens are decoded, lossily with [`NON_UTF8_MARKER`] when not valid UTF-8
+/// - other `u8` arrays become [`ReadableValue::Bytes`] and string arrays [`ReadableValue::StringArray`]
 ///
 /// # Examples
 ///
 /// ```
-/// use inspector_gguf::format::get_full_tokenizer_content;
+/// use inspector_gguf::format::{typed_value_for_key, ReadableValue};
 /// use candle::quantized::gguf_file::Value;
 ///
-/// let chat_template = Value::String("template content".to_string());
-///
-/// // Tokenizer keys return full content
-/// let result = get_full_tokenizer_content("tokenizer.chat_template", &chat_template);
-/// assert!(result.is_some());
-/// assert_eq!(result.unwrap(), "template content");
-///
-/// // Non-tokenizer keys return None
-/// let result = get_full_tokenizer_content("general.name", &chat_template);
+/// assert_eq!(typed_value_for_key("qwen3.block_count", &Value::U32(28)), ReadableValue::Int(28));
+/// assert_eq!(
+///     typed_value_for_key("general.tags", &Value::Array(vec![Value::String("text".into())])),
+///     ReadableValue::StringArray(vec!["text".to_string()])
+/// );
+/// ```
+pub fn typed_value_for_key(key: &str, v: &gguf_file::Value) -> ReadableValue {
+    use gguf_file::Value;
+    match v {
+        Value::U8(n) => ReadableValue::Int((*n).into()),
+        Value::I8(n) => ReadableValue::Int((*n).into()),
+        Value::U16(n) => ReadableValue::Int((*n).into()),
+        Value::I16(n) => ReadableValue::Int((*n).into()),
+        Value::U32(n) => ReadableValue::Int((*n).into()),
+        Value::I32(n) => ReadableValue::Int((*n).into()),
+        Value::I64(n) => ReadableValue::Int(*n),
+        Value::U64(n) => i64::try_from(*n)
+            .map(ReadableValue::Int)
+            .unwrap_or_else(|_| ReadableValue::Scalar(n.to_string())),
+        Value::F32(f) => ReadableValue::Float((*f).into()),
+        Value::F64(f) => ReadableValue::Float(*f),
+        Value::Bool(b) => ReadableValue::Bool(*b),
+        Value::String(s) => ReadableValue::Scalar(s.clone()),
+        Value::Array(arr) => {
+            if is_token_list_key(key) {
+                return ReadableValue::StringArray(arr.iter().filter_map(token_string).collect());
+            }
+            if !arr.is_empty() && arr.iter().all(|el| matches!(el, Value::U8(_))) {
+                let bytes = value_to_raw_bytes(v).unwrap_or_default();
+                if key == "tokenizer.chat_template" {
+                    return match String::from_utf8(bytes) {
+                        Ok(s) => ReadableValue::Scalar(s),
+                        Err(e) => ReadableValue::Bytes(e.into_bytes()),
+                    };
+                }
+                return ReadableValue::Bytes(bytes);
+            }
+            if !arr.is_empty() && arr.iter().all(|el| matches!(el, Value::String(_))) {
+                return ReadableValue::StringArray(arr.iter().filter_map(|el| el.to_string().ok().cloned()).collect());
+            }
+            ReadableValue::Array(arr.iter().map(|el| typed_value_for_key("", el)).collect())
+        }
+    }
+}
+
+/// Decodes one element of a token or merge list.
+///
+/// Byte-level tokens that are not valid UTF-8 are shown lossily, labeled with
+/// [`NON_UTF8_MARKER`]; non-byte nested arrays are skipped.
+fn token_string(el: &gguf_file::Value) -> Option<String> {
+    match el {
+        gguf_file::Value::String(s) => Some(s.clone()),
+        gguf_file::Value::Array(inner) => {
+            if inner.is_empty() || !inner.iter().all(|iel| matches!(iel, gguf_file::Value::U8(_))) {
+                return None;
+            }
+            let bytes = value_to_raw_bytes(el)?;
+            Some(match String::from_utf8(bytes) {
+                Ok(s) => s,
+                // Byte-level BPE tokens need not be valid UTF-8: show them lossily, labeled
+                Err(e) => format!("{}: {}", NON_UTF8_MARKER, String::from_utf8_lossy(e.as_bytes())),
+            })
+        }
+        // Other types - just convert to string representation
+        _ => Some(format!("{:?}", el)),
+    }
+}
+
+/// Renders one element of a general (non-byte, non-token) array.
+///
+/// Numbers and booleans are written plainly (`32`, `1.0`, `true`) instead of the
+/// `U32(32)` debug form, so per-layer arrays such as `<arch>.attention.head_count_kv`
+/// read naturally. Nested arrays are summarized by their length.
+fn array_element_string(el: &gguf_file::Value) -> String {
+    use gguf_file::Value;
+    match el {
+        Value::U8(n) => n.to_string(),
+        Value::I8(n) => n.to_string(),
+        Value::U16(n) => n.to_string(),
+        Value::I16(n) => n.to_string(),
+        Value::U32(n) => n.to_string(),
+        Value::I32(n) => n.to_string(),
+        Value::U64(n) => n.to_string(),
+        Value::I64(n) => n.to_string(),
+        // Debug keeps the decimal point: `1.0` rather than `1`
+        Value::F32(f) => format!("{:?}", f),
+        Value::F64(f) => format!("{:?}", f),
+        Value::Bool(b) => b.to_string(),
+        Value::String(s) => s.clone(),
+        Value::Array(inner) => format!("Array(len={})", inner.len()),
+    }
+}
+
+/// Maximum nesting depth rendered for arrays of arrays; deeper arrays show as `Array(len=N)`.
+const MAX_NESTED_ARRAY_DEPTH: usize = 3;
+
+/// Elements shown on each level of a nested array before the `…` marker.
+const NESTED_ARRAY_PREVIEW: usize = 3;
+
+/// Formats an array that contains arrays as `[[1, 2, 3, …], [4, 5], …]`.
+///
+/// Every level is truncated to [`NESTED_ARRAY_PREVIEW`] elements, and levels past
+/// [`MAX_NESTED_ARRAY_DEPTH`] are summarized by their length.
+fn nested_array_string(arr: &[gguf_file::Value], depth: usize) -> String {
+    let mut parts: Vec<String> = arr
+        .iter()
+        .take(NESTED_ARRAY_PREVIEW)
+        .map(|el| match el {
+            gguf_file::Value::Array(inner) if depth + 1 < MAX_NESTED_ARRAY_DEPTH => nested_array_string(inner, depth + 1),
+            el => array_element_string(el),
+        })
+        .collect();
+    if arr.len() > NESTED_ARRAY_PREVIEW {
+        parts.push("…".to_string());
+    }
+    format!("[{}]", parts.join(", "))
+}
+
+/// Float configuration keys shown with a short description after their value.
+///
+/// Each entry is a key suffix following the architecture prefix (so
+/// `.rope.freq_base` matches `llama.rope.freq_base` and `qwen3.rope.freq_base`)
+/// and the text displayed in parentheses. Add a row to describe another key.
+pub const KNOWN_CONFIG_KEYS: &[(&str, &str)] = &[
+    (".rope.freq_base", "rope freq base"),
+    (".rope.scaling.factor", "rope scaling factor"),
+    (".rope.scaling.attn_factor", "rope attention scaling factor"),
+    (".attention.layer_norm_epsilon", "layer norm epsilon"),
+    (".attention.layer_norm_rms_epsilon", "RMS norm epsilon"),
+    (".expert_weights_scale", "expert weights scale"),
+];
+
+/// Returns the description of a [`KNOWN_CONFIG_KEYS`] entry matching `key`.
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::known_config_key_context;
+///
+/// assert_eq!(known_config_key_context("llama.rope.freq_base"), Some("rope freq base"));
+/// assert_eq!(known_config_key_context("rope.freq_base"), None);
+/// ```
+pub fn known_config_key_context(key: &str) -> Option<&'static str> {
+    KNOWN_CONFIG_KEYS
+        .iter()
+        .find(|(suffix, _)| key.len() > suffix.len() && key.ends_with(suffix))
+        .map(|(_, context)| *context)
+}
+
+/// Key holding the ggml quantization format version (`GGML_QNT_VERSION`).
+pub const QUANTIZATION_VERSION_KEY: &str = "general.quantization_version";
+
+/// Known `general.quantization_version` values and the ggml quant families they cover.
+///
+/// Version 2 is still current: K-quants and I-quants were added without bumping it.
+pub const KNOWN_QUANTIZATION_VERSIONS: &[(u64, &str)] = &[
+    (0, "original ggml format: Q4_0, Q4_1, Q4_2, Q5_0, Q5_1, Q8_0"),
+    (1, "ggml v1 format: Q4_0, Q4_1, Q5_0, Q5_1, Q8_0"),
+    (2, "current ggml format: Q4_0–Q8_1, K-quants, I-quants"),
+];
+
+/// Labels a `general.quantization_version` value with its [`KNOWN_QUANTIZATION_VERSIONS`] description.
+///
+/// Unknown versions get `(unrecognized)`; non-integer values return `None`.
+///
+/// # Examples
+///
+/// ```
+/// use candle::quantized::gguf_file::Value;
+/// use inspector_gguf::format::quantization_version_label;
+///
+/// assert_eq!(
+///     quantization_version_label(&Value::U32(2)).as_deref(),
+///     Some("2 (current ggml format: Q4_0–Q8_1, K-quants, I-quants)")
+/// );
+/// assert_eq!(quantization_version_label(&Value::U32(9)).as_deref(), Some("9 (unrecognized)"));
+/// ```
+pub fn quantization_version_label(v: &gguf_file::Value) -> Option<String> {
+    let version = value_as_u64(v)?;
+    let description = KNOWN_QUANTIZATION_VERSIONS
+        .iter()
+        .find(|(known, _)| *known == version)
+        .map_or("unrecognized", |(_, description)| *description);
+    Some(format!("{} ({})", version, description))
+}
+
+/// Returns the raw value of a key whose display string carries a description.
+///
+/// Covers float [`KNOWN_CONFIG_KEYS`] values (`10000.0` for `10000 (rope freq base)`)
+/// and [`QUANTIZATION_VERSION_KEY`] (`2`). The GUI keeps this plain form as the
+/// entry's full value.
+pub fn known_config_raw_value(key: &str, v: &gguf_file::Value) -> Option<String> {
+    if key == QUANTIZATION_VERSION_KEY {
+        return value_as_u64(v).map(|version| version.to_string());
+    }
+    known_config_key_context(key)?;
+    matches!(v, gguf_file::Value::F32(_) | gguf_file::Value::F64(_)).then(|| array_element_string(v))
+}
+
+/// Shared implementation of the `readable_value_for_key*` family.
+///
+/// `annotate` appends the [`KNOWN_CONFIG_KEYS`] description to float values of known keys
+/// and labels [`QUANTIZATION_VERSION_KEY`] (see [`quantization_version_label`]).
+fn format_value(
+    key: &str,
+    v: &gguf_file::Value,
+    full_content: bool,
+    preview_limit: usize,
+    preview_chars: usize,
+    annotate: bool,
+) -> String {
+    // Custom formatters take precedence; the lock is released before calling them
+    if let Some(formatter) = custom_formatter_for(key) {
+        return formatter(key, v);
+    }
+
+    if annotate && let Some(context) = known_config_key_context(key) {
+        match v {
+            gguf_file::Value::F32(f) => return format!("{} ({})", f, context),
+            gguf_file::Value::F64(f) => return format!("{} ({})", f, context),
+            _ => {}
+        }
+    }
+    if annotate
+        && key == QUANTIZATION_VERSION_KEY
+        && let Some(label) = quantization_version_label(v)
+    {
+        return label;
+    }
+
+    // Special handling for tokenizer.chat_template - decode as UTF-8 string instead of base64
+    if key == "tokenizer.chat_template"
+        && matches!(v, gguf_file::Value::Array(_))
+        && let ReadableValue::Scalar(s) = typed_value_for_key(key, v)
+    {
+        return s;
+    }
+
+    // Special handling for tokenizer.ggml.tokens and tokenizer.ggml.merges - decode arrays of strings
+    if is_token_list_key(key)
+        && let ReadableValue::StringArray(strings) = typed_value_for_key(key, v)
+        && !strings.is_empty()
+    {
+        if full_content || preview_limit == 0 || strings.len() <= preview_limit + 2 {
+            return strings.join(", ");
+        } else {
+            let first_few = strings.iter().take(preview_limit).cloned().collect::<Vec<_>>().join(", ");
+            return format!("{}, …", first_few);
+        }
+    }
+
+    // Special handling for arrays - show examples like in real.md
+    if let gguf_file::Value::Array(arr) = v {
+        // Nothing to preview; the element branches below would render `, …`
+        if arr.is_empty() {
+            return "[]".to_string();
+        }
+
+        // If array of bytes (U8) - for tokenizer data, show as string preview
+        if !arr.is_empty() && arr.iter().all(|el| matches!(el, gguf_file::Value::U8(_))) {
+            let bytes_len = arr.len();
+            // Embedded preview images are summarized; the data itself is in the full content
+            if let Some(format) = detect_image_format(&leading_bytes(arr)) {
+                let name = if format == image::ImageFormat::Png { "PNG" } else { "JPEG" };
+                return format!("{} image ({} bytes)", name, bytes_len);
+            }
+            if bytes_len <= 64 {
+                // Try to decode as UTF-8 string
+                let bytes: Vec<u8> = arr.iter()
+                    .filter_map(|el| {
+                        if let gguf_file::Value::U8(b) = el {
+                            Some(*b)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                if let Ok(s) = String::from_utf8(bytes.clone()) {
+                    // Show first part of the string
+                    let preview = truncate_chars(&s, preview_chars);
+                    if preview.len() == s.len() {
+                        return s;
+                    } else {
+                        return format!("{}…", preview);
+                    }
+                } else {
+                    // If not valid UTF-8, show as hex
+                    let hex: String = bytes.iter().take(20).map(|b| format!("{:02x}", b)).collect();
+                    return format!("{}…", hex);
+                }
+            } else {
+                return format!("Array(len={})", bytes_len);
+            }
+        }
+
+        // Arrays of arrays (e.g. per-layer configs) keep their structure
+        if arr.iter().any(|el| matches!(el, gguf_file::Value::Array(_))) {
+            return nested_array_string(arr, 0);
+        }
+
+        // For other arrays, show first few elements like in real.md
+        if arr.len() <= 10 {
+            // Complete arrays have nothing left to elide
+            let parts: Vec<String> = arr.iter().map(array_element_string).collect();
+            return parts.join(", ");
+        } else {
+            // Show first 3 elements and indicate there are more
+            let first_parts: Vec<String> = arr.iter().take(3).map(array_element_string).collect();
+            return format!("{}, …", first_parts.join(", "));
+        }
+    }
+
+    // For scalar values, try the library-provided string representation
+    if let Ok(s) = v.to_string() {
+        return s.to_string();
+    }
+
+    // Fallback to debug representation
+    format!("{:?}", v)
+}
+
+/// Extracts full tokenizer content for tokenizer-related metadata keys.
+///
+/// This function determines if a metadata key is tokenizer-related and returns
+/// the complete, untruncated content if so. It's used internally by
+/// [`load_gguf_metadata_with_full_content_sync`] to provide full tokenizer data.
+///
+/// # Arguments
+///
+/// * `key` - The metadata key name to check
+/// * `v` - The GGUF value to extract content from
+///
+/// # Returns
+///
+/// - `Some(String)` containing full content if [`is_full_content_key`] accepts the key
+/// - `None` for every other key
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::get_full_tokenizer_content;
+/// use candle::quantized::gguf_file::Value;
+///
+/// let chat_template = Value::String("template content".to_string());
+///
+/// // Tokenizer keys return full content
+/// let result = get_full_tokenizer_content("tokenizer.chat_template", &chat_template);
+/// assert!(result.is_some());
+/// assert_eq!(result.unwrap(), "template content");
+///
+/// // Non-tokenizer keys return None
+/// let result = get_full_tokenizer_content("general.name", &chat_template);
 /// assert!(result.is_none());
 ///
 /// // Test various tokenizer key patterns
@@ -656,18 +1362,215 @@ pub fn readable_value_for_key_full(key: &str, v: &gguf_file::Value, full_content
 /// - `tokenizer.ggml.bos_token_id`
 /// - And any other keys with the "tokenizer." prefix
 ///
+/// Further prefixes, such as `general.added_tokens`, can be added with
+/// [`register_full_content_prefix`].
+///
 /// See also [`readable_value_for_key_full`] for full content formatting,
 /// [`load_gguf_metadata_with_full_content_sync`] for loading with full tokenizer content,
-/// and [`crate::gui::panels`] module for UI components that display tokenizer content.
+/// and `gui::panels` module for UI components that display tokenizer content.
 pub fn get_full_tokenizer_content(key: &str, v: &gguf_file::Value) -> Option<String> {
     // For tokenizer keys, return the full readable value without truncation
-    if key.starts_with("tokenizer.") {
+    if is_full_content_key(key) {
         Some(readable_value_for_key_full(key, v, true))
     } else {
         None
     }
 }
 
+/// Key prefix whose values are always kept in full by [`get_full_tokenizer_content`].
+pub const DEFAULT_FULL_CONTENT_PREFIX: &str = "tokenizer.";
+
+static FULL_CONTENT_PREFIXES: OnceLock<Mutex<Vec<String>>> = OnceLock::new();
+
+/// Registers an extra key prefix whose values [`get_full_tokenizer_content`] keeps in full.
+///
+/// Use this for models that store tokenizer data outside `tokenizer.`, so those
+/// keys get untruncated values for copying and the full-content viewers.
+/// Registration is global and thread-safe, like [`register_value_formatter`].
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::{is_full_content_key, register_full_content_prefix};
+///
+/// assert!(!is_full_content_key("example.vocab.extra"));
+/// register_full_content_prefix("example.vocab.");
+/// assert!(is_full_content_key("example.vocab.extra"));
+/// ```
+pub fn register_full_content_prefix(prefix: impl Into<String>) {
+    FULL_CONTENT_PREFIXES
+        .get_or_init(|| Mutex::new(Vec::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(prefix.into());
+}
+
+/// Returns `true` if `key` starts with [`DEFAULT_FULL_CONTENT_PREFIX`] or a
+/// prefix added with [`register_full_content_prefix`].
+pub fn is_full_content_key(key: &str) -> bool {
+    if key.starts_with(DEFAULT_FULL_CONTENT_PREFIX) {
+        return true;
+    }
+    FULL_CONTENT_PREFIXES.get().is_some_and(|prefixes| {
+        prefixes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .iter()
+            .any(|prefix| key.starts_with(prefix.as_str()))
+    })
+}
+
+/// `tokenizer.ggml.token_type` of control tokens such as `<|im_start|>`.
+const TOKEN_TYPE_CONTROL: u64 = 3;
+
+/// `tokenizer.ggml.token_type` of tokens added by the model author.
+const TOKEN_TYPE_USER_DEFINED: u64 = 4;
+
+/// Keys holding the ids of special tokens; these are always listed as added tokens.
+const SPECIAL_TOKEN_ID_KEYS: &[&str] = &[
+    "tokenizer.ggml.bos_token_id",
+    "tokenizer.ggml.eos_token_id",
+    "tokenizer.ggml.unknown_token_id",
+    "tokenizer.ggml.padding_token_id",
+    "tokenizer.ggml.separator_token_id",
+    "tokenizer.ggml.cls_token_id",
+    "tokenizer.ggml.mask_token_id",
+];
+
+/// Rebuilds a HuggingFace `tokenizer.json` from the tokenizer metadata of a GGUF file.
+///
+/// Only byte-level BPE tokenizers (`tokenizer.ggml.model = "gpt2"`) can be
+/// represented. For other tokenizer models, such as SentencePiece (`llama`), or
+/// files without a token list, the reason is printed to stderr and `None` is
+/// returned. See [`tokenizer_json_value`] for the document layout.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or is not a valid GGUF file.
+pub fn reconstruct_tokenizer_json(path: &std::path::Path) -> Result<Option<String>, BoxError> {
+    puffin::profile_scope!("reconstruct_tokenizer_json");
+    let file = File::open(path)?;
+    check_gguf_size(file.metadata()?.len())?;
+    let mut reader = std::io::BufReader::new(file);
+    let content = gguf_file::Content::read(&mut reader)?;
+
+    match tokenizer_json_value(&content.metadata) {
+        Ok(json) => Ok(Some(serde_json::to_string_pretty(&json)?)),
+        Err(reason) => {
+            eprintln!("Cannot rebuild tokenizer.json for {}: {}", path.display(), reason);
+            Ok(None)
+        }
+    }
+}
+
+/// Builds the `tokenizer.json` document from `tokenizer.ggml.*` metadata.
+///
+/// The vocabulary comes from `tokenizer.ggml.tokens` and the merge rules from
+/// `tokenizer.ggml.merges`. Control and user-defined tokens (`token_type` 3 and 4)
+/// and the tokens named by the `*_token_id` keys become `added_tokens`. The
+/// pre-tokenizer and decoder are byte-level, as for GPT-2 style models.
+///
+/// # Errors
+///
+/// Returns the reason the tokenizer cannot be represented: a model other than
+/// `gpt2`, or a missing or malformed token list.
+pub fn tokenizer_json_value(
+    metadata: &std::collections::HashMap<String, gguf_file::Value>,
+) -> Result<serde_json::Value, String> {
+    let model = match metadata.get("tokenizer.ggml.model") {
+        Some(gguf_file::Value::String(model)) => model.as_str(),
+        _ => return Err("no tokenizer.ggml.model key".to_string()),
+    };
+    if model != "gpt2" {
+        return Err(format!("tokenizer model \"{}\" is not supported, only BPE (gpt2)", model));
+    }
+
+    let tokens = string_array(metadata, "tokenizer.ggml.tokens")
+        .ok_or("tokenizer.ggml.tokens is missing or not a list of strings")?;
+    let merges = string_array(metadata, "tokenizer.ggml.merges").unwrap_or_default();
+    let token_types: Vec<u64> = match metadata.get("tokenizer.ggml.token_type") {
+        Some(gguf_file::Value::Array(types)) => types.iter().map(|t| value_as_u64(t).unwrap_or(1)).collect(),
+        _ => Vec::new(),
+    };
+    let token_id = |key: &str| metadata.get(key).and_then(value_as_u64);
+    let special_ids: Vec<u64> = SPECIAL_TOKEN_ID_KEYS.iter().filter_map(|key| token_id(key)).collect();
+
+    let vocab: serde_json::Map<String, serde_json::Value> = tokens
+        .iter()
+        .enumerate()
+        .map(|(id, token)| (token.to_string(), serde_json::Value::from(id)))
+        .collect();
+
+    let added_tokens: Vec<serde_json::Value> = tokens
+        .iter()
+        .enumerate()
+        .filter_map(|(id, token)| {
+            let token_type = token_types.get(id).copied().unwrap_or(1);
+            let special = token_type == TOKEN_TYPE_CONTROL || special_ids.contains(&(id as u64));
+            (special || token_type == TOKEN_TYPE_USER_DEFINED).then(|| {
+                serde_json::json!({
+                    "id": id,
+                    "content": token,
+                    "single_word": false,
+                    "lstrip": false,
+                    "rstrip": false,
+                    "normalized": !special,
+                    "special": special,
+                })
+            })
+        })
+        .collect();
+
+    let unk_token = token_id("tokenizer.ggml.unknown_token_id")
+        .and_then(|id| tokens.get(id as usize))
+        .map(|token| token.to_string());
+    let byte_level = serde_json::json!({
+        "type": "ByteLevel",
+        "add_prefix_space": false,
+        "trim_offsets": true,
+        "use_regex": true,
+    });
+
+    Ok(serde_json::json!({
+        "version": "1.0",
+        "truncation": null,
+        "padding": null,
+        "added_tokens": added_tokens,
+        "normalizer": null,
+        "pre_tokenizer": byte_level,
+        "post_processor": byte_level,
+        "decoder": byte_level,
+        "model": {
+            "type": "BPE",
+            "dropout": null,
+            "unk_token": unk_token,
+            "continuing_subword_prefix": null,
+            "end_of_word_suffix": null,
+            "fuse_unk": false,
+            "byte_fallback": false,
+            "vocab": vocab,
+            "merges": merges,
+        },
+    }))
+}
+
+/// Returns the strings of an array value, or `None` if any element is not a string.
+fn string_array<'a>(
+    metadata: &'a std::collections::HashMap<String, gguf_file::Value>,
+    key: &str,
+) -> Option<Vec<&'a str>> {
+    match metadata.get(key)? {
+        gguf_file::Value::Array(items) => items
+            .iter()
+            .map(|item| match item {
+                gguf_file::Value::String(s) => Some(s.as_str()),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
 /// Converts a GGUF value to a readable string without key context.
 ///
 /// This is a convenience function that calls [`readable_value_for_key`] with an empty
@@ -721,7 +1624,1840 @@ pub fn get_full_tokenizer_content(key: &str, v: &gguf_file::Value) -> Option<Str
 ///
 /// See also [`readable_value_for_key`] for context-aware formatting,
 /// [`readable_value_for_key_full`] for complete content display,
-/// and [`crate::gui::export`] module for exporting values in various formats.
+/// and `gui::export` module for exporting values in various formats.
 pub fn readable_value(v: &gguf_file::Value) -> String {
     readable_value_for_key("", v)
 }
+
+/// Returns the first few bytes of a `U8` array, enough for [`detect_image_format`].
+fn leading_bytes(arr: &[gguf_file::Value]) -> Vec<u8> {
+    arr.iter()
+        .take(8)
+        .map_while(|el| match el {
+            gguf_file::Value::U8(b) => Some(*b),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns the contents of a `U8` array value as the raw bytes candle parsed.
+///
+/// Any other value, including arrays of other element types, yields `None`.
+/// An empty array has no element type in candle's [`gguf_file::Value`] and yields
+/// empty bytes.
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::value_to_raw_bytes;
+/// use candle::quantized::gguf_file::Value;
+///
+/// let bytes = Value::Array(vec![Value::U8(0x47), Value::U8(0xff)]);
+/// assert_eq!(value_to_raw_bytes(&bytes), Some(vec![0x47, 0xff]));
+/// assert_eq!(value_to_raw_bytes(&Value::String("GGUF".into())), None);
+/// ```
+pub fn value_to_raw_bytes(v: &gguf_file::Value) -> Option<Vec<u8>> {
+    match v {
+        gguf_file::Value::Array(arr) => arr
+            .iter()
+            .map(|el| match el {
+                gguf_file::Value::U8(b) => Some(*b),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+/// Number of bytes shown on each row of [`hex_dump`].
+pub const HEX_DUMP_WIDTH: usize = 16;
+
+/// Formats one [`hex_dump`] row: the offset, up to [`HEX_DUMP_WIDTH`] bytes as
+/// hex in pairs, and the printable ASCII characters, with `.` for the rest.
+///
+/// Short rows are padded so the ASCII column stays aligned.
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::hex_dump_line;
+///
+/// assert_eq!(hex_dump_line(0x20, b"GGUF\x03"), "00000020: 4747 5546 03                             GGUF.");
+/// ```
+pub fn hex_dump_line(offset: usize, chunk: &[u8]) -> String {
+    let hex = chunk
+        .chunks(2)
+        .map(|pair| pair.iter().map(|b| format!("{:02x}", b)).collect::<String>())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let ascii: String = chunk
+        .iter()
+        .map(|&b| if b == b' ' || b.is_ascii_graphic() { b as char } else { '.' })
+        .collect();
+    // Width of a full row of hex: two digits per byte plus a space between pairs
+    let hex_width = HEX_DUMP_WIDTH * 2 + HEX_DUMP_WIDTH / 2 - 1;
+    format!("{:08x}: {:<hex_width$}  {}", offset, hex, ascii)
+}
+
+/// Formats `bytes` like `xxd`, one [`hex_dump_line`] per [`HEX_DUMP_WIDTH`] bytes.
+///
+/// Every row ends with a newline; empty input yields an empty string.
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::hex_dump;
+///
+/// assert_eq!(hex_dump(b"GGUF"), "00000000: 4747 5546                                GGUF\n");
+/// ```
+pub fn hex_dump(bytes: &[u8]) -> String {
+    bytes
+        .chunks(HEX_DUMP_WIDTH)
+        .enumerate()
+        .map(|(row, chunk)| hex_dump_line(row * HEX_DUMP_WIDTH, chunk) + "\n")
+        .collect()
+}
+
+/// Detects PNG and JPEG data from its leading magic bytes.
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::detect_image_format;
+/// use image::ImageFormat;
+///
+/// assert_eq!(detect_image_format(b"\x89PNG\r\n\x1a\n"), Some(ImageFormat::Png));
+/// assert_eq!(detect_image_format(&[0xFF, 0xD8, 0xFF, 0xE0]), Some(ImageFormat::Jpeg));
+/// assert_eq!(detect_image_format(b"GGUF"), None);
+/// ```
+pub fn detect_image_format(bytes: &[u8]) -> Option<image::ImageFormat> {
+    const PNG_MAGIC: &[u8] = b"\x89PNG\r\n\x1a\n";
+    const JPEG_MAGIC: &[u8] = &[0xFF, 0xD8, 0xFF];
+
+    if bytes.starts_with(PNG_MAGIC) {
+        Some(image::ImageFormat::Png)
+    } else if bytes.starts_with(JPEG_MAGIC) {
+        Some(image::ImageFormat::Jpeg)
+    } else {
+        None
+    }
+}
+
+/// Encodes an embedded PNG/JPEG byte array as a `data:` URI.
+///
+/// Returns `None` for values that are not byte arrays starting with image magic
+/// bytes. Loaders store the URI as the entry's full content so the GUI can show
+/// the image (see `gui::panels::dialogs::render_image_panel`).
+pub fn image_data_uri(v: &gguf_file::Value) -> Option<String> {
+    use base64::Engine;
+
+    let gguf_file::Value::Array(arr) = v else {
+        return None;
+    };
+    let format = detect_image_format(&leading_bytes(arr))?;
+    let bytes = value_to_raw_bytes(v)?;
+    Some(format!(
+        "data:{};base64,{}",
+        format.to_mime_type(),
+        base64::engine::general_purpose::STANDARD.encode(&bytes)
+    ))
+}
+
+/// High-level description of a model derived from its GGUF metadata and tensors.
+///
+/// Produced by [`model_summary`]; every metadata-derived field is optional because
+/// GGUF files are not required to carry them.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct ModelSummary {
+    /// Model name from `general.name`.
+    pub name: Option<String>,
+    /// Architecture from `general.architecture` (e.g. `llama`, `qwen3`).
+    pub architecture: Option<String>,
+    /// Total number of parameters across all tensors.
+    pub parameter_count: u64,
+    /// Context length from `<architecture>.context_length`.
+    pub context_length: Option<u64>,
+    /// Vocabulary size from `<architecture>.vocab_size` or the token list length.
+    pub vocab_size: Option<u64>,
+    /// Number of tensors in the file.
+    pub tensor_count: usize,
+}
+
+/// Converts any integer GGUF value to `u64`, rejecting negative numbers.
+fn value_as_u64(v: &gguf_file::Value) -> Option<u64> {
+    match v {
+        gguf_file::Value::U8(x) => Some(*x as u64),
+        gguf_file::Value::U16(x) => Some(*x as u64),
+        gguf_file::Value::U32(x) => Some(*x as u64),
+        gguf_file::Value::U64(x) => Some(*x),
+        gguf_file::Value::I8(x) => u64::try_from(*x).ok(),
+        gguf_file::Value::I16(x) => u64::try_from(*x).ok(),
+        gguf_file::Value::I32(x) => u64::try_from(*x).ok(),
+        gguf_file::Value::I64(x) => u64::try_from(*x).ok(),
+        _ => None,
+    }
+}
+
+/// Builds a [`ModelSummary`] from parsed GGUF content.
+///
+/// Architecture-specific keys are looked up with the `general.architecture`
+/// prefix; when `<architecture>.vocab_size` is absent, the vocabulary size falls
+/// back to the length of `tokenizer.ggml.tokens`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use inspector_gguf::format::model_summary;
+///
+/// let mut file = std::fs::File::open("model.gguf")?;
+/// let content = candle::quantized::gguf_file::Content::read(&mut file)?;
+/// let summary = model_summary(&content);
+/// println!("{:?} with {} parameters", summary.architecture, summary.parameter_count);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn model_summary(content: &gguf_file::Content) -> ModelSummary {
+    let string_value = |key: &str| content.metadata.get(key).and_then(|v| v.to_string().ok()).cloned();
+    let name = string_value("general.name");
+    let architecture = string_value("general.architecture");
+
+    let arch_value = |suffix: &str| {
+        architecture
+            .as_ref()
+            .and_then(|arch| content.metadata.get(&format!("{}.{}", arch, suffix)))
+            .and_then(value_as_u64)
+    };
+    let context_length = arch_value("context_length");
+    let vocab_size = arch_value("vocab_size").or_else(|| match content.metadata.get("tokenizer.ggml.tokens") {
+        Some(gguf_file::Value::Array(tokens)) => Some(tokens.len() as u64),
+        _ => None,
+    });
+
+    let parameter_count = content
+        .tensor_infos
+        .values()
+        .map(|info| info.shape.elem_count() as u64)
+        .sum();
+
+    ModelSummary {
+        name,
+        architecture,
+        parameter_count,
+        context_length,
+        vocab_size,
+        tensor_count: content.tensor_infos.len(),
+    }
+}
+
+/// Estimates the size in bytes of all tensor data in the file.
+///
+/// Each tensor contributes `elements / block_size * type_size` bytes for its
+/// quantization type, which matches the on-disk size without alignment padding.
+pub fn estimate_model_size(content: &gguf_file::Content) -> u64 {
+    content
+        .tensor_infos
+        .values()
+        .map(|info| {
+            let dtype = info.ggml_dtype;
+            (info.shape.elem_count() / dtype.block_size() * dtype.type_size()) as u64
+        })
+        .sum()
+}
+
+/// Per-quantization-type statistics reported by [`quant_breakdown`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct QuantStats {
+    /// Quantization type name (e.g. `Q5K`, `F32`).
+    pub dtype: String,
+    /// Number of tensors stored with this type.
+    pub tensor_count: usize,
+    /// Number of parameters stored with this type.
+    pub parameter_count: u64,
+}
+
+/// Groups tensors by quantization type, largest parameter share first.
+pub fn quant_breakdown(content: &gguf_file::Content) -> Vec<QuantStats> {
+    let mut by_dtype: std::collections::BTreeMap<String, QuantStats> = std::collections::BTreeMap::new();
+    for info in content.tensor_infos.values() {
+        let dtype = format!("{:?}", info.ggml_dtype);
+        let stats = by_dtype.entry(dtype.clone()).or_insert(QuantStats { dtype, tensor_count: 0, parameter_count: 0 });
+        stats.tensor_count += 1;
+        stats.parameter_count += info.shape.elem_count() as u64;
+    }
+
+    let mut breakdown: Vec<QuantStats> = by_dtype.into_values().collect();
+    breakdown.sort_by(|a, b| b.parameter_count.cmp(&a.parameter_count));
+    breakdown
+}
+
+/// Description of one tensor returned by [`load_gguf_tensor_info_sync`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct TensorInfoEntry {
+    /// Tensor name (e.g. `blk.0.attn_q.weight`).
+    pub name: String,
+    /// Quantization type name (e.g. `Q5K`, `F32`).
+    pub dtype: String,
+    /// Tensor dimensions.
+    pub shape: Vec<usize>,
+    /// Number of elements in the tensor.
+    pub element_count: u64,
+    /// Offset of the tensor data relative to the start of the data section.
+    pub offset: u64,
+    /// Size of the tensor data in bytes (`elements / block_size * type_size`).
+    pub byte_size: u64,
+}
+
+/// Lists the tensors of parsed GGUF content, ordered by name.
+pub fn tensor_info_entries(content: &gguf_file::Content) -> Vec<TensorInfoEntry> {
+    let mut entries: Vec<TensorInfoEntry> = content
+        .tensor_infos
+        .iter()
+        .map(|(name, info)| TensorInfoEntry {
+            name: name.clone(),
+            dtype: format!("{:?}", info.ggml_dtype),
+            shape: info.shape.dims().to_vec(),
+            element_count: info.shape.elem_count() as u64,
+            offset: info.offset,
+            byte_size: (info.shape.elem_count() / info.ggml_dtype.block_size() * info.ggml_dtype.type_size()) as u64,
+        })
+        .collect();
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    entries
+}
+
+/// Loads the tensor descriptions of a GGUF file without reading the tensor data.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened or is not a valid GGUF file.
+pub fn load_gguf_tensor_info_sync(path: &std::path::Path) -> Result<Vec<TensorInfoEntry>, BoxError> {
+    puffin::profile_scope!("load_gguf_tensor_info_sync");
+    let file = File::open(path)?;
+    check_gguf_size(file.metadata()?.len())?;
+    let mut reader = std::io::BufReader::new(file);
+    let content = gguf_file::Content::read(&mut reader)?;
+    Ok(tensor_info_entries(&content))
+}
+
+/// GGUF file kept open after parsing, for reading individual tensors.
+///
+/// Metadata and tensor descriptions are parsed once by [`GgufReader::open`];
+/// [`GgufReader::read_tensor_bytes`] then seeks straight to one tensor's data
+/// at the data section offset plus the tensor's own offset, without reopening
+/// the file or reading the rest of the data.
+///
+/// # Examples
+///
+/// ```no_run
+/// use inspector_gguf::format::GgufReader;
+/// use std::path::Path;
+///
+/// let mut reader = GgufReader::open(Path::new("model.gguf"))?;
+/// let bytes = reader.read_tensor_bytes("output_norm.weight")?;
+/// let norms: Vec<f32> = bytes.chunks_exact(4).map(|b| f32::from_le_bytes(b.try_into().unwrap())).collect();
+/// println!("NaNs: {}", norms.iter().filter(|x| x.is_nan()).count());
+/// # Ok::<(), inspector_gguf::format::BoxError>(())
+/// ```
+pub struct GgufReader {
+    file: File,
+    content: gguf_file::Content,
+    tensors: Vec<TensorInfoEntry>,
+}
+
+impl GgufReader {
+    /// Opens and parses `path`, keeping the file handle for tensor reads.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or is not a valid GGUF file.
+    pub fn open(path: &std::path::Path) -> Result<Self, BoxError> {
+        puffin::profile_scope!("GgufReader::open");
+        let mut file = File::open(path)?;
+        check_gguf_size(file.metadata()?.len())?;
+        let content = gguf_file::Content::read(&mut std::io::BufReader::new(&mut file))?;
+        let tensors = tensor_info_entries(&content);
+        Ok(Self { file, content, tensors })
+    }
+
+    /// Returns the parsed metadata and tensor descriptions.
+    pub fn content(&self) -> &gguf_file::Content {
+        &self.content
+    }
+
+    /// Returns the tensor names, sorted.
+    pub fn tensor_names(&self) -> Vec<&str> {
+        self.tensors.iter().map(|entry| entry.name.as_str()).collect()
+    }
+
+    /// Returns the description of the tensor called `name`.
+    pub fn tensor_info(&self, name: &str) -> Option<&TensorInfoEntry> {
+        self.tensors
+            .binary_search_by(|entry| entry.name.as_str().cmp(name))
+            .ok()
+            .map(|index| &self.tensors[index])
+    }
+
+    /// Reads the raw, still quantized, data of the tensor called `name`.
+    ///
+    /// The result is [`TensorInfoEntry::byte_size`] bytes long.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no such tensor or the file is shorter than
+    /// the tensor's data. The range is checked against the file length before
+    /// anything is allocated, so a crafted header cannot force a huge allocation.
+    pub fn read_tensor_bytes(&mut self, name: &str) -> Result<Vec<u8>, BoxError> {
+        use std::io::{Seek, SeekFrom};
+
+        let Some(info) = self.tensor_info(name) else {
+            return Err(format!("Unknown tensor: {}", name).into());
+        };
+        let file_len = self.file.metadata()?.len();
+        let start = self.content.tensor_data_offset.checked_add(info.offset);
+        let end = start.and_then(|start| start.checked_add(info.byte_size));
+        let (Some(start), Some(end)) = (start, end) else {
+            return Err(format!("Tensor {} has an out-of-range offset", name).into());
+        };
+        if end > file_len {
+            return Err(format!(
+                "Tensor {} ends at byte {}, past the end of the {}-byte file",
+                name, end, file_len
+            )
+            .into());
+        }
+        let len = usize::try_from(info.byte_size)?;
+        let mut bytes = vec![0u8; len];
+        self.file.seek(SeekFrom::Start(start))?;
+        self.file.read_exact(&mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+/// Concise model report printed by `gguf-inspector --info`.
+///
+/// The [`std::fmt::Display`] implementation renders the short human-readable
+/// summary; serializing with serde produces the `--info --json` output.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct ModelReport {
+    /// Metadata-derived model description.
+    #[serde(flatten)]
+    pub summary: ModelSummary,
+    /// Estimated size of all tensor data in bytes.
+    pub estimated_size: u64,
+    /// Tensors grouped by quantization type.
+    pub quantization: Vec<QuantStats>,
+    /// Header counts that disagree with the parsed content, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count_mismatch: Option<CountMismatch>,
+}
+
+impl ModelReport {
+    /// Builds a report from parsed GGUF content.
+    pub fn from_content(content: &gguf_file::Content) -> Self {
+        Self {
+            summary: model_summary(content),
+            estimated_size: estimate_model_size(content),
+            quantization: quant_breakdown(content),
+            count_mismatch: None,
+        }
+    }
+}
+
+/// Formats a count with a `K`/`M`/`B` suffix (e.g. `751.63M`).
+pub(crate) fn format_count(n: u64) -> String {
+    match n {
+        n if n >= 1_000_000_000 => format!("{:.2}B", n as f64 / 1e9),
+        n if n >= 1_000_000 => format!("{:.2}M", n as f64 / 1e6),
+        n if n >= 1_000 => format!("{:.2}K", n as f64 / 1e3),
+        n => n.to_string(),
+    }
+}
+
+/// Formats a byte count with decimal units and one decimal place (e.g. `412.3 MB`).
+///
+/// Counts below 1000 are shown exactly; larger ones are scaled to KB, MB, GB or TB.
+/// Used for every byte size the CLI and GUI show, including the `--info` estimate.
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::human_bytes;
+///
+/// assert_eq!(human_bytes(512), "512 B");
+/// assert_eq!(human_bytes(412_300_000), "412.3 MB");
+/// ```
+pub fn human_bytes(n: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = n as f64;
+    let mut unit = 0;
+    // 999.95 rounds to 1000.0 at one decimal, so it already belongs to the next unit
+    while value >= 999.95 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    if unit == 0 { format!("{} B", n) } else { format!("{:.1} {}", value, UNITS[unit]) }
+}
+
+impl std::fmt::Display for ModelReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unknown = "unknown".to_string();
+        let optional = |v: Option<u64>| v.map(|n| n.to_string()).unwrap_or_else(|| unknown.clone());
+        let summary = &self.summary;
+
+        writeln!(f, "Name:           {}", summary.name.as_ref().unwrap_or(&unknown))?;
+        writeln!(f, "Architecture:   {}", summary.architecture.as_ref().unwrap_or(&unknown))?;
+        writeln!(
+            f,
+            "Parameters:     {} ({})",
+            format_count(summary.parameter_count),
+            summary.parameter_count
+        )?;
+        writeln!(f, "Tensors:        {}", summary.tensor_count)?;
+        writeln!(f, "Estimated size: {}", human_bytes(self.estimated_size))?;
+        writeln!(f, "Context length: {}", optional(summary.context_length))?;
+        writeln!(f, "Vocab size:     {}", optional(summary.vocab_size))?;
+        writeln!(f, "Quantization:")?;
+        for stats in &self.quantization {
+            let share = if summary.parameter_count == 0 {
+                0.0
+            } else {
+                stats.parameter_count as f64 * 100.0 / summary.parameter_count as f64
+            };
+            writeln!(
+                f,
+                "  {:<8} {:>6} tensors {:>10} params {:>6.2}%",
+                stats.dtype,
+                stats.tensor_count,
+                format_count(stats.parameter_count),
+                share
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads a GGUF file and builds its [`ModelReport`].
+///
+/// Only the header, metadata, and tensor descriptors are read; tensor data is
+/// never loaded, so this is cheap even for multi-gigabyte models.
+///
+/// # Errors
+///
+/// Returns a [`GgufError`] for empty or truncated files, and an I/O or parser
+/// error if the file cannot be opened or is not a valid GGUF file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use inspector_gguf::format::build_model_report;
+/// use std::path::Path;
+///
+/// let report = build_model_report(Path::new("model.gguf"))?;
+/// print!("{}", report);
+/// # Ok::<(), inspector_gguf::format::BoxError>(())
+/// ```
+pub fn build_model_report(path: &std::path::Path) -> Result<ModelReport, BoxError> {
+    use std::io::{Seek, SeekFrom};
+
+    puffin::profile_scope!("build_model_report");
+    let file = File::open(path)?;
+    check_gguf_size(file.metadata()?.len())?;
+    let mut reader = std::io::BufReader::new(file);
+    let mut header = [0u8; GGUF_HEADER_SIZE as usize];
+    reader.read_exact(&mut header)?;
+    reader.seek(SeekFrom::Start(0))?;
+    let content = gguf_file::Content::read(&mut reader)?;
+    Ok(ModelReport {
+        count_mismatch: check_header_counts(&header, &content),
+        ..ModelReport::from_content(&content)
+    })
+}
+
+/// Summary of one GGUF file found by [`scan_directory`].
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct FileScanResult {
+    /// Path of the scanned file.
+    pub path: std::path::PathBuf,
+    /// File size in bytes.
+    pub size: u64,
+    /// Model report, or `None` if the file could not be parsed.
+    pub report: Option<ModelReport>,
+    /// Parse error message when `report` is `None`.
+    pub error: Option<String>,
+}
+
+impl FileScanResult {
+    /// Returns the file name for display.
+    pub fn file_name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| self.path.display().to_string())
+    }
+
+    /// Returns the architecture, if the file parsed and declares one.
+    pub fn architecture(&self) -> Option<&str> {
+        self.report.as_ref()?.summary.architecture.as_deref()
+    }
+
+    /// Returns the total parameter count, if the file parsed.
+    pub fn parameter_count(&self) -> Option<u64> {
+        self.report.as_ref().map(|report| report.summary.parameter_count)
+    }
+
+    /// Describes the quantization mix as `TYPE share%` entries, largest first.
+    ///
+    /// Types holding less than 1% of the parameters are omitted.
+    pub fn quant_summary(&self) -> String {
+        let Some(report) = &self.report else {
+            return String::new();
+        };
+        let total = report.summary.parameter_count.max(1) as f64;
+        report
+            .quantization
+            .iter()
+            .map(|stats| (stats, stats.parameter_count as f64 / total * 100.0))
+            .filter(|(_, share)| *share >= 1.0)
+            .map(|(stats, share)| format!("{} {:.0}%", stats.dtype, share))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Most files [`scan_directory`] parses at once, which also bounds its open file handles.
+pub const MAX_SCAN_THREADS: usize = 8;
+
+/// Scans a directory (non-recursively) and summarizes every `.gguf` file in it.
+///
+/// Files are parsed concurrently on up to [`MAX_SCAN_THREADS`] threads (see
+/// [`scan_files_parallel`]) and returned sorted by name. Files that fail to parse
+/// are still listed, with [`FileScanResult::error`] set. An unreadable directory
+/// yields an empty list.
+///
+/// # Examples
+///
+/// ```no_run
+/// use inspector_gguf::format::scan_directory;
+/// use std::path::Path;
+///
+/// for file in scan_directory(Path::new("models")) {
+///     println!("{}: {:?}", file.file_name(), file.architecture());
+/// }
+/// ```
+pub fn scan_directory(dir: &std::path::Path) -> Vec<FileScanResult> {
+    scan_directory_with_progress(dir, |_, _| {})
+}
+
+/// Same as [`scan_directory`], calling `progress(done, total)` after each file.
+pub fn scan_directory_with_progress(
+    dir: &std::path::Path,
+    progress: impl FnMut(usize, usize),
+) -> Vec<FileScanResult> {
+    puffin::profile_scope!("scan_directory");
+    let paths = gguf_files_in(dir).unwrap_or_default();
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get()).min(MAX_SCAN_THREADS);
+    scan_files_parallel(&paths, threads, progress)
+}
+
+/// Returns `true` if `path` has one of `extensions` (case-insensitive, without the dot).
+pub fn has_extension(path: &std::path::Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| extensions.iter().any(|wanted| ext.eq_ignore_ascii_case(wanted)))
+}
+
+/// Returns `true` if `path` has a `.gguf` extension (case-insensitive).
+pub fn has_gguf_extension(path: &std::path::Path) -> bool {
+    has_extension(path, &["gguf"])
+}
+
+/// Lists the `.gguf` files directly inside `dir`, sorted by path.
+///
+/// Subdirectories are not searched; see `cli::find_files` for recursive scans.
+///
+/// # Errors
+///
+/// Returns an error if `dir` cannot be read.
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::gguf_files_in;
+///
+/// let dir = tempfile::tempdir()?;
+/// std::fs::write(dir.path().join("b.gguf"), b"")?;
+/// std::fs::write(dir.path().join("a.GGUF"), b"")?;
+/// std::fs::write(dir.path().join("notes.txt"), b"")?;
+///
+/// let files = gguf_files_in(dir.path())?;
+/// assert_eq!(files, [dir.path().join("a.GGUF"), dir.path().join("b.gguf")]);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn gguf_files_in(dir: &std::path::Path) -> std::io::Result<Vec<std::path::PathBuf>> {
+    let mut paths: Vec<std::path::PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && has_gguf_extension(path))
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// Summarizes one file for [`scan_files_parallel`].
+fn scan_file(path: &std::path::Path) -> FileScanResult {
+    let size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    let (report, error) = match build_model_report(path) {
+        Ok(report) => (Some(report), None),
+        Err(e) => (None, Some(e.to_string())),
+    };
+    FileScanResult { path: path.to_path_buf(), size, report, error }
+}
+
+/// Summarizes `paths` on up to `threads` worker threads.
+///
+/// Workers take the next unscanned path until none are left, so at most
+/// `threads` files are open at once. Results are returned in the order of
+/// `paths`, whatever order the files finish in; `progress(done, total)` is
+/// called on the calling thread after each file. `threads` of 0 is treated as 1.
+pub fn scan_files_parallel(
+    paths: &[std::path::PathBuf],
+    threads: usize,
+    mut progress: impl FnMut(usize, usize),
+) -> Vec<FileScanResult> {
+    let total = paths.len();
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut results: Vec<Option<FileScanResult>> = vec![None; total];
+
+    std::thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, total.max(1)) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(path) = paths.get(index) else { break };
+                    if sender.send((index, scan_file(path))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+        for (done, (index, result)) in receiver.iter().enumerate() {
+            results[index] = Some(result);
+            progress(done + 1, total);
+        }
+    });
+    results.into_iter().flatten().collect()
+}
+
+/// One difference found by [`diff_metadata`] between expected and actual metadata.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MetadataDiff {
+    /// Key expected but absent from the actual metadata.
+    Missing {
+        /// Metadata key.
+        key: String,
+        /// Expected value.
+        expected: String,
+    },
+    /// Key present in the actual metadata but not expected.
+    Extra {
+        /// Metadata key.
+        key: String,
+        /// Actual value.
+        actual: String,
+    },
+    /// Key present on both sides with different values.
+    Changed {
+        /// Metadata key.
+        key: String,
+        /// Expected value.
+        expected: String,
+        /// Actual value.
+        actual: String,
+    },
+}
+
+impl MetadataDiff {
+    /// Returns the metadata key the difference refers to.
+    pub fn key(&self) -> &str {
+        match self {
+            MetadataDiff::Missing { key, .. }
+            | MetadataDiff::Extra { key, .. }
+            | MetadataDiff::Changed { key, .. } => key,
+        }
+    }
+}
+
+/// Renders the difference as a single diff-style line (`-`, `+` or `~`).
+impl std::fmt::Display for MetadataDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MetadataDiff::Missing { key, expected } => write!(f, "- {}: {}", key, expected),
+            MetadataDiff::Extra { key, actual } => write!(f, "+ {}: {}", key, actual),
+            MetadataDiff::Changed { key, expected, actual } => write!(f, "~ {}: {} -> {}", key, expected, actual),
+        }
+    }
+}
+
+/// Placeholder that [`normalize_key`] puts in place of the architecture prefix.
+pub const ARCH_KEY_TOKEN: &str = "<arch>";
+
+/// Replaces the architecture prefix of `key` with [`ARCH_KEY_TOKEN`].
+///
+/// Architecture-specific keys such as `llama.block_count` and `qwen2.block_count`
+/// describe the same setting, so normalized keys line up when metadata of models
+/// with different architectures is aggregated or diffed. Keys without the
+/// `<architecture>.` prefix, such as `general.name`, are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::normalize_key;
+///
+/// assert_eq!(normalize_key("llama.block_count", "llama"), "<arch>.block_count");
+/// assert_eq!(normalize_key("qwen2.block_count", "qwen2"), "<arch>.block_count");
+/// assert_eq!(normalize_key("general.name", "llama"), "general.name");
+/// ```
+pub fn normalize_key(key: &str, architecture: &str) -> String {
+    match key.strip_prefix(architecture).and_then(|rest| rest.strip_prefix('.')) {
+        Some(rest) if !architecture.is_empty() => format!("{}.{}", ARCH_KEY_TOKEN, rest),
+        _ => key.to_string(),
+    }
+}
+
+/// Compares two metadata lists and returns their differences, ordered by key.
+///
+/// # Examples
+///
+/// ```
+/// use inspector_gguf::format::{diff_metadata, MetadataDiff};
+///
+/// let expected = vec![("general.name".to_string(), "Qwen3".to_string())];
+/// let actual = vec![("general.name".to_string(), "Qwen3-0.6B".to_string())];
+///
+/// let diffs = diff_metadata(&expected, &actual);
+/// assert_eq!(diffs.len(), 1);
+/// assert_eq!(diffs[0].to_string(), "~ general.name: Qwen3 -> Qwen3-0.6B");
+/// assert!(diff_metadata(&expected, &expected).is_empty());
+/// ```
+pub fn diff_metadata(expected: &[(String, String)], actual: &[(String, String)]) -> Vec<MetadataDiff> {
+    use std::collections::BTreeMap;
+
+    let expected: BTreeMap<&str, &str> = expected.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+    let actual: BTreeMap<&str, &str> = actual.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+
+    let mut diffs = Vec::new();
+    for (key, expected_value) in &expected {
+        match actual.get(key) {
+            None => diffs.push(MetadataDiff::Missing {
+                key: key.to_string(),
+                expected: expected_value.to_string(),
+            }),
+            Some(actual_value) if actual_value != expected_value => diffs.push(MetadataDiff::Changed {
+                key: key.to_string(),
+                expected: expected_value.to_string(),
+                actual: actual_value.to_string(),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (key, actual_value) in &actual {
+        if !expected.contains_key(key) {
+            diffs.push(MetadataDiff::Extra {
+                key: key.to_string(),
+                actual: actual_value.to_string(),
+            });
+        }
+    }
+    diffs.sort_by(|a, b| a.key().cmp(b.key()));
+    diffs
+}
+
+/// Returns the GGUF on-disk type identifier for a metadata value.
+#[cfg(any(test, feature = "test-util"))]
+fn test_value_type_id(value: &gguf_file::Value) -> u32 {
+    match value {
+        gguf_file::Value::U8(_) => 0,
+        gguf_file::Value::I8(_) => 1,
+        gguf_file::Value::U16(_) => 2,
+        gguf_file::Value::I16(_) => 3,
+        gguf_file::Value::U32(_) => 4,
+        gguf_file::Value::I32(_) => 5,
+        gguf_file::Value::F32(_) => 6,
+        gguf_file::Value::Bool(_) => 7,
+        gguf_file::Value::String(_) => 8,
+        gguf_file::Value::Array(_) => 9,
+        gguf_file::Value::U64(_) => 10,
+        gguf_file::Value::I64(_) => 11,
+        gguf_file::Value::F64(_) => 12,
+    }
+}
+
+/// Serializes a metadata value payload (without its type tag).
+#[cfg(any(test, feature = "test-util"))]
+fn write_test_value(buf: &mut Vec<u8>, value: &gguf_file::Value) {
+    match value {
+        gguf_file::Value::U8(v) => buf.push(*v),
+        gguf_file::Value::I8(v) => buf.extend_from_slice(&v.to_le_bytes()),
+        gguf_file::Value::U16(v) => buf.extend_from_slice(&v.to_le_bytes()),
+        gguf_file::Value::I16(v) => buf.extend_from_slice(&v.to_le_bytes()),
+        gguf_file::Value::U32(v) => buf.extend_from_slice(&v.to_le_bytes()),
+        gguf_file::Value::I32(v) => buf.extend_from_slice(&v.to_le_bytes()),
+        gguf_file::Value::F32(v) => buf.extend_from_slice(&v.to_le_bytes()),
+        gguf_file::Value::Bool(v) => buf.push(u8::from(*v)),
+        gguf_file::Value::String(v) => {
+            buf.extend_from_slice(&(v.len() as u64).to_le_bytes());
+            buf.extend_from_slice(v.as_bytes());
+        }
+        gguf_file::Value::Array(items) => {
+            let item_type = items.first().map(test_value_type_id).unwrap_or(0);
+            buf.extend_from_slice(&item_type.to_le_bytes());
+            buf.extend_from_slice(&(items.len() as u64).to_le_bytes());
+            for item in items {
+                write_test_value(buf, item);
+            }
+        }
+        gguf_file::Value::U64(v) => buf.extend_from_slice(&v.to_le_bytes()),
+        gguf_file::Value::I64(v) => buf.extend_from_slice(&v.to_le_bytes()),
+        gguf_file::Value::F64(v) => buf.extend_from_slice(&v.to_le_bytes()),
+    }
+}
+
+/// Synthesizes a minimal valid GGUF v3 buffer with the given metadata and no tensors.
+///
+/// Lets tests exercise the parsing pipeline deterministically without the
+/// bundled sample model or temporary files. Only built for tests and with the
+/// `test-util` feature, which the benchmarks in `benches/` require.
+#[cfg(any(test, feature = "test-util"))]
+pub fn make_test_gguf(metadata: &[(&str, gguf_file::Value)]) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(b"GGUF");
+    buf.extend_from_slice(&3u32.to_le_bytes());
+    buf.extend_from_slice(&0u64.to_le_bytes());
+    buf.extend_from_slice(&(metadata.len() as u64).to_le_bytes());
+    for (key, value) in metadata {
+        buf.extend_from_slice(&(key.len() as u64).to_le_bytes());
+        buf.extend_from_slice(key.as_bytes());
+        buf.extend_from_slice(&test_value_type_id(value).to_le_bytes());
+        write_test_value(&mut buf, value);
+    }
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Compile-time check: loader results can cross threads and async task boundaries
+    const _: () = {
+        const fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<BoxError>();
+        assert_send_sync::<GgufError>();
+        assert_send_sync::<Result<Vec<(String, String, Option<String>)>, BoxError>>();
+        assert_send_sync::<Result<ModelReport, BoxError>>();
+    };
+
+    fn find<'a>(entries: &'a [(String, String, Option<String>)], key: &str) -> &'a (String, String, Option<String>) {
+        entries
+            .iter()
+            .find(|(k, _, _)| k == key)
+            .unwrap_or_else(|| panic!("Key {} should be present", key))
+    }
+
+    /// Builds a GGUF file holding `values` as a single F32 tensor called `name`.
+    fn tensor_gguf(name: &str, values: &[f32]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(b"GGUF");
+        buf.extend_from_slice(&3u32.to_le_bytes());
+        buf.extend_from_slice(&1u64.to_le_bytes());
+        buf.extend_from_slice(&0u64.to_le_bytes());
+        buf.extend_from_slice(&(name.len() as u64).to_le_bytes());
+        buf.extend_from_slice(name.as_bytes());
+        buf.extend_from_slice(&1u32.to_le_bytes());
+        buf.extend_from_slice(&(values.len() as u64).to_le_bytes());
+        buf.extend_from_slice(&0u32.to_le_bytes()); // GGML_TYPE_F32
+        buf.extend_from_slice(&0u64.to_le_bytes());
+        // The data section starts at the default 32-byte alignment
+        buf.resize(buf.len().div_ceil(32) * 32, 0);
+        for value in values {
+            buf.extend_from_slice(&value.to_le_bytes());
+        }
+        buf
+    }
+
+    #[test]
+    fn test_load_timings_cover_the_call() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let path = dir.path().join("timed.gguf");
+        let tokens = gguf_file::Value::Array((0..5000).map(|i| gguf_file::Value::String(format!("tok{}", i))).collect());
+        std::fs::write(&path, make_test_gguf(&[("tokenizer.ggml.tokens", tokens)])).unwrap();
+
+        let start = std::time::Instant::now();
+        let (metadata, timings) = load_gguf_metadata_timed(&path).expect("Synthetic GGUF should load");
+        let measured = start.elapsed();
+
+        assert_eq!(metadata, load_gguf_metadata_sync(&path).unwrap());
+        // Only ordering invariants: wall-clock gaps vary with machine load
+        assert!(timings.total() <= measured, "{:?} vs {:?}", timings, measured);
+        for phase in [timings.read, timings.parse, timings.process] {
+            assert!(phase <= timings.total(), "{:?}", timings);
+        }
+        assert_eq!(timings.total(), timings.read + timings.parse + timings.process);
+    }
+
+    #[test]
+    fn test_normalize_key_aligns_architecture_keys() {
+        let llama = normalize_key("llama.block_count", "llama");
+        assert_eq!(llama, normalize_key("qwen2.block_count", "qwen2"));
+        assert_eq!(llama, format!("{}.block_count", ARCH_KEY_TOKEN));
+        assert_eq!(normalize_key("general.name", "llama"), "general.name");
+        assert_eq!(normalize_key("general.name", "qwen2"), "general.name");
+        // Only a whole `<architecture>.` prefix is replaced
+        assert_eq!(normalize_key("qwen2vl.block_count", "qwen2"), "qwen2vl.block_count");
+        assert_eq!(normalize_key("llama.block_count", ""), "llama.block_count");
+    }
+
+    #[test]
+    fn test_header_count_mismatch_is_reported() {
+        // The header advertises two entries, but the repeated key parses as one
+        let name = |s: &str| gguf_file::Value::String(s.to_string());
+        let buf = make_test_gguf(&[("general.name", name("first")), ("general.name", name("second"))]);
+        let content = gguf_file::Content::read(&mut std::io::Cursor::new(&buf)).expect("Synthetic GGUF should parse");
+
+        let mismatch = check_header_counts(&buf, &content).expect("Inflated kv_count should be detected");
+        assert_eq!((mismatch.header_kv_count, mismatch.parsed_kv_count), (2, 1));
+        assert_eq!((mismatch.header_tensor_count, mismatch.parsed_tensor_count), (0, 0));
+        let message = mismatch.to_string();
+        assert!(message.contains("2 metadata entries") && message.contains("1 metadata entries"), "{}", message);
+
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let path = dir.path().join("inflated.gguf");
+        std::fs::write(&path, &buf).unwrap();
+        let report = build_model_report(&path).expect("Report should still be built");
+        assert_eq!(report.count_mismatch, Some(mismatch));
+
+        let consistent = make_test_gguf(&[("general.name", name("only"))]);
+        let content = gguf_file::Content::read(&mut std::io::Cursor::new(&consistent)).unwrap();
+        assert_eq!(check_header_counts(&consistent, &content), None);
+    }
+
+    /// Serves `body` on a local port, answering `Range: bytes=a-b` requests with 206
+    /// when `ranges` is set. Returns the URL and the number of requests served so far.
+    #[cfg(feature = "http")]
+    fn serve_http(body: Vec<u8>, ranges: bool) -> (String, std::sync::Arc<AtomicUsize>) {
+        use std::io::{BufRead, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("Should bind a local port");
+        let url = format!("http://{}/model.gguf", listener.local_addr().unwrap());
+        let requests = std::sync::Arc::new(AtomicUsize::new(0));
+        let served = std::sync::Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(stream) = stream else { continue };
+                let mut reader = std::io::BufReader::new(&stream);
+                let mut range = None;
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap_or(0) == 0 || line.trim_end().is_empty() {
+                        break;
+                    }
+                    if let Some(spec) = line.trim_end().to_ascii_lowercase().strip_prefix("range: bytes=") {
+                        range = spec.split_once('-').and_then(|(a, b)| Some((a.parse::<usize>().ok()?, b.parse::<usize>().ok()?)));
+                    }
+                }
+                let (status, part) = match range.filter(|_| ranges) {
+                    Some((start, end)) => ("206 Partial Content", &body[start.min(body.len())..(end + 1).min(body.len())]),
+                    None => ("200 OK", &body[..]),
+                };
+                served.fetch_add(1, Ordering::SeqCst);
+                let mut writer = &stream;
+                let _ = write!(writer, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", status, part.len());
+                let _ = writer.write_all(part);
+            }
+        });
+        (url, requests)
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_fetch_gguf_head_over_http() {
+        let name = |s: &str| gguf_file::Value::String(s.to_string());
+        let mut buf = make_test_gguf(&[("general.name", name("remote")), ("general.architecture", name("qwen3"))]);
+        let expected = load_gguf_metadata_from_bytes(&buf).unwrap();
+        // Stands in for tensor data, which must not be downloaded
+        buf.resize(buf.len() + 4096, 0xAA);
+
+        let (url, requests) = serve_http(buf.clone(), true);
+        assert_eq!(fetch_gguf_head(&url, 16).unwrap(), buf[..16]);
+        // The 107 bytes of metadata don't fit in 32, so the second request asks for 128
+        assert_eq!(load_gguf_metadata_from_url_with_head(&url, 32).unwrap(), expected);
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+        // Without range support the whole file arrives at once
+        let (url, requests) = serve_http(buf.clone(), false);
+        assert_eq!(fetch_gguf_head(&url, 16).unwrap(), buf);
+        assert_eq!(load_gguf_metadata_from_url_with_head(&url, 32).unwrap(), expected);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_gguf_reader_reads_tensor_bytes() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let path = dir.path().join("tensor.gguf");
+        let values = [1.0f32, f32::NAN, -2.5, 0.0];
+        std::fs::write(&path, tensor_gguf("blk.0.ffn_norm.weight", &values)).unwrap();
+
+        let mut reader = GgufReader::open(&path).expect("Synthetic GGUF should parse");
+        assert_eq!(reader.tensor_names(), ["blk.0.ffn_norm.weight"]);
+        let info = reader.tensor_info("blk.0.ffn_norm.weight").expect("Tensor should be described");
+        assert_eq!((info.shape.as_slice(), info.byte_size), (&[4usize][..], 16));
+
+        let bytes = reader.read_tensor_bytes("blk.0.ffn_norm.weight").expect("Tensor should be readable");
+        let read: Vec<f32> = bytes.chunks_exact(4).map(|b| f32::from_le_bytes(b.try_into().unwrap())).collect();
+        assert_eq!(read.len(), values.len());
+        assert!(read[1].is_nan());
+        assert_eq!((read[0], read[2], read[3]), (1.0, -2.5, 0.0));
+        assert!(reader.read_tensor_bytes("missing.weight").is_err());
+    }
+
+    #[test]
+    fn test_gguf_reader_rejects_tensor_past_end_of_file() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let path = dir.path().join("truncated.gguf");
+        let buf = tensor_gguf("blk.0.ffn_norm.weight", &[1.0f32; 4]);
+        // Keeps the header and descriptors but cuts the tensor data short
+        std::fs::write(&path, &buf[..buf.len() - 8]).unwrap();
+
+        let mut reader = GgufReader::open(&path).expect("Header should still parse");
+        let err = reader.read_tensor_bytes("blk.0.ffn_norm.weight").expect_err("Truncated tensor should fail");
+        assert!(err.to_string().contains("past the end"), "{}", err);
+
+        // A forged offset must not overflow or allocate
+        reader.tensors[0].offset = u64::MAX;
+        let err = reader.read_tensor_bytes("blk.0.ffn_norm.weight").expect_err("Forged offset should fail");
+        assert!(err.to_string().contains("out-of-range"), "{}", err);
+        reader.tensors[0].offset = 0;
+        reader.tensors[0].byte_size = 1 << 40;
+        assert!(reader.read_tensor_bytes("blk.0.ffn_norm.weight").is_err());
+    }
+
+    #[test]
+    fn test_gguf_reader_sample_model_tensor() {
+        let path = std::path::Path::new("model/Qwen3-0.6B-Q5_K_M.gguf");
+        if !path.exists() {
+            eprintln!("Sample model not found at {}, skipping", path.display());
+            return;
+        }
+
+        let mut reader = GgufReader::open(path).expect("Sample model should parse");
+        assert!(!reader.tensor_names().is_empty());
+        let info = reader.tensor_info("output_norm.weight").expect("Sample model has an output norm").clone();
+        let dtype = reader.content().tensor_infos["output_norm.weight"].ggml_dtype;
+        let expected = info.shape.iter().product::<usize>() / dtype.block_size() * dtype.type_size();
+
+        let bytes = reader.read_tensor_bytes("output_norm.weight").expect("Tensor should be readable");
+        assert_eq!(bytes.len(), expected);
+        assert_eq!(bytes.len() as u64, info.byte_size);
+    }
+
+    #[test]
+    fn test_make_test_gguf_header() {
+        let buf = make_test_gguf(&[
+            ("general.name", gguf_file::Value::String("tiny".to_string())),
+            ("general.alignment", gguf_file::Value::U32(32)),
+        ]);
+
+        let header = read_gguf_header_from_buffer(&buf).expect("Header should parse");
+        assert_eq!(header.version, 3);
+        assert_eq!(header.tensor_count, 0);
+        assert_eq!(header.kv_count, 2);
+    }
+
+    fn header_buffer(version: u32, counts: &[u8]) -> Vec<u8> {
+        let mut buf = GGUF_MAGIC.to_vec();
+        buf.extend_from_slice(&version.to_le_bytes());
+        buf.extend_from_slice(counts);
+        buf.resize(GGUF_HEADER_SIZE as usize, 0xAA);
+        buf
+    }
+
+    #[test]
+    fn test_v1_header_reads_u32_counts() {
+        let mut counts = 3u32.to_le_bytes().to_vec();
+        counts.extend_from_slice(&7u32.to_le_bytes());
+
+        let header = read_gguf_header_from_buffer(&header_buffer(1, &counts)).expect("v1 header should parse");
+        assert_eq!(header.version, 1);
+        assert_eq!(header.tensor_count, 3);
+        assert_eq!(header.kv_count, 7);
+    }
+
+    #[test]
+    fn test_v2_header_reads_u64_counts() {
+        let mut counts = 5_000_000_000u64.to_le_bytes().to_vec();
+        counts.extend_from_slice(&9u64.to_le_bytes());
+
+        let header = read_gguf_header_from_buffer(&header_buffer(2, &counts)).expect("v2 header should parse");
+        assert_eq!(header.version, 2);
+        assert_eq!(header.tensor_count, 5_000_000_000);
+        assert_eq!(header.kv_count, 9);
+    }
+
+    fn u32_array(values: &[u32]) -> gguf_file::Value {
+        gguf_file::Value::Array(values.iter().map(|n| gguf_file::Value::U32(*n)).collect())
+    }
+
+    #[test]
+    fn test_nested_array_is_readable_and_truncated_per_level() {
+        let nested = gguf_file::Value::Array(vec![
+            u32_array(&[1, 2, 3, 4, 5]),
+            u32_array(&[6, 7]),
+            u32_array(&[8]),
+            u32_array(&[9]),
+        ]);
+
+        let readable = readable_value_for_key("llama.layer_configs", &nested);
+        assert_eq!(readable, "[[1, 2, 3, …], [6, 7], [8], …]");
+        assert!(!readable.contains("Array(") && !readable.contains("U32("), "No debug output: {}", readable);
+
+        let short = gguf_file::Value::Array(vec![u32_array(&[1]), u32_array(&[])]);
+        assert_eq!(readable_value_for_key("llama.layer_configs", &short), "[[1], []]");
+    }
+
+    #[test]
+    fn test_empty_array_renders_as_brackets() {
+        let empty = u32_array(&[]);
+        for key in ["llama.layer_configs", "tokenizer.ggml.tokens", "tokenizer.ggml.merges", "tokenizer.chat_template"] {
+            assert_eq!(readable_value_for_key(key, &empty), "[]", "Truncated value of {}", key);
+            assert_eq!(readable_value_for_key_full(key, &empty, true), "[]", "Full value of {}", key);
+        }
+        assert_eq!(readable_value_for_key_with_limit("general.tags", &empty, 0), "[]");
+        assert_eq!(get_full_tokenizer_content("tokenizer.ggml.tokens", &empty).as_deref(), Some("[]"));
+    }
+
+    #[test]
+    fn test_nested_array_depth_is_limited() {
+        let deep = gguf_file::Value::Array(vec![gguf_file::Value::Array(vec![gguf_file::Value::Array(vec![u32_array(&[1, 2])])])]);
+        assert_eq!(readable_value_for_key("llama.layer_configs", &deep), "[[[Array(len=2)]]]");
+    }
+
+    #[test]
+    fn test_value_to_raw_bytes_only_for_u8_arrays() {
+        let bytes = gguf_file::Value::Array(vec![gguf_file::Value::U8(0), gguf_file::Value::U8(0x80), gguf_file::Value::U8(0xff)]);
+        assert_eq!(value_to_raw_bytes(&bytes), Some(vec![0, 0x80, 0xff]));
+
+        let mixed = gguf_file::Value::Array(vec![gguf_file::Value::U8(1), gguf_file::Value::U32(2)]);
+        assert_eq!(value_to_raw_bytes(&mixed), None);
+        assert_eq!(value_to_raw_bytes(&gguf_file::Value::Array(vec![gguf_file::Value::I8(1)])), None);
+        assert_eq!(value_to_raw_bytes(&gguf_file::Value::U8(7)), None);
+        assert_eq!(value_to_raw_bytes(&gguf_file::Value::String("abc".to_string())), None);
+    }
+
+    #[test]
+    fn test_hex_dump_rows() {
+        let mut bytes = b"GGUF".to_vec();
+        bytes.extend_from_slice(&[3, 0, 0, 0]);
+        bytes.extend_from_slice(b"hello, world!\n\x7f\xff");
+
+        assert_eq!(
+            hex_dump(&bytes),
+            concat!(
+                "00000000: 4747 5546 0300 0000 6865 6c6c 6f2c 2077  GGUF....hello, w\n",
+                "00000010: 6f72 6c64 210a 7fff                      orld!...\n",
+            )
+        );
+        assert_eq!(hex_dump_line(0x1230, &[0x41]), format!("00001230: 41{}  A", " ".repeat(37)));
+        assert_eq!(hex_dump(&[]), "");
+    }
+
+    #[test]
+    fn test_load_from_bytes_string_value() {
+        let buf = make_test_gguf(&[("general.name", gguf_file::Value::String("tiny-model".to_string()))]);
+        let entries = load_gguf_metadata_from_bytes(&buf).expect("Synthetic GGUF should load");
+
+        assert_eq!(entries[0].0, "version");
+        assert_eq!(entries[0].1, "3");
+        assert_eq!(find(&entries, "kv_count").1, "1");
+
+        let (_, value, full) = find(&entries, "general.name");
+        assert_eq!(value, "tiny-model");
+        assert!(full.is_none());
+    }
+
+    #[test]
+    fn test_load_from_bytes_scales_to_many_keys() {
+        const KEY_COUNT: usize = 10_000;
+        let keys: Vec<String> = (0..KEY_COUNT).map(|i| format!("bench.block.{}.value", i)).collect();
+        let metadata: Vec<(&str, gguf_file::Value)> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, key)| (key.as_str(), gguf_file::Value::U32(i as u32)))
+            .collect();
+        let buf = make_test_gguf(&metadata);
+
+        // Takes milliseconds; only a quadratic formatting loop gets near the budget
+        let started = std::time::Instant::now();
+        let entries = load_gguf_metadata_from_bytes(&buf).expect("Synthetic GGUF should load");
+        let elapsed = started.elapsed();
+
+        assert_eq!(find(&entries, "kv_count").1, KEY_COUNT.to_string());
+        assert!(entries.len() > KEY_COUNT);
+        assert!(elapsed < std::time::Duration::from_secs(10), "Parsing {} keys took {:?}", KEY_COUNT, elapsed);
+    }
+
+    #[test]
+    fn test_load_from_bytes_u32_value() {
+        let buf = make_test_gguf(&[("llama.context_length", gguf_file::Value::U32(4096))]);
+        let entries = load_gguf_metadata_from_bytes(&buf).expect("Synthetic GGUF should load");
+
+        let (_, value, _) = find(&entries, "llama.context_length");
+        assert!(value.contains("4096"));
+    }
+
+    #[test]
+    fn test_load_from_bytes_byte_array_value() {
+        let template = "{{ bos_token }}{{ message }}";
+        let bytes = template.bytes().map(gguf_file::Value::U8).collect();
+        let buf = make_test_gguf(&[
+            ("tokenizer.chat_template", gguf_file::Value::Array(bytes)),
+            ("test.bytes", gguf_file::Value::Array(vec![gguf_file::Value::U8(0xff), gguf_file::Value::U8(0x00)])),
+        ]);
+        let entries = load_gguf_metadata_from_bytes(&buf).expect("Synthetic GGUF should load");
+
+        let (_, value, full) = find(&entries, "tokenizer.chat_template");
+        assert_eq!(value, template);
+        assert_eq!(full.as_deref(), Some(template));
+
+        let (_, value, _) = find(&entries, "test.bytes");
+        assert_eq!(value, "ff00…");
+    }
+
+    #[test]
+    fn test_readable_value_for_key_matches_loader() {
+        let value = gguf_file::Value::String("llama".to_string());
+        let buf = make_test_gguf(&[("general.architecture", value.clone())]);
+        let entries = load_gguf_metadata_from_bytes(&buf).expect("Synthetic GGUF should load");
+
+        assert_eq!(find(&entries, "general.architecture").1, readable_value_for_key("general.architecture", &value));
+    }
+
+    #[test]
+    fn test_model_summary_from_metadata() {
+        let buf = make_test_gguf(&[
+            ("general.name", gguf_file::Value::String("tiny".to_string())),
+            ("general.architecture", gguf_file::Value::String("llama".to_string())),
+            ("llama.context_length", gguf_file::Value::U32(2048)),
+            (
+                "tokenizer.ggml.tokens",
+                gguf_file::Value::Array(vec![
+                    gguf_file::Value::String("a".to_string()),
+                    gguf_file::Value::String("b".to_string()),
+                ]),
+            ),
+        ]);
+        let content = gguf_file::Content::read(&mut std::io::Cursor::new(&buf)).expect("Synthetic GGUF should parse");
+        let summary = model_summary(&content);
+
+        assert_eq!(summary.name.as_deref(), Some("tiny"));
+        assert_eq!(summary.architecture.as_deref(), Some("llama"));
+        assert_eq!(summary.context_length, Some(2048));
+        assert_eq!(summary.vocab_size, Some(2));
+        assert_eq!(summary.parameter_count, 0);
+        assert_eq!(estimate_model_size(&content), 0);
+        assert!(quant_breakdown(&content).is_empty());
+    }
+
+    #[test]
+    fn test_build_model_report_sample_model() {
+        let path = std::path::Path::new("model/Qwen3-0.6B-Q5_K_M.gguf");
+        if !path.exists() {
+            eprintln!("Sample model not found at {}, skipping", path.display());
+            return;
+        }
+
+        let report = build_model_report(path).expect("Sample model should parse");
+        assert_eq!(report.summary.architecture.as_deref(), Some("qwen3"));
+        assert!(report.summary.parameter_count > 0);
+
+        let text = report.to_string();
+        assert!(text.contains("qwen3"));
+        let json = serde_json::to_value(&report).expect("Report should serialize");
+        assert_eq!(json["architecture"], "qwen3");
+        assert!(json["parameter_count"].as_u64().unwrap_or(0) > 0);
+    }
+
+    #[test]
+    fn test_scan_directory_sample_model() {
+        let sample = std::path::Path::new("model/Qwen3-0.6B-Q5_K_M.gguf");
+        if !sample.exists() {
+            eprintln!("Sample model not found at {}, skipping", sample.display());
+            return;
+        }
+
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        std::fs::copy(sample, dir.path().join("qwen3.gguf")).expect("Should copy sample model");
+        std::fs::write(dir.path().join("broken.gguf"), b"GGUF").expect("Should write broken file");
+        std::fs::write(dir.path().join("notes.txt"), b"not a model").expect("Should write text file");
+
+        let mut calls = Vec::new();
+        let results = scan_directory_with_progress(dir.path(), |done, total| calls.push((done, total)));
+
+        assert_eq!(results.len(), 2, "Only .gguf files are scanned");
+        assert_eq!(calls, vec![(1, 2), (2, 2)]);
+
+        let broken = &results[0];
+        assert_eq!(broken.file_name(), "broken.gguf");
+        assert!(broken.report.is_none());
+        assert!(broken.error.is_some());
+
+        let model = &results[1];
+        assert_eq!(model.file_name(), "qwen3.gguf");
+        assert_eq!(model.size, std::fs::metadata(sample).unwrap().len());
+        assert_eq!(model.architecture(), Some("qwen3"));
+        assert!(model.parameter_count().unwrap_or(0) > 0);
+        assert!(!model.quant_summary().is_empty());
+        assert!(model.error.is_none());
+    }
+
+    #[test]
+    fn test_parallel_scan_matches_sequential_scan() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let sample = std::path::Path::new("model/Qwen3-0.6B-Q5_K_M.gguf");
+        if sample.exists() {
+            for i in 0..3 {
+                std::fs::copy(sample, dir.path().join(format!("qwen3-{}.gguf", i))).expect("Should copy sample model");
+            }
+        } else {
+            eprintln!("Sample model not found at {}, using synthetic files only", sample.display());
+        }
+        for i in 0..6 {
+            let name = format!("model-{}", i);
+            let buf = make_test_gguf(&[("general.name", gguf_file::Value::String(name.clone()))]);
+            std::fs::write(dir.path().join(format!("{}.gguf", name)), buf).expect("Should write model");
+        }
+        std::fs::write(dir.path().join("broken.gguf"), b"GGUF").expect("Should write broken file");
+
+        let mut paths: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        paths.sort();
+
+        let sequential = scan_files_parallel(&paths, 1, |_, _| {});
+        let mut calls = Vec::new();
+        let parallel = scan_files_parallel(&paths, 4, |done, total| calls.push((done, total)));
+
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel.iter().map(|r| r.path.clone()).collect::<Vec<_>>(), paths);
+        assert_eq!(calls, (1..=paths.len()).map(|done| (done, paths.len())).collect::<Vec<_>>());
+        assert_eq!(scan_directory(dir.path()), sequential);
+        assert!(scan_files_parallel(&[], 4, |_, _| panic!("No progress for no files")).is_empty());
+    }
+
+    #[test]
+    fn test_scan_missing_directory_is_empty() {
+        assert!(scan_directory(std::path::Path::new("no/such/dir")).is_empty());
+    }
+
+    fn token_array(count: usize) -> gguf_file::Value {
+        gguf_file::Value::Array((0..count).map(|i| gguf_file::Value::String(format!("t{}", i))).collect())
+    }
+
+    #[test]
+    fn test_preview_limit_default_matches_three() {
+        let tokens = token_array(20);
+        let preview = readable_value_for_key_with_limit("tokenizer.ggml.tokens", &tokens, 3);
+        assert_eq!(preview, "t0, t1, t2, …");
+        assert_eq!(readable_value_for_key("tokenizer.ggml.tokens", &tokens), preview);
+
+        // Short lists are still shown whole
+        assert_eq!(
+            readable_value_for_key_with_limit("tokenizer.ggml.tokens", &token_array(5), 3),
+            "t0, t1, t2, t3, t4"
+        );
+    }
+
+    #[test]
+    fn test_preview_limit_ten() {
+        let preview = readable_value_for_key_with_limit("tokenizer.ggml.merges", &token_array(20), 10);
+        assert_eq!(preview, "t0, t1, t2, t3, t4, t5, t6, t7, t8, t9, …");
+    }
+
+    #[test]
+    fn test_preview_limit_zero_shows_all() {
+        let preview = readable_value_for_key_with_limit("tokenizer.ggml.tokens", &token_array(20), 0);
+        assert!(!preview.contains('…'));
+        assert_eq!(preview.split(", ").count(), 20);
+        assert!(preview.ends_with("t19"));
+    }
+
+    #[test]
+    fn test_custom_formatter_overrides_default() {
+        register_value_formatter(
+            |key| key.ends_with(".rope.scaling"),
+            |_, value| match value {
+                gguf_file::Value::F32(factor) => format!("rope scaling ×{}", factor),
+                _ => "rope scaling".to_string(),
+            },
+        );
+
+        let value = gguf_file::Value::F32(4.0);
+        assert_eq!(readable_value_for_key("qwen3.rope.scaling", &value), "rope scaling ×4");
+        assert_eq!(readable_value_for_key_full("llama.rope.scaling", &value, true), "rope scaling ×4");
+        // Keys the predicate rejects keep the built-in formatting
+        assert_ne!(readable_value_for_key("qwen3.rope.freq_base", &value), "rope scaling ×4");
+    }
+
+    #[test]
+    fn test_registered_full_content_prefix() {
+        let tokens = gguf_file::Value::Array((0..10).map(|i| gguf_file::Value::String(format!("<tok{}>", i))).collect());
+        assert_eq!(get_full_tokenizer_content("general.added_tokens", &tokens), None);
+
+        register_full_content_prefix("general.added_tokens");
+        let full = get_full_tokenizer_content("general.added_tokens", &tokens).expect("Registered prefix should match");
+        assert!(full.contains("<tok9>"), "Full content is not truncated: {}", full);
+        assert!(get_full_tokenizer_content("tokenizer.ggml.tokens", &tokens).is_some());
+        assert_eq!(get_full_tokenizer_content("general.name", &tokens), None);
+        assert_eq!(get_full_tokenizer_content("general.added", &tokens), None);
+    }
+
+    #[test]
+    fn test_known_config_keys_are_annotated() {
+        let freq_base = gguf_file::Value::F32(10000.0);
+        assert_eq!(readable_value_for_key("llama.rope.freq_base", &freq_base), "10000 (rope freq base)");
+        assert_eq!(known_config_raw_value("llama.rope.freq_base", &freq_base).as_deref(), Some("10000.0"));
+
+        let factor = gguf_file::Value::F64(8.0);
+        assert_eq!(readable_value_for_key("qwen3.rope.scaling.factor", &factor), "8 (rope scaling factor)");
+        // Complete content stays the plain value
+        assert!(!readable_value_for_key_full("qwen3.rope.scaling.factor", &factor, true).contains("rope"));
+    }
+
+    #[test]
+    fn test_known_quantization_version_is_labeled() {
+        let version = gguf_file::Value::U32(2);
+        assert_eq!(
+            readable_value_for_key(QUANTIZATION_VERSION_KEY, &version),
+            "2 (current ggml format: Q4_0–Q8_1, K-quants, I-quants)"
+        );
+        assert_eq!(known_config_raw_value(QUANTIZATION_VERSION_KEY, &version).as_deref(), Some("2"));
+        // Complete content and other keys keep the plain formatting
+        let plain = readable_value_for_key("", &version);
+        assert_eq!(readable_value_for_key_full(QUANTIZATION_VERSION_KEY, &version, true), plain);
+        assert_eq!(readable_value_for_key("llama.quantization_version", &version), plain);
+    }
+
+    #[test]
+    fn test_unknown_quantization_version_is_unrecognized() {
+        let version = gguf_file::Value::U32(7);
+        assert_eq!(readable_value_for_key(QUANTIZATION_VERSION_KEY, &version), "7 (unrecognized)");
+        assert_eq!(known_config_raw_value(QUANTIZATION_VERSION_KEY, &version).as_deref(), Some("7"));
+
+        let text = gguf_file::Value::String("2".to_string());
+        assert_eq!(readable_value_for_key(QUANTIZATION_VERSION_KEY, &text), "2");
+        assert_eq!(known_config_raw_value(QUANTIZATION_VERSION_KEY, &text), None);
+    }
+
+    #[test]
+    fn test_unknown_and_non_float_keys_are_not_annotated() {
+        let value = gguf_file::Value::F32(10000.0);
+        assert_eq!(readable_value_for_key("llama.rope.freq_scale", &value), readable_value_for_key("", &value));
+        assert_eq!(readable_value_for_key("rope.freq_base", &value), readable_value_for_key("", &value));
+        assert_eq!(known_config_raw_value("llama.rope.freq_scale", &value), None);
+
+        let text = gguf_file::Value::String("10000".to_string());
+        assert_eq!(readable_value_for_key("llama.rope.freq_base", &text), "10000");
+        assert_eq!(known_config_raw_value("llama.rope.freq_base", &text), None);
+    }
+
+    #[test]
+    fn test_human_bytes_thresholds() {
+        assert_eq!(human_bytes(0), "0 B");
+        assert_eq!(human_bytes(999), "999 B");
+        assert_eq!(human_bytes(1_000), "1.0 KB");
+        assert_eq!(human_bytes(1_536), "1.5 KB");
+        assert_eq!(human_bytes(999_999), "1.0 MB");
+        assert_eq!(human_bytes(412_300_000), "412.3 MB");
+        assert_eq!(human_bytes(1_000_000_000), "1.0 GB");
+        assert_eq!(human_bytes(7_240_000_000), "7.2 GB");
+    }
+
+    #[test]
+    fn test_float_array_has_no_debug_wrappers() {
+        let value = gguf_file::Value::Array(vec![
+            gguf_file::Value::F32(1.0),
+            gguf_file::Value::F32(2.5),
+            gguf_file::Value::F32(3.0),
+        ]);
+        let result = readable_value_for_key("llama.rope.freq_factors", &value);
+        assert_eq!(result, "1.0, 2.5, 3.0");
+        assert!(!result.contains("F32("));
+    }
+
+    #[test]
+    fn test_int_array_has_no_debug_wrappers() {
+        let value = gguf_file::Value::Array((0..12).map(|i| gguf_file::Value::U32(8 + i % 2)).collect());
+        let result = readable_value_for_key("llama.attention.head_count_kv", &value);
+        // Long arrays keep the first-three-elements truncation
+        assert_eq!(result, "8, 9, 8, …");
+        assert!(!result.contains("U32("));
+    }
+
+    fn byte_token(bytes: &[u8]) -> gguf_file::Value {
+        gguf_file::Value::Array(bytes.iter().map(|b| gguf_file::Value::U8(*b)).collect())
+    }
+
+    #[test]
+    fn test_typed_value_scalars() {
+        assert_eq!(typed_value_for_key("a", &gguf_file::Value::U32(42)), ReadableValue::Int(42));
+        assert_eq!(typed_value_for_key("a", &gguf_file::Value::I8(-3)), ReadableValue::Int(-3));
+        assert_eq!(
+            typed_value_for_key("a", &gguf_file::Value::U64(u64::MAX)),
+            ReadableValue::Scalar(u64::MAX.to_string())
+        );
+        assert_eq!(typed_value_for_key("a", &gguf_file::Value::F32(0.5)), ReadableValue::Float(0.5));
+        assert_eq!(typed_value_for_key("a", &gguf_file::Value::Bool(true)), ReadableValue::Bool(true));
+        assert_eq!(
+            typed_value_for_key("general.name", &gguf_file::Value::String("Qwen3".to_string())),
+            ReadableValue::Scalar("Qwen3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_typed_value_arrays() {
+        assert_eq!(
+            typed_value_for_key("tokenizer.ggml.tokens", &token_array(3)),
+            ReadableValue::StringArray(vec!["t0".to_string(), "t1".to_string(), "t2".to_string()])
+        );
+        assert_eq!(
+            typed_value_for_key("general.blob", &byte_token(&[0xff, 0x00])),
+            ReadableValue::Bytes(vec![0xff, 0x00])
+        );
+        assert_eq!(
+            typed_value_for_key("tokenizer.chat_template", &byte_token(b"{{ msg }}")),
+            ReadableValue::Scalar("{{ msg }}".to_string())
+        );
+        assert_eq!(
+            typed_value_for_key("llama.layers", &gguf_file::Value::Array(vec![gguf_file::Value::I32(1), gguf_file::Value::F64(2.5)])),
+            ReadableValue::Array(vec![ReadableValue::Int(1), ReadableValue::Float(2.5)])
+        );
+    }
+
+    #[test]
+    fn test_non_utf8_token_bytes_use_lossy_marker() {
+        let tokens = gguf_file::Value::Array(vec![
+            byte_token(b"hello"),
+            byte_token(&[0xE2, 0x82]), // truncated multi-byte sequence
+            gguf_file::Value::String("world".to_string()),
+        ]);
+
+        let full = readable_value_for_key_full("tokenizer.ggml.tokens", &tokens, true);
+        let parts: Vec<&str> = full.split(", ").collect();
+        assert_eq!(parts.len(), 3, "Invalid tokens must not be dropped");
+        assert_eq!(parts[0], "hello");
+        assert!(parts[1].starts_with(NON_UTF8_MARKER));
+        assert!(parts[1].contains('\u{FFFD}'));
+        assert_eq!(parts[2], "world");
+    }
+
+    #[test]
+    fn test_valid_utf8_token_bytes_render_cleanly() {
+        let tokens = gguf_file::Value::Array(vec![byte_token("Привет".as_bytes()), byte_token(b"hi")]);
+        let full = readable_value_for_key_full("tokenizer.ggml.tokens", &tokens, true);
+        assert_eq!(full, "Привет, hi");
+        assert!(!full.contains(NON_UTF8_MARKER));
+    }
+
+    #[test]
+    fn test_detect_image_format_magic() {
+        let png = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00];
+        let jpeg = [0xFF, 0xD8, 0xFF, 0xDB, 0x00];
+        assert_eq!(detect_image_format(&png), Some(image::ImageFormat::Png));
+        assert_eq!(detect_image_format(&jpeg), Some(image::ImageFormat::Jpeg));
+        assert_eq!(detect_image_format(&png[..4]), None);
+        assert_eq!(detect_image_format(&[0xFF, 0xD8]), None);
+        assert_eq!(detect_image_format(&[]), None);
+    }
+
+    #[test]
+    fn test_image_byte_array_gets_data_uri() {
+        let bytes = [0xFF, 0xD8, 0xFF, 0xE0, 0x01, 0x02];
+        let value = gguf_file::Value::Array(bytes.iter().map(|b| gguf_file::Value::U8(*b)).collect());
+
+        assert_eq!(readable_value_for_key("general.thumbnail", &value), "JPEG image (6 bytes)");
+        let uri = image_data_uri(&value).expect("JPEG bytes should produce a data URI");
+        assert!(uri.starts_with("data:image/jpeg;base64,"));
+
+        let plain = gguf_file::Value::Array(vec![gguf_file::Value::U8(1), gguf_file::Value::U8(2)]);
+        assert_eq!(image_data_uri(&plain), None);
+    }
+
+    #[test]
+    fn test_empty_buffer_is_typed_error() {
+        assert_eq!(read_gguf_header_from_buffer(&[]).unwrap_err(), GgufError::Empty);
+
+        let err = load_gguf_metadata_from_bytes(&[]).unwrap_err();
+        assert_eq!(err.downcast_ref::<GgufError>(), Some(&GgufError::Empty));
+    }
+
+    #[test]
+    fn test_short_buffer_is_truncated_error() {
+        let buf = make_test_gguf(&[]);
+        let short = &buf[..12];
+        assert_eq!(read_gguf_header_from_buffer(short).unwrap_err(), GgufError::Truncated(12));
+
+        let err = load_gguf_metadata_from_bytes(short).unwrap_err();
+        assert_eq!(err.downcast_ref::<GgufError>(), Some(&GgufError::Truncated(12)));
+    }
+
+    #[test]
+    fn test_load_from_bytes_rejects_garbage() {
+        assert!(load_gguf_metadata_from_bytes(b"not a gguf file at all").is_err());
+    }
+
+    fn strings(items: &[&str]) -> gguf_file::Value {
+        gguf_file::Value::Array(items.iter().map(|s| gguf_file::Value::String(s.to_string())).collect())
+    }
+
+    #[test]
+    fn test_reconstruct_tokenizer_json_for_bpe() {
+        let buf = make_test_gguf(&[
+            ("tokenizer.ggml.model", gguf_file::Value::String("gpt2".to_string())),
+            ("tokenizer.ggml.tokens", strings(&["h", "i", "hi", "<|endoftext|>"])),
+            ("tokenizer.ggml.merges", strings(&["h i"])),
+            (
+                "tokenizer.ggml.token_type",
+                gguf_file::Value::Array(vec![
+                    gguf_file::Value::I32(1),
+                    gguf_file::Value::I32(1),
+                    gguf_file::Value::I32(1),
+                    gguf_file::Value::I32(3),
+                ]),
+            ),
+            ("tokenizer.ggml.eos_token_id", gguf_file::Value::U32(3)),
+        ]);
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let path = dir.path().join("bpe.gguf");
+        std::fs::write(&path, buf).unwrap();
+
+        let json = reconstruct_tokenizer_json(&path)
+            .expect("Synthetic GGUF should load")
+            .expect("BPE tokenizers should be supported");
+        let json: serde_json::Value = serde_json::from_str(&json).expect("Output should be valid JSON");
+
+        assert_eq!(json["model"]["type"], "BPE");
+        let vocab = json["model"]["vocab"].as_object().expect("model.vocab should be an object");
+        assert_eq!(vocab.len(), 4);
+        assert_eq!(vocab["hi"], 2);
+        assert_eq!(json["model"]["merges"], serde_json::json!(["h i"]));
+
+        let added = json["added_tokens"].as_array().expect("added_tokens should be a list");
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0]["id"], 3);
+        assert_eq!(added[0]["content"], "<|endoftext|>");
+        assert_eq!(added[0]["special"], true);
+    }
+
+    #[test]
+    fn test_reconstruct_tokenizer_json_rejects_sentencepiece() {
+        let buf = make_test_gguf(&[
+            ("tokenizer.ggml.model", gguf_file::Value::String("llama".to_string())),
+            ("tokenizer.ggml.tokens", strings(&["<unk>", "▁hi"])),
+        ]);
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let path = dir.path().join("spm.gguf");
+        std::fs::write(&path, buf).unwrap();
+
+        assert_eq!(reconstruct_tokenizer_json(&path).expect("Synthetic GGUF should load"), None);
+
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert("tokenizer.ggml.model".to_string(), gguf_file::Value::String("llama".to_string()));
+        let reason = tokenizer_json_value(&metadata).unwrap_err();
+        assert!(reason.contains("llama"), "Got: {}", reason);
+    }
+
+    #[test]
+    fn test_errors_downcast_to_typed_errors() {
+        let err = load_gguf_metadata_from_bytes(&[]).unwrap_err();
+        let handle = std::thread::spawn(move || err.downcast::<GgufError>().map(|e| *e).ok());
+        assert_eq!(handle.join().unwrap(), Some(GgufError::Empty));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn test_async_load_matches_sync_load() {
+        let path = std::path::Path::new("model/Qwen3-0.6B-Q5_K_M.gguf");
+        if !path.exists() {
+            println!("Sample model not found at {}, skipping", path.display());
+            return;
+        }
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("Should build a Tokio runtime");
+        let handle = runtime.spawn(load_gguf_metadata(path));
+        let mut metadata = runtime
+            .block_on(handle)
+            .expect("Load task should not panic")
+            .expect("Sample model should load");
+        let mut expected = load_gguf_metadata_sync(path).unwrap();
+        metadata.sort();
+        expected.sort();
+        assert_eq!(metadata, expected);
+    }
+
+    fn byte_array(s: &str) -> gguf_file::Value {
+        gguf_file::Value::Array(s.bytes().map(gguf_file::Value::U8).collect())
+    }
+
+    #[test]
+    fn test_byte_preview_truncates_on_char_boundary() {
+        // Byte 50 falls inside the first 'é', which used to panic when slicing
+        let text = format!("{}ééx", "a".repeat(49));
+        assert!(!text.is_char_boundary(50));
+
+        let display = readable_value_for_key("test.bytes", &byte_array(&text));
+        assert_eq!(display, format!("{}é…", "a".repeat(49)));
+    }
+
+    #[test]
+    fn test_byte_preview_length_is_configurable() {
+        let text = "日本語のテキスト";
+        let value = byte_array(text);
+
+        assert_eq!(readable_value_for_key_with_limits("test.bytes", &value, DEFAULT_PREVIEW_LIMIT, 3), "日本語…");
+        assert_eq!(readable_value_for_key_with_limits("test.bytes", &value, DEFAULT_PREVIEW_LIMIT, 8), text);
+        assert_eq!(readable_value_for_key_with_limits("test.bytes", &value, DEFAULT_PREVIEW_LIMIT, 0), "…");
+    }
+
+    #[test]
+    fn test_truncate_chars_multibyte() {
+        assert_eq!(truncate_chars("héllo", 2), "hé");
+        assert_eq!(truncate_chars("🦀🦀🦀", 1), "🦀");
+        assert_eq!(truncate_chars("🦀🦀🦀", 3), "🦀🦀🦀");
+        assert_eq!(truncate_chars("abc", 0), "");
+        assert_eq!(truncate_chars("", 5), "");
+    }
+
+    #[test]
+    fn test_full_value_with_cyrillic_text_does_not_panic() {
+        let cyrillic: String = "привет".chars().cycle().take(60).collect();
+        assert_eq!(cyrillic.chars().count(), 60);
+
+        for full_content in [false, true] {
+            let string_value = gguf_file::Value::String(cyrillic.clone());
+            assert_eq!(readable_value_for_key_full("general.description", &string_value, full_content), cyrillic);
+
+            let display = readable_value_for_key_full("test.bytes", &byte_array(&cyrillic), full_content);
+            assert!(std::str::from_utf8(display.as_bytes()).is_ok());
+        }
+
+        // Short enough for the text preview; byte 50 splits a Cyrillic letter
+        let mixed = format!("{}{}", "a".repeat(41), cyrillic.chars().take(11).collect::<String>());
+        assert!(!mixed.is_char_boundary(50));
+        let display = readable_value_for_key_full("test.bytes", &byte_array(&mixed), false);
+        assert_eq!(display, format!("{}{}…", "a".repeat(41), cyrillic.chars().take(9).collect::<String>()));
+    }
+}
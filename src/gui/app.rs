@@ -34,14 +34,33 @@
 //! // eframe::run_native("Inspector GGUF", options, Box::new(|_cc| Box::new(app)));
 //! ```
 
+use std::collections::BTreeSet;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use eframe::egui;
-use crate::localization::{LocalizationManager, LanguageProvider};
-use crate::gui::loader::{LoadingResult, MetadataEntry};
-use crate::gui::theme::{apply_inspector_theme, load_custom_font, TECH_GRAY, GADGET_YELLOW};
-use crate::gui::layout::{get_sidebar_width, get_adaptive_font_size};
+use crate::localization::{format_date, AppSettings, LocalizationManager, LanguageProvider, SettingsManager};
+use crate::format::{format_count, human_bytes, ModelReport, ModelSummary, DEFAULT_PREVIEW_LIMIT};
+use crate::gui::cache::{MetadataCache, SharedMetadataCache};
+use crate::gui::changes::{changed_keys, record_snapshot, snapshot, CHANGED_BADGE_DURATION};
+use crate::gui::export::{
+    entries_for_export, exported_key_count, model_card_options, parse_key_patterns, sanitize_filename, BackgroundExport,
+    ExportFormat, ExportOptions, LineEnding,
+};
+use crate::gui::loader::{locate_sample_model, LoadError, LoadTracker, LoadingResult, MetadataEntry};
+use crate::gui::panels::content::{
+    accept_dropped, entry_clipboard_text, escape_whitespace, filter_entries, hex_view_bytes, is_special_token, page_count, paginate,
+    partition_pinned, preview_first_tokens, remember_filter, render_drop_overlay, render_plain_value, restore_filter,
+    suggest_keys, toggle_pinned, extract_license_info,
+    DroppedInput, FilterMode, LicenseInfo, FILTER_SUGGESTION_LIMIT, INLINE_TOKEN_PREVIEW,
+};
+use crate::gui::settings::GuiSettings;
+use crate::gui::theme::{apply_high_contrast_theme, apply_inspector_theme, load_fonts, TECH_GRAY, GADGET_YELLOW, DANGER_RED, SUCCESS_GREEN};
+use crate::gui::layout::{get_sidebar_width, get_adaptive_font_size, set_font_scale};
 use crate::gui::updater::check_for_updates;
+use crate::gui::watcher::FileWatcher;
 use crate::gui::panels::dialogs;
+use crate::gui::panels::scan::{render_scan_window, FolderScan};
 use rfd;
 
 /// Main application struct that orchestrates all GUI functionality.
@@ -55,16 +74,42 @@ use rfd;
 /// ## Core Data
 /// - `metadata`: Currently loaded GGUF metadata entries
 /// - `filter`: Text filter for metadata display
+/// - `filter_suggestion`: Key suggestion highlighted under the filter box
+/// - `current_page`, `entries_per_page`: Page of the filtered entries shown in the content panel
+/// - `pinned_keys`: Keys shown in a separate section above the other entries
+/// - `loaded_path`, `loaded_file_metadata`: Displayed file and its size/modification time
+/// - `model_summary`: Name and parameter count of the displayed file, shown in the window title
+/// - `license_info`: License id, link and embedded text shown above the entries
+/// - `changed_keys`, `changed_keys_at`: Keys badged as changed since the file was last opened
 ///
 /// ## Loading State
 /// - `loading`: Whether a file is currently being loaded
 /// - `loading_progress`: Shared progress indicator for async operations
 /// - `loading_result`: Shared result container for async loading
+/// - `load_tracker`: Cancels superseded loads so the most recent file wins
+/// - `load_error`: Localized message from the last failed load
+/// - `load_warning`: Localized warning about the displayed file, such as mismatched header counts
+/// - `loading_path`: File being loaded, promoted to `loaded_path` on success
 ///
 /// ## UI State
 /// - `show_settings`: Whether the settings dialog is visible
 /// - `show_about`: Whether the about dialog is visible
+/// - `show_export_options`, `export_options`: Export options dialog and the options it edits
+/// - `preview_limit`: Tokenizer preview length persisted in [`crate::localization::AppSettings`]
+/// - `auto_reload`, `file_watcher`: Reload the loaded file when it changes on disk
+/// - `remember_filters`, `remembered_filter`: Per-file filter restored when a file is reopened
+/// - `metadata_cache`, `metadata_cache_capacity`: Optional LRU cache of parsed files ([`crate::gui::cache`])
+/// - `use_display_font`: Rubik Distressed display font, or egui's plain fonts when off
+/// - `font_scale`: Multiplier for adaptive font sizes ([`crate::gui::layout::set_font_scale`])
+/// - `high_contrast`: Black, white and yellow high-contrast theme instead of the Inspector theme
 /// - `selected_*`: Currently selected content for right-side panels
+/// - `show_escapes`: Display newlines and tabs as escape sequences (copying stays raw)
+/// - `filter_mode`: Substring or fuzzy filtering; fuzzy mode lists the best matches first
+/// - `folder_scan`: Folder scan window with its sortable table of GGUF files
+/// - `sample_model`: Bundled sample offered by "Load example" while nothing is loaded
+/// - `export`: Export running on a background thread, shown as "Exporting…"
+/// - `export_error`: Localized message from the last failed export
+/// - `export_pending`, `last_action_at`: Ignore repeated Load/export clicks while a dialog or export is pending
 ///
 /// ## Services
 /// - `update_status`: Current update check status message from [`crate::gui::updater::check_for_updates`]
@@ -101,22 +146,100 @@ pub struct GgufApp {
     pub metadata: Vec<MetadataEntry>,
     /// Current filter text for searching through metadata keys and values.
     pub filter: String,
+    /// Index of the highlighted [`suggest_keys`] entry under the filter box, moved with the arrow keys.
+    pub filter_suggestion: Option<usize>,
+    /// Zero-based page of the filtered metadata shown in the content panel; reset when the filter changes.
+    pub current_page: usize,
+    /// Number of metadata entries per content panel page, persisted in [`crate::gui::settings::GuiSettings`].
+    pub entries_per_page: usize,
+    /// Metadata keys pinned above the other entries, persisted in [`crate::localization::AppSettings`].
+    pub pinned_keys: Vec<String>,
     /// Flag indicating whether a file loading operation is currently in progress.
     pub loading: bool,
     /// Shared progress indicator for async file loading operations (0.0 to 1.0).
     pub loading_progress: Arc<Mutex<f32>>,
     /// Shared result container for async loading operations.
     pub loading_result: LoadingResult,
+    /// Generation tracker that cancels superseded loads and discards their results.
+    pub load_tracker: LoadTracker,
+    /// Localized error message from the last failed load, shown in the content panel.
+    pub load_error: Option<String>,
+    /// Localized warning about the displayed file, shown below [`GgufApp::load_error`].
+    pub load_warning: Option<String>,
+    /// Path of the file currently being loaded.
+    pub loading_path: Option<PathBuf>,
+    /// Path of the file whose metadata is displayed.
+    pub loaded_path: Option<PathBuf>,
+    /// File system metadata (size, modification time) of [`GgufApp::loaded_path`].
+    pub loaded_file_metadata: Option<std::fs::Metadata>,
+    /// Summary of [`GgufApp::loaded_path`] shown in the window title; `None` when nothing is displayed.
+    pub model_summary: Option<ModelSummary>,
+    /// License of the displayed file from its `general.license*` keys; see [`extract_license_info`].
+    pub license_info: Option<LicenseInfo>,
+    /// Keys that changed or were added since [`GgufApp::loaded_path`] was last opened.
+    pub changed_keys: BTreeSet<String>,
+    /// When [`GgufApp::changed_keys`] were computed; they are badged for [`CHANGED_BADGE_DURATION`].
+    pub changed_keys_at: Option<Instant>,
     /// Flag controlling the visibility of the settings dialog window.
     pub show_settings: bool,
     /// Flag controlling the visibility of the about dialog window.
     pub show_about: bool,
+    /// Number of tokenizer tokens/merges previewed for newly loaded files (`0` shows all).
+    pub preview_limit: usize,
+    /// Whether the loaded file is reloaded automatically when it changes on disk.
+    pub auto_reload: bool,
+    /// Watcher for [`GgufApp::loaded_path`], active while `auto_reload` is enabled.
+    pub file_watcher: Option<FileWatcher>,
+    /// Whether the filter of each file is restored when it is reopened, persisted in [`crate::localization::AppSettings`].
+    pub remember_filters: bool,
+    /// Filter last stored for [`GgufApp::loaded_path`]; the filter is stored again once it differs.
+    pub remembered_filter: String,
+    /// Cache of parsed metadata keyed by file hash; `None` while caching is disabled.
+    pub metadata_cache: Option<SharedMetadataCache>,
+    /// Number of files kept by [`GgufApp::metadata_cache`].
+    pub metadata_cache_capacity: usize,
+    /// Whether the Rubik Distressed display font is used (see [`crate::gui::theme::load_fonts`]).
+    pub use_display_font: bool,
+    /// Multiplier for adaptive font sizes, persisted in [`crate::localization::AppSettings`].
+    pub font_scale: f32,
+    /// Manager used for every settings read and write of the app.
+    settings_manager: SettingsManager,
+    /// Whether the high-contrast theme is applied (see [`crate::gui::theme::apply_high_contrast_theme`]).
+    pub high_contrast: bool,
     /// Currently selected chat template content for right-side panel display.
     pub selected_chat_template: Option<String>,
     /// Currently selected GGML tokens content for right-side panel display.
     pub selected_ggml_tokens: Option<String>,
     /// Currently selected GGML merges content for right-side panel display.
     pub selected_ggml_merges: Option<String>,
+    /// Whether values and tokenizer panels show whitespace as `\n`/`\t` escapes.
+    pub show_escapes: bool,
+    /// How [`GgufApp::filter`] selects and orders the displayed entries.
+    pub filter_mode: FilterMode,
+    /// Data URI of the embedded preview image shown in the right-side panel.
+    pub selected_image: Option<String>,
+    /// Key and bytes of the value shown in the hex view panel.
+    pub selected_hex: Option<(String, Vec<u8>)>,
+    /// Folder scan window state and its background scan.
+    pub folder_scan: FolderScan,
+    /// Bundled sample model, located once at startup; `None` hides "Load example".
+    pub sample_model: Option<PathBuf>,
+    /// Export currently being written on a background thread.
+    pub export: BackgroundExport,
+    /// Localized message from the last failed export, shown under the export buttons.
+    pub export_error: Option<String>,
+    /// Whether an export's save dialog is open or its export is still being written.
+    pub export_pending: bool,
+    /// When the Load or an export button was last accepted; see [`should_accept_action`].
+    pub last_action_at: Option<Instant>,
+    /// Flag controlling the visibility of the export options dialog window.
+    pub show_export_options: bool,
+    /// Options applied to every export, persisted in [`crate::gui::settings::GuiSettings`].
+    pub export_options: ExportOptions,
+    /// Text of the include patterns field, parsed into [`ExportOptions::include_keys`].
+    pub export_include_text: String,
+    /// Text of the exclude patterns field, parsed into [`ExportOptions::exclude_keys`].
+    pub export_exclude_text: String,
     /// Current status message from update checking operations.
     pub update_status: Option<String>,
     /// Localization manager for multi-language support and text translation.
@@ -131,17 +254,75 @@ impl Default for GgufApp {
                 LocalizationManager::default()
             });
             
+        // Created once: creating a manager probes the settings directory with a test write
+        let settings_manager = SettingsManager::new().unwrap_or_default();
+        let settings = settings_manager.load_settings().ok();
+        let preview_limit = settings.as_ref().map(|s| s.preview_limit).unwrap_or(DEFAULT_PREVIEW_LIMIT);
+        let auto_reload = settings.as_ref().is_some_and(|s| s.auto_reload);
+        let remember_filters = settings.as_ref().is_some_and(|s| s.remember_filters);
+        let gui_settings = settings.as_ref().map(GuiSettings::from_settings).unwrap_or_default();
+        let metadata_cache_capacity = gui_settings.metadata_cache_capacity;
+        let use_display_font = settings.as_ref().is_none_or(|s| s.use_display_font);
+        let font_scale = settings.as_ref().map_or(1.0, |s| s.font_scale);
+        let high_contrast = settings.as_ref().is_some_and(|s| s.high_contrast);
+        let entries_per_page = gui_settings.entries_per_page;
+        let pinned_keys = settings.as_ref().map(|s| s.pinned_keys.clone()).unwrap_or_default();
+        let export_options = gui_settings.export_options;
+        let metadata_cache = settings
+            .as_ref()
+            .is_some_and(|s| s.metadata_cache)
+            .then(|| MetadataCache::shared(metadata_cache_capacity));
+
         Self {
             metadata: Vec::new(),
             filter: String::new(),
+            filter_suggestion: None,
+            current_page: 0,
+            entries_per_page,
+            pinned_keys,
             loading: false,
             loading_progress: Arc::new(Mutex::new(0.0)),
             loading_result: Arc::new(Mutex::new(None)),
+            load_tracker: LoadTracker::default(),
+            load_error: None,
+            load_warning: None,
+            loading_path: None,
+            loaded_path: None,
+            loaded_file_metadata: None,
+            model_summary: None,
+            license_info: None,
+            changed_keys: BTreeSet::new(),
+            changed_keys_at: None,
             show_settings: false,
             show_about: false,
+            preview_limit,
+            auto_reload,
+            file_watcher: None,
+            remember_filters,
+            remembered_filter: String::new(),
+            metadata_cache,
+            metadata_cache_capacity,
+            use_display_font,
+            font_scale,
+            settings_manager,
+            high_contrast,
             selected_chat_template: None,
             selected_ggml_tokens: None,
             selected_ggml_merges: None,
+            show_escapes: false,
+            filter_mode: FilterMode::default(),
+            selected_image: None,
+            selected_hex: None,
+            folder_scan: FolderScan::default(),
+            sample_model: locate_sample_model(),
+            export: BackgroundExport::default(),
+            export_error: None,
+            export_pending: false,
+            last_action_at: None,
+            show_export_options: false,
+            export_include_text: export_options.include_keys.as_deref().unwrap_or_default().join(", "),
+            export_exclude_text: export_options.exclude_keys.join(", "),
+            export_options,
             update_status: None,
             localization_manager,
         }
@@ -153,8 +334,13 @@ impl eframe::App for GgufApp {
         puffin::GlobalProfiler::lock().new_frame();
 
         // Load custom font and apply theme
-        load_custom_font(ctx);
-        apply_inspector_theme(ctx);
+        load_fonts(ctx, self.use_display_font);
+        set_font_scale(ctx, self.font_scale);
+        if self.high_contrast {
+            apply_high_contrast_theme(ctx);
+        } else {
+            apply_inspector_theme(ctx);
+        }
 
         // Update loading progress
         let current_progress = if let Ok(progress) = self.loading_progress.try_lock() {
@@ -163,38 +349,107 @@ impl eframe::App for GgufApp {
             0.0
         };
 
+        // Reload the open file once it has stopped changing on disk
+        if !self.loading
+            && let Some(watcher) = &self.file_watcher
+            && watcher.poll(Instant::now())
+        {
+            let path = watcher.path().to_path_buf();
+            self.start_loading(path);
+        }
+
+        // The filter is stored once editing is done rather than on every key
+        if !ctx.memory(|m| m.has_focus(egui::Id::new("metadata_filter"))) {
+            self.save_file_filter();
+        }
+
         // Handle loading completion
+        let mut loaded_report: Option<ModelReport> = None;
         if self.loading {
-            if current_progress < 0.0 {
-                self.loading = false; // Error
-            } else if current_progress >= 1.0 {
-                // Check loading result
+            if current_progress < 0.0 || current_progress >= 1.0 {
+                // Check loading result (errors set negative progress, then the Err result)
                 if let Ok(mut result) = self.loading_result.try_lock()
                     && let Some(load_result) = result.take()
                 {
                     self.loading = false;
                     match load_result {
-                        Ok(metadata) => {
-                            self.metadata = metadata.into_iter()
-                                .map(|(key, display_value, full_value)| MetadataEntry {
-                                    key,
-                                    display_value,
-                                    full_value,
-                                })
-                                .collect();
+                        Ok(file) => {
+                            self.load_error = None;
+                            // Reloading the same file keeps the current page and filter
+                            let new_file = self.loading_path != self.loaded_path;
+                            if new_file {
+                                self.current_page = 0;
+                                self.save_file_filter();
+                            }
+                            self.loaded_path = self.loading_path.take();
+                            self.loaded_file_metadata = self
+                                .loaded_path
+                                .as_ref()
+                                .and_then(|path| std::fs::metadata(path).ok());
+                            loaded_report = Some(file.report);
+                            self.metadata = file.entries;
+                            self.track_changed_keys();
+                            if new_file {
+                                self.restore_file_filter();
+                            }
                         }
                         Err(e) => {
-                            eprintln!("{}", self.t_with_args("messages.parsing_error", &[&e.to_string()]));
+                            let message = match &e {
+                                LoadError::Format(e) => self.t(e.translation_key()),
+                                LoadError::Io(e) => self.t_with_args("messages.file_open_error", &[e.as_str()]),
+                                LoadError::Parse(e) => self.t_with_args("messages.parsing_error", &[e.as_str()]),
+                            };
+                            eprintln!("{}", message);
+                            self.load_error = Some(message);
+                            self.loading_path = None;
                         }
                     }
                 }
             }
         }
+        if let Some(report) = loaded_report {
+            self.refresh_file_watcher(ctx);
+            // Built by the loader thread from the content it parsed
+            self.load_warning = report.count_mismatch.map(|mismatch| {
+                eprintln!("Warning: {}", mismatch);
+                self.t_with_args(
+                    "messages.count_mismatch",
+                    &[
+                        &mismatch.header_tensor_count.to_string(),
+                        &mismatch.parsed_tensor_count.to_string(),
+                        &mismatch.header_kv_count.to_string(),
+                        &mismatch.parsed_kv_count.to_string(),
+                    ],
+                )
+            });
+            self.model_summary = Some(report.summary);
+            self.license_info = extract_license_info(&self.metadata);
+            self.update_window_title(ctx);
+        }
+
+        // Handle export completion
+        let outcome = self.export.poll();
+        if outcome.is_some() {
+            self.export_pending = false;
+        }
+        match outcome {
+            Some(Ok(_)) => self.export_error = None,
+            Some(Err(e)) => {
+                let message = self.t_with_args("messages.export_failed", &[&e]);
+                eprintln!("{}", message);
+                self.export_error = Some(message);
+            }
+            None => {}
+        }
 
         // Pre-compute translation strings to avoid borrowing issues
         let t_chat_template = self.t("panels.chat_template");
         let t_ggml_tokens = self.t("panels.ggml_tokens");
         let t_ggml_merges = self.t("panels.ggml_merges");
+        let t_image_preview = self.t("panels.image_preview");
+        let t_hex_view = self.t("panels.hex_view");
+        let t_copy = self.t("buttons.copy");
+        let t_show_escapes = self.t("buttons.show_escapes");
 
         // Render right-side panels for special content
         dialogs::render_right_side_panels(
@@ -202,10 +457,19 @@ impl eframe::App for GgufApp {
             &mut self.selected_chat_template,
             &mut self.selected_ggml_tokens,
             &mut self.selected_ggml_merges,
+            &mut self.show_escapes,
             &t_chat_template,
             &t_ggml_tokens,
             &t_ggml_merges,
+            &t_show_escapes,
         );
+        dialogs::render_image_panel(ctx, &mut self.selected_image, &t_image_preview);
+        dialogs::render_hex_panel(ctx, &mut self.selected_hex, &t_hex_view, &t_copy);
+
+        // Folder scan window; clicking a file opens it
+        if let Some(path) = render_scan_window(ctx, &mut self.folder_scan, &self.localization_manager) {
+            self.start_loading(path);
+        }
 
         // Render sidebar panel using the dedicated function
         egui::SidePanel::left("inspector_toolkit")
@@ -237,15 +501,26 @@ impl eframe::App for GgufApp {
                             )
                             .clicked()
                             && !self.loading
-                            && let Some(path) = rfd::FileDialog::new().pick_file()
+                            && let Some(path) = self.pick_gguf_file()
                         {
-                            self.loading = true;
-                            *self.loading_progress.lock().unwrap() = 0.0;
-                            *self.loading_result.lock().unwrap() = None;
+                            self.start_loading(path);
+                        }
 
-                            let progress_clone = Arc::clone(&self.loading_progress);
-                            let result_clone = Arc::clone(&self.loading_result);
-                            crate::gui::loader::load_gguf_metadata_async(path, progress_clone, result_clone);
+                        // Scan folder button
+                        let scan_text = format!("{} {}", egui_phosphor::regular::FOLDERS, self.t("buttons.scan_folder"));
+
+                        if ui
+                            .add_sized(
+                                [button_width, button_height],
+                                egui::Button::new(
+                                    egui::RichText::new(scan_text)
+                                    .size(get_adaptive_font_size(16.0, ctx)),
+                                ),
+                            )
+                            .clicked()
+                            && let Some(dir) = rfd::FileDialog::new().pick_folder()
+                        {
+                            self.folder_scan.start(dir);
                         }
 
                         // Clear button
@@ -262,6 +537,10 @@ impl eframe::App for GgufApp {
                             .clicked()
                         {
                             self.metadata.clear();
+                            self.model_summary = None;
+                            self.license_info = None;
+                            self.load_warning = None;
+                            self.update_window_title(ctx);
                         }
 
                         ui.add_space(16.0);
@@ -272,6 +551,10 @@ impl eframe::App for GgufApp {
                         );
                         
                         let small_button_height = get_adaptive_font_size(28.0, ctx);
+                        // Default file names are derived from the model name
+                        let export_name = crate::gui::export::export_base_name(
+                            self.metadata.iter().find(|entry| entry.key == "general.name").map(|entry| entry.display_value.as_str()),
+                        );
                         
                         // CSV Export button
                         let csv_text = format!("{} {}", egui_phosphor::regular::FILE_CSV, self.t("export.csv"));
@@ -285,10 +568,9 @@ impl eframe::App for GgufApp {
                                 ),
                             )
                             .clicked()
-                            && let Some(path) = rfd::FileDialog::new().save_file()
-                            && let Err(e) = crate::gui::export::export_csv(&self.metadata.iter().map(|entry| (&entry.key, &entry.display_value)).collect::<Vec<_>>(), &path)
+                            && let Some(path) = self.pick_export_path(format!("{}.csv", export_name))
                         {
-                            eprintln!("{}", self.t_with_args("messages.export_failed", &[&e.to_string()]));
+                            self.start_export(ExportFormat::Csv, path);
                         }
                         
                         // YAML Export button
@@ -303,10 +585,9 @@ impl eframe::App for GgufApp {
                                 ),
                             )
                             .clicked()
-                            && let Some(path) = rfd::FileDialog::new().save_file()
-                            && let Err(e) = crate::gui::export::export_yaml(&self.metadata.iter().map(|entry| (&entry.key, &entry.display_value)).collect::<Vec<_>>(), &path)
+                            && let Some(path) = self.pick_export_path(format!("{}.yaml", export_name))
                         {
-                            eprintln!("{}", self.t_with_args("messages.export_failed", &[&e.to_string()]));
+                            self.start_export(ExportFormat::Yaml, path);
                         }
                         
                         // Markdown Export button
@@ -323,10 +604,9 @@ impl eframe::App for GgufApp {
                                 ),
                             )
                             .clicked()
-                            && let Some(path) = rfd::FileDialog::new().save_file()
-                            && let Err(e) = crate::gui::export::export_markdown_to_file(&self.metadata.iter().map(|entry| (&entry.key, &entry.display_value)).collect::<Vec<_>>(), &path)
+                            && let Some(path) = self.pick_export_path(format!("{}.md", export_name))
                         {
-                            eprintln!("{}", self.t_with_args("messages.export_failed", &[&e.to_string()]));
+                            self.start_export(ExportFormat::Markdown, path);
                         }
                         
                         // HTML Export button
@@ -339,10 +619,9 @@ impl eframe::App for GgufApp {
                                 ),
                             )
                             .clicked()
-                            && let Some(path) = rfd::FileDialog::new().save_file()
-                            && let Err(e) = crate::gui::export::export_html_to_file(&self.metadata.iter().map(|entry| (&entry.key, &entry.display_value)).collect::<Vec<_>>(), &path)
+                            && let Some(path) = self.pick_export_path(format!("{}.html", export_name))
                         {
-                            eprintln!("{}", self.t_with_args("messages.export_failed", &[&e.to_string()]));
+                            self.start_export(ExportFormat::Html, path);
                         }
                         
                         // PDF Export button
@@ -359,12 +638,68 @@ impl eframe::App for GgufApp {
                                 ),
                             )
                             .clicked()
-                            && let Some(path) = rfd::FileDialog::new().save_file()
+                            && let Some(path) = self.pick_export_path(format!("{}.pdf", export_name))
                         {
-                            let md = crate::gui::export::export_markdown(&self.metadata.iter().map(|entry| (&entry.key, &entry.display_value)).collect::<Vec<_>>());
-                            if let Err(e) = crate::gui::export::export_pdf_from_markdown(&md, &path) {
-                                eprintln!("{}", self.t_with_args("messages.export_failed", &[&e.to_string()]));
-                            }
+                            self.start_export(ExportFormat::Pdf, path);
+                        }
+
+                        // Model card: header and general.* keys only, as Markdown
+                        if ui
+                            .add_sized(
+                                [button_width, small_button_height],
+                                egui::Button::new(
+                                    egui::RichText::new(format!(
+                                        "{} {}",
+                                        egui_phosphor::regular::IDENTIFICATION_CARD,
+                                        self.t("export.model_card")
+                                    ))
+                                    .size(get_adaptive_font_size(16.0, ctx)),
+                                ),
+                            )
+                            .clicked()
+                            && let Some(path) = self.pick_export_path(format!("{}-card.md", export_name))
+                        {
+                            self.start_model_card_export(path);
+                        }
+
+                        // Export options button
+                        if ui
+                            .add_sized(
+                                [button_width, small_button_height],
+                                egui::Button::new(
+                                    egui::RichText::new(format!(
+                                        "{} {}",
+                                        egui_phosphor::regular::SLIDERS,
+                                        self.t("buttons.export_options")
+                                    ))
+                                    .size(get_adaptive_font_size(16.0, ctx)),
+                                ),
+                            )
+                            .clicked()
+                        {
+                            self.show_export_options = !self.show_export_options;
+                        }
+
+                        // Exports run on a worker thread; the spinner keeps repainting until it finishes
+                        if self.export.is_running() {
+                            ui.horizontal(|ui| {
+                                ui.spinner();
+                                ui.label(
+                                    egui::RichText::new(self.t("messages.exporting"))
+                                        .size(get_adaptive_font_size(14.0, ctx))
+                                        .color(TECH_GRAY),
+                                );
+                                if ui
+                                    .small_button(egui_phosphor::regular::X)
+                                    .on_hover_text(self.t("buttons.cancel_export"))
+                                    .clicked()
+                                {
+                                    self.export.cancel();
+                                    self.export_pending = false;
+                                }
+                            });
+                        } else if let Some(ref error) = self.export_error {
+                            ui.label(egui::RichText::new(error).color(DANGER_RED).size(get_adaptive_font_size(12.0, ctx)));
                         }
 
                         ui.add_space(16.0);
@@ -413,46 +748,67 @@ impl eframe::App for GgufApp {
             )
             .show(ctx, |ui| {
                 // Handle drag and drop
+                render_drop_overlay(ctx, &*self);
                 let dropped = ctx.input(|i| i.raw.dropped_files.clone());
                 if !dropped.is_empty() {
+                    // The most recent drop wins: starting a load cancels the in-flight one
                     for df in dropped {
-                        if !self.loading
-                            && let Some(path) = df.path
-                        {
-                            self.loading = true;
-                            *self.loading_progress.lock().unwrap() = 0.0;
-                            *self.loading_result.lock().unwrap() = None;
-                            let progress_clone = Arc::clone(&self.loading_progress);
-                            let result_clone = Arc::clone(&self.loading_result);
-                            crate::gui::loader::load_gguf_metadata_async(path, progress_clone, result_clone);
+                        let accepted = match (&df.path, &df.bytes) {
+                            (Some(path), _) => accept_dropped(DroppedInput::Path(path)),
+                            (None, Some(bytes)) => accept_dropped(DroppedInput::Bytes(bytes)),
+                            (None, None) => false,
+                        };
+                        if !accepted {
+                            let name = df
+                                .path
+                                .as_ref()
+                                .and_then(|path| path.file_name())
+                                .map(|name| name.to_string_lossy().into_owned())
+                                .unwrap_or(df.name);
+                            self.load_error = Some(self.t_with_args("errors.not_gguf_file", &[&name]));
+                            continue;
+                        }
+                        if let Some(path) = df.path {
+                            self.start_loading(path);
                         } else if let Some(bytes) = df.bytes {
                             // Save to temporary file and load
                             let tmp = std::env::temp_dir().join(&df.name);
                             match std::fs::write(&tmp, &*bytes) {
-                                Ok(_) => {
-                                    self.loading = true;
-                                    *self.loading_progress.lock().unwrap() = 0.0;
-                                    *self.loading_result.lock().unwrap() = None;
-                                    let progress_clone = Arc::clone(&self.loading_progress);
-                                    let result_clone = Arc::clone(&self.loading_result);
-                                    crate::gui::loader::load_gguf_metadata_async(tmp, progress_clone, result_clone);
-                                }
+                                Ok(_) => self.start_loading(tmp),
                                 Err(e) => eprintln!("{}", self.t_with_args("messages.file_open_error", &[&e.to_string()])),
                             }
                         }
                     }
                 }
 
+                // Basic facts about the displayed file
+                if let (Some(path), Some(file_metadata)) = (&self.loaded_path, &self.loaded_file_metadata) {
+                    let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+                    let modified = file_metadata
+                        .modified()
+                        .map(|time| format_date(&chrono::DateTime::<chrono::Local>::from(time), self.localization_manager.get_current_language()))
+                        .unwrap_or_else(|_| "—".to_string());
+                    let info = self.t_with_args("messages.file_info", &[&name, &human_bytes(file_metadata.len()), &modified]);
+                    ui.label(egui::RichText::new(info).color(TECH_GRAY).size(get_adaptive_font_size(14.0, ctx)));
+                }
+
                 // Show progress bar if loading
                 if self.loading {
                     ui.add(
                         egui::ProgressBar::new(current_progress)
-                            .show_percentage()
+                            .text(self.format_percent(current_progress))
                             .fill(egui::Color32::from_rgb(30, 58, 138)),
                     );
                     ui.label(egui::RichText::new(self.t("messages.loading")).color(TECH_GRAY).size(get_adaptive_font_size(14.0, ctx)));
                 }
 
+                if let Some(ref error) = self.load_error {
+                    ui.label(egui::RichText::new(error).color(DANGER_RED).size(get_adaptive_font_size(14.0, ctx)));
+                }
+                if let Some(ref warning) = self.load_warning {
+                    ui.label(egui::RichText::new(warning).color(GADGET_YELLOW).size(get_adaptive_font_size(14.0, ctx)));
+                }
+
                 // Filter section
                 ui.horizontal(|ui| {
                     ui.label(egui::RichText::new(format!("{}:", self.t("buttons.filter"))).color(TECH_GRAY).size(get_adaptive_font_size(14.0, ctx)));
@@ -461,13 +817,29 @@ impl eframe::App for GgufApp {
                     let label_width = get_adaptive_font_size(50.0, ctx);
                     let button_width = get_adaptive_font_size(120.0, ctx);
 
-                    let total_reserved_width = label_width + if !self.filter.is_empty() { button_width } else { 0.0 };
+                    let table_button_width = get_adaptive_font_size(220.0, ctx);
+
+                    let total_reserved_width = label_width
+                        + if !self.filter.is_empty() { button_width } else { 0.0 }
+                        + if !self.metadata.is_empty() { table_button_width + button_width } else { 0.0 };
                     let filter_width = (available_width - total_reserved_width).clamp(100.0, 400.0);
 
-                    ui.add_sized(
+                    let filter_id = egui::Id::new("metadata_filter");
+                    let suggestions = self.filter_suggestions();
+                    // Keys are taken before the text edit sees them, so arrows don't move its cursor
+                    if !suggestions.is_empty() && ctx.memory(|m| m.has_focus(filter_id)) {
+                        self.handle_suggestion_keys(ctx, filter_id, &suggestions);
+                    }
+
+                    let filter_response = ui.add_sized(
                         [filter_width, get_adaptive_font_size(20.0, ctx)],
-                        egui::TextEdit::singleline(&mut self.filter)
+                        egui::TextEdit::singleline(&mut self.filter).id(filter_id)
                     );
+                    if filter_response.changed() {
+                        self.current_page = 0;
+                        self.filter_suggestion = None;
+                    }
+                    self.show_filter_suggestions(ctx, &filter_response);
 
                     if !self.filter.is_empty()
                         && ui.add_sized(
@@ -480,106 +852,356 @@ impl eframe::App for GgufApp {
                         ).clicked()
                     {
                         self.filter.clear();
+                        self.current_page = 0;
+                    }
+
+                    // Display-only: copy actions still use the raw values
+                    ui.toggle_value(&mut self.show_escapes, "\\n")
+                        .on_hover_text(self.t("buttons.show_escapes"));
+
+                    let mut fuzzy = self.filter_mode == FilterMode::Fuzzy;
+                    if ui.toggle_value(&mut fuzzy, "~").on_hover_text(self.t("buttons.fuzzy_filter")).changed() {
+                        self.filter_mode = if fuzzy { FilterMode::Fuzzy } else { FilterMode::Substring };
+                        self.current_page = 0;
+                    }
+
+                    // Filtered entries as a GFM table, ready to paste into GitHub issues
+                    if !self.metadata.is_empty()
+                        && ui.add_sized(
+                            [table_button_width, get_adaptive_font_size(20.0, ctx)],
+                            egui::Button::new(format!(
+                                "{} {}",
+                                egui_phosphor::regular::TABLE,
+                                self.t("buttons.copy_markdown_table")
+                            ))
+                        ).clicked()
+                    {
+                        let filtered: Vec<_> = filter_entries(&self.metadata, &self.filter, self.filter_mode)
+                            .into_iter()
+                            .map(|entry| (&entry.key, &entry.display_value))
+                            .collect();
+                        ctx.copy_text(crate::gui::export::export_markdown_table(
+                            &filtered,
+                            crate::gui::export::MARKDOWN_TABLE_VALUE_LIMIT,
+                        ));
+                    }
+
+                    // Every loaded key, one per line, regardless of the filter
+                    if !self.metadata.is_empty()
+                        && ui.add_sized(
+                            [button_width, get_adaptive_font_size(20.0, ctx)],
+                            egui::Button::new(format!(
+                                "{} {}",
+                                egui_phosphor::regular::LIST_BULLETS,
+                                self.t("buttons.copy_key_list")
+                            ))
+                        ).clicked()
+                    {
+                        let keys: Vec<&str> = self.metadata.iter().map(|entry| entry.key.as_str()).collect();
+                        ctx.copy_text(keys.join("\n"));
                     }
                 });
 
                 // Pre-compute translated strings to avoid borrowing issues
                 let view_text = self.t("buttons.view");
                 let no_metadata_text = self.t("messages.no_metadata");
+                let load_example_text = format!("{} {}", egui_phosphor::regular::CUBE, self.t("buttons.load_example"));
+                let show_load_example = self.metadata.is_empty() && !self.loading && self.sample_model.is_some();
                 let binary_long_text = self.t("data.binary_long");
                 let base64_text = self.t("data.base64");
-                
+                let copy_text = self.t("buttons.copy");
+                let previous_page_text = self.t("buttons.previous_page");
+                let next_page_text = self.t("buttons.next_page");
+                let pin_text = self.t("buttons.pin_key");
+                let unpin_text = self.t("buttons.unpin_key");
+                let export_raw_text = self.t("buttons.export_raw_bytes");
+                let hex_view_text = self.t("buttons.hex_view");
+                let changed_text = self.t("messages.key_changed");
+                let changed_hint = self.t("messages.key_changed_hint");
+
+                // Changed keys are badged briefly after a load; repaint once more to clear the badges
+                let badge_remaining = self
+                    .changed_keys_at
+                    .map(|at| CHANGED_BADGE_DURATION.saturating_sub(at.elapsed()))
+                    .unwrap_or_default();
+                let badge_changed = !badge_remaining.is_zero() && !self.changed_keys.is_empty();
+                if badge_changed {
+                    ctx.request_repaint_after(badge_remaining);
+                }
+                let pinned_heading = format!("{} {}", egui_phosphor::regular::PUSH_PIN, self.t("messages.pinned_keys"));
+                let other_heading = self.t("messages.other_keys");
+                let license_heading = format!("{} {}", egui_phosphor::regular::SCROLL, self.t("panels.license"));
+                let copy_license_text = self.t("buttons.copy_license");
+
+                // Pinned entries stay above the pages; pagination applies to the remaining filtered entries
+                let filtered = filter_entries(&self.metadata, &self.filter, self.filter_mode);
+                let (pinned, filtered) = partition_pinned(&filtered, &self.pinned_keys);
+                let pages = page_count(filtered.len(), self.entries_per_page);
+                self.current_page = self.current_page.min(pages - 1);
+                if pages > 1 {
+                    let page_text = self.t_with_args(
+                        "messages.page_indicator",
+                        &[
+                            &self.format_number(self.current_page as u64 + 1),
+                            &self.format_number(pages as u64),
+                            &self.format_number(filtered.len() as u64),
+                        ],
+                    );
+                    ui.horizontal(|ui| {
+                        if ui
+                            .add_enabled(
+                                self.current_page > 0,
+                                egui::Button::new(format!("{} {}", egui_phosphor::regular::CARET_LEFT, previous_page_text)),
+                            )
+                            .clicked()
+                        {
+                            self.current_page -= 1;
+                        }
+                        ui.label(egui::RichText::new(page_text).color(TECH_GRAY).size(get_adaptive_font_size(14.0, ctx)));
+                        if ui
+                            .add_enabled(
+                                self.current_page + 1 < pages,
+                                egui::Button::new(format!("{} {}", next_page_text, egui_phosphor::regular::CARET_RIGHT)),
+                            )
+                            .clicked()
+                        {
+                            self.current_page += 1;
+                        }
+                    });
+                }
+                let page_entries = paginate(&filtered, self.current_page, self.entries_per_page);
+
+                let mut toggled_pin: Option<String> = None;
+                let mut raw_export_key: Option<String> = None;
+                let mut hex_view_key: Option<String> = None;
+                let mut load_example = false;
                 egui::ScrollArea::vertical()
                     .auto_shrink([false; 2])
                     .show(ui, |ui| {
-                        let mut first = true;
-                        for entry in self
-                            .metadata
-                            .iter()
-                            .filter(|entry| entry.key.contains(&self.filter) || entry.display_value.contains(&self.filter))
-                        {
-                            let k = &entry.key;
-                            let v = &entry.display_value;
+                        if let Some(license) = &self.license_info {
                             ui.group(|ui| {
-                                ui.vertical(|ui| {
-                                    ui.label(egui::RichText::new(k).color(GADGET_YELLOW).strong().size(get_adaptive_font_size(14.0, ctx)));
-                                    ui.add_space(get_adaptive_font_size(4.0, ctx));
-                                    
-                                    if k == "tokenizer.chat_template" {
-                                        if ui
-                                            .button(format!(
-                                                "{} {}",
-                                                egui_phosphor::regular::EYE,
-                                                view_text
-                                            ))
-                                            .clicked()
-                                        {
-                                            self.selected_ggml_tokens = None;
-                                            self.selected_ggml_merges = None;
-                                            self.selected_chat_template = entry.full_value.clone();
-                                        }
-                                    } else if k == "tokenizer.ggml.tokens" {
-                                        if ui
-                                            .button(format!(
-                                                "{} {}",
-                                                egui_phosphor::regular::EYE,
-                                                view_text
-                                            ))
-                                            .clicked()
-                                        {
-                                            self.selected_chat_template = None;
-                                            self.selected_ggml_merges = None;
-                                            self.selected_ggml_tokens = entry.full_value.clone();
+                                egui::CollapsingHeader::new(
+                                    egui::RichText::new(&license_heading).color(GADGET_YELLOW).strong().size(get_adaptive_font_size(14.0, ctx)),
+                                )
+                                .id_salt("license_section")
+                                .show(ui, |ui| {
+                                    ui.horizontal_wrapped(|ui| {
+                                        for part in [&license.name, &license.id].into_iter().flatten() {
+                                            ui.label(egui::RichText::new(part).color(egui::Color32::WHITE).size(get_adaptive_font_size(12.0, ctx)));
                                         }
-                                    } else if k == "tokenizer.ggml.merges" {
-                                        if ui
-                                            .button(format!(
-                                                "{} {}",
-                                                egui_phosphor::regular::EYE,
-                                                view_text
-                                            ))
-                                            .clicked()
+                                        if let Some(link) = &license.link
+                                            && ui.link(egui::RichText::new(link).size(get_adaptive_font_size(12.0, ctx))).clicked()
+                                            && let Err(e) = opener::open(link)
                                         {
-                                            self.selected_chat_template = None;
-                                            self.selected_ggml_tokens = None;
-                                            self.selected_ggml_merges = entry.full_value.clone();
+                                            eprintln!("Failed to open {}: {}", link, e);
                                         }
-                                    } else if v.len() > 1024 || v.contains("\0") {
+                                    });
+                                    if let Some(text) = &license.text {
+                                        egui::ScrollArea::vertical()
+                                            .id_salt("license_text")
+                                            .max_height(get_adaptive_font_size(200.0, ctx))
+                                            .show(ui, |ui| {
+                                                ui.label(egui::RichText::new(text).monospace().color(egui::Color32::LIGHT_GRAY).size(get_adaptive_font_size(12.0, ctx)));
+                                            });
+                                    }
+                                    if ui.button(format!("{} {}", egui_phosphor::regular::COPY, copy_license_text)).clicked() {
+                                        ctx.copy_text(license.clipboard_text());
+                                    }
+                                });
+                            });
+                            ui.add_space(get_adaptive_font_size(8.0, ctx));
+                        }
+                        let mut first = true;
+                        for (heading, entries) in [(&pinned_heading, pinned.as_slice()), (&other_heading, page_entries)] {
+                            // Section headings only appear while something is pinned
+                            if !pinned.is_empty() && !entries.is_empty() {
+                                ui.label(egui::RichText::new(heading).color(TECH_GRAY).strong().size(get_adaptive_font_size(14.0, ctx)));
+                                ui.add_space(get_adaptive_font_size(4.0, ctx));
+                            }
+                            for &entry in entries {
+                                let k = &entry.key;
+                                let v = &entry.display_value;
+                                let is_pinned = self.pinned_keys.contains(k);
+                                let is_changed = badge_changed && self.changed_keys.contains(k);
+                                ui.group(|ui| {
+                                    ui.vertical(|ui| {
                                         ui.horizontal(|ui| {
-                                            ui.label(
-                                                egui::RichText::new(&binary_long_text)
-                                                    .color(egui::Color32::LIGHT_GRAY)
-                                                    .size(get_adaptive_font_size(12.0, ctx)),
-                                            );
+                                            ui.label(egui::RichText::new(k).color(GADGET_YELLOW).strong().size(get_adaptive_font_size(14.0, ctx)));
+                                            if is_changed {
+                                                ui.label(
+                                                    egui::RichText::new(format!("{} {}", egui_phosphor::regular::SPARKLE, changed_text))
+                                                        .color(SUCCESS_GREEN)
+                                                        .size(get_adaptive_font_size(12.0, ctx)),
+                                                )
+                                                .on_hover_text(&changed_hint);
+                                            }
+                                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                                if ui
+                                                    .small_button(egui_phosphor::regular::COPY)
+                                                    .on_hover_text(&copy_text)
+                                                    .clicked()
+                                                {
+                                                    ctx.copy_text(entry_clipboard_text(entry));
+                                                }
+                                                let star_color = if is_pinned { GADGET_YELLOW } else { TECH_GRAY };
+                                                if ui
+                                                    .small_button(egui::RichText::new(egui_phosphor::regular::STAR).color(star_color))
+                                                    .on_hover_text(if is_pinned { &unpin_text } else { &pin_text })
+                                                    .clicked()
+                                                {
+                                                    toggled_pin = Some(k.clone());
+                                                }
+                                                if entry.raw_bytes.is_some()
+                                                    && ui
+                                                        .small_button(egui_phosphor::regular::DOWNLOAD_SIMPLE)
+                                                        .on_hover_text(&export_raw_text)
+                                                        .clicked()
+                                                {
+                                                    raw_export_key = Some(k.clone());
+                                                }
+                                                if hex_view_bytes(entry).is_some()
+                                                    && ui
+                                                        .small_button(egui_phosphor::regular::BINARY)
+                                                        .on_hover_text(&hex_view_text)
+                                                        .clicked()
+                                                {
+                                                    hex_view_key = Some(k.clone());
+                                                }
+                                            });
+                                        });
+                                        ui.add_space(get_adaptive_font_size(4.0, ctx));
+                                        
+                                        if k == "tokenizer.chat_template" {
                                             if ui
                                                 .button(format!(
-                                                    "{} {} {}",
+                                                    "{} {}",
                                                     egui_phosphor::regular::EYE,
-                                                    view_text,
-                                                    base64_text
+                                                    view_text
                                                 ))
                                                 .clicked()
-                                                && let Err(e) = crate::gui::export::show_base64_dialog(v)
                                             {
-                                                eprintln!("Export failed: {}", e);
+                                                self.selected_ggml_tokens = None;
+                                                self.selected_ggml_merges = None;
+                                                self.selected_chat_template = entry.full_value.clone();
                                             }
-                                        });
-                                    } else {
-                                        ui.label(
-                                            egui::RichText::new(v).color(egui::Color32::WHITE).size(get_adaptive_font_size(12.0, ctx)),
-                                        );
-                                    }
+                                        } else if k == "tokenizer.ggml.tokens" {
+                                            let tokens = preview_first_tokens(entry.full_value.as_deref().unwrap_or(v), INLINE_TOKEN_PREVIEW);
+                                            ui.horizontal_wrapped(|ui| {
+                                                for token in &tokens {
+                                                    let color = if is_special_token(token) { SUCCESS_GREEN } else { egui::Color32::LIGHT_GRAY };
+                                                    ui.label(
+                                                        egui::RichText::new(escape_whitespace(token))
+                                                            .monospace()
+                                                            .color(color)
+                                                            .size(get_adaptive_font_size(12.0, ctx)),
+                                                    );
+                                                }
+                                            });
+                                            if ui
+                                                .button(format!(
+                                                    "{} {}",
+                                                    egui_phosphor::regular::EYE,
+                                                    view_text
+                                                ))
+                                                .clicked()
+                                            {
+                                                self.selected_chat_template = None;
+                                                self.selected_ggml_merges = None;
+                                                self.selected_ggml_tokens = entry.full_value.clone();
+                                            }
+                                        } else if k == "tokenizer.ggml.merges" {
+                                            if ui
+                                                .button(format!(
+                                                    "{} {}",
+                                                    egui_phosphor::regular::EYE,
+                                                    view_text
+                                                ))
+                                                .clicked()
+                                            {
+                                                self.selected_chat_template = None;
+                                                self.selected_ggml_tokens = None;
+                                                self.selected_ggml_merges = entry.full_value.clone();
+                                            }
+                                        } else if let Some(uri) = entry.full_value.as_ref().filter(|full| full.starts_with("data:image/")) {
+                                            ui.horizontal(|ui| {
+                                                ui.label(
+                                                    egui::RichText::new(v)
+                                                        .color(egui::Color32::LIGHT_GRAY)
+                                                        .size(get_adaptive_font_size(12.0, ctx)),
+                                                );
+                                                if ui
+                                                    .button(format!("{} {}", egui_phosphor::regular::IMAGE, view_text))
+                                                    .clicked()
+                                                {
+                                                    self.selected_image = Some(uri.clone());
+                                                }
+                                            });
+                                        } else if v.len() > 1024 || v.contains("\0") {
+                                            ui.horizontal(|ui| {
+                                                ui.label(
+                                                    egui::RichText::new(&binary_long_text)
+                                                        .color(egui::Color32::LIGHT_GRAY)
+                                                        .size(get_adaptive_font_size(12.0, ctx)),
+                                                );
+                                                if ui
+                                                    .button(format!(
+                                                        "{} {} {}",
+                                                        egui_phosphor::regular::EYE,
+                                                        view_text,
+                                                        base64_text
+                                                    ))
+                                                    .clicked()
+                                                    && let Err(e) = crate::gui::export::show_base64_dialog(v)
+                                                {
+                                                    eprintln!("Export failed: {}", e);
+                                                }
+                                            });
+                                        } else if self.show_escapes {
+                                            render_plain_value(ui, ctx, k, &escape_whitespace(v));
+                                        } else {
+                                            render_plain_value(ui, ctx, k, v);
+                                        }
+                                    });
                                 });
-                            });
-                            first = false;
-                            ui.add_space(get_adaptive_font_size(8.0, ctx));
+                                first = false;
+                                ui.add_space(get_adaptive_font_size(8.0, ctx));
+                            }
                         }
                         if first {
                             ui.label(
                                 egui::RichText::new(&no_metadata_text).color(TECH_GRAY).size(get_adaptive_font_size(14.0, ctx)),
                             );
+                            if show_load_example {
+                                ui.add_space(get_adaptive_font_size(8.0, ctx));
+                                load_example = ui.button(&load_example_text).clicked();
+                            }
                         }
                     });
+
+                if load_example && let Some(path) = self.sample_model.clone() {
+                    self.start_loading(path);
+                }
+
+                // Pins are saved right away so they survive reloading the file or restarting
+                if let Some(key) = toggled_pin {
+                    toggle_pinned(&mut self.pinned_keys, &key);
+                    self.persist_settings("pinned keys", |settings| settings.pinned_keys = self.pinned_keys.clone());
+                }
+
+                if let Some(key) = hex_view_key
+                    && let Some(bytes) = self.metadata.iter().find(|entry| entry.key == key).and_then(hex_view_bytes)
+                {
+                    self.selected_hex = Some((key, bytes.to_vec()));
+                }
+
+                // Byte arrays are written exactly as candle parsed them
+                if let Some(key) = raw_export_key
+                    && let Some(bytes) = self.metadata.iter().find(|entry| entry.key == key).and_then(|entry| entry.raw_bytes.as_ref())
+                    && let Some(path) = rfd::FileDialog::new().set_file_name(format!("{}.bin", sanitize_filename(&key))).save_file()
+                    && let Err(e) = std::fs::write(&path, bytes)
+                {
+                    eprintln!("{}", self.t_with_args("messages.export_failed", &[&e.to_string()]));
+                }
             });
 
         // Render dialog windows - these create their own windows so no ui parameter needed
@@ -639,6 +1261,143 @@ impl eframe::App for GgufApp {
                             .color(TECH_GRAY));
                         
                         ui.add_space(get_adaptive_font_size(16.0, ctx));
+
+                        // Tokenizer preview length section
+                        let preview_limit_label = format!("{}:", self.t("settings.preview_limit"));
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(preview_limit_label)
+                                .size(get_adaptive_font_size(14.0, ctx))
+                                .color(GADGET_YELLOW));
+                            let response = ui.add(egui::DragValue::new(&mut self.preview_limit).range(0..=1000));
+                            // Saved once editing ends, not on every frame of a drag
+                            if response.drag_stopped() || response.lost_focus() {
+                                self.persist_settings("preview limit", |settings| settings.preview_limit = self.preview_limit);
+                            }
+                        });
+
+                        ui.add_space(get_adaptive_font_size(4.0, ctx));
+                        ui.label(egui::RichText::new(self.t("settings.preview_limit_description"))
+                            .size(get_adaptive_font_size(12.0, ctx))
+                            .color(TECH_GRAY));
+
+                        ui.add_space(get_adaptive_font_size(16.0, ctx));
+
+                        // Content panel page size section
+                        let entries_per_page_label = format!("{}:", self.t("settings.entries_per_page"));
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(entries_per_page_label)
+                                .size(get_adaptive_font_size(14.0, ctx))
+                                .color(GADGET_YELLOW));
+                            let response = ui.add(egui::DragValue::new(&mut self.entries_per_page).range(10..=1000));
+                            if response.changed() {
+                                self.current_page = 0;
+                            }
+                            if response.drag_stopped() || response.lost_focus() {
+                                self.persist_gui_settings("entries per page", |gui| gui.entries_per_page = self.entries_per_page);
+                            }
+                        });
+
+                        ui.add_space(get_adaptive_font_size(4.0, ctx));
+                        ui.label(egui::RichText::new(self.t("settings.entries_per_page_description"))
+                            .size(get_adaptive_font_size(12.0, ctx))
+                            .color(TECH_GRAY));
+
+                        ui.add_space(get_adaptive_font_size(16.0, ctx));
+
+                        // Auto-reload section
+                        let auto_reload_label = egui::RichText::new(self.t("settings.auto_reload"))
+                            .size(get_adaptive_font_size(14.0, ctx))
+                            .color(GADGET_YELLOW);
+                        if ui.checkbox(&mut self.auto_reload, auto_reload_label).changed() {
+                            self.persist_settings("auto-reload setting", |settings| settings.auto_reload = self.auto_reload);
+                            self.refresh_file_watcher(ctx);
+                        }
+
+                        ui.add_space(get_adaptive_font_size(4.0, ctx));
+                        ui.label(egui::RichText::new(self.t("settings.auto_reload_description"))
+                            .size(get_adaptive_font_size(12.0, ctx))
+                            .color(TECH_GRAY));
+
+                        ui.add_space(get_adaptive_font_size(16.0, ctx));
+
+                        // Remembered filters section
+                        let remember_filters_label = egui::RichText::new(self.t("settings.remember_filters"))
+                            .size(get_adaptive_font_size(14.0, ctx))
+                            .color(GADGET_YELLOW);
+                        if ui.checkbox(&mut self.remember_filters, remember_filters_label).changed() {
+                            self.persist_settings("remember-filters setting", |settings| settings.remember_filters = self.remember_filters);
+                            // The current filter is stored on the next frame
+                            self.remembered_filter.clear();
+                        }
+
+                        ui.add_space(get_adaptive_font_size(4.0, ctx));
+                        ui.label(egui::RichText::new(self.t("settings.remember_filters_description"))
+                            .size(get_adaptive_font_size(12.0, ctx))
+                            .color(TECH_GRAY));
+
+                        ui.add_space(get_adaptive_font_size(16.0, ctx));
+
+                        // Display font section
+                        let display_font_label = egui::RichText::new(self.t("settings.use_display_font"))
+                            .size(get_adaptive_font_size(14.0, ctx))
+                            .color(GADGET_YELLOW);
+                        if ui.checkbox(&mut self.use_display_font, display_font_label).changed() {
+                            self.persist_settings("display font setting", |settings| settings.use_display_font = self.use_display_font);
+                        }
+
+                        ui.add_space(get_adaptive_font_size(4.0, ctx));
+                        ui.label(egui::RichText::new(self.t("settings.use_display_font_description"))
+                            .size(get_adaptive_font_size(12.0, ctx))
+                            .color(TECH_GRAY));
+
+                        ui.add_space(get_adaptive_font_size(16.0, ctx));
+
+                        // High contrast section
+                        let high_contrast_label = egui::RichText::new(self.t("settings.high_contrast"))
+                            .size(get_adaptive_font_size(14.0, ctx))
+                            .color(GADGET_YELLOW);
+                        if ui.checkbox(&mut self.high_contrast, high_contrast_label).changed() {
+                            self.persist_settings("high contrast setting", |settings| settings.high_contrast = self.high_contrast);
+                        }
+
+                        ui.add_space(get_adaptive_font_size(4.0, ctx));
+                        ui.label(egui::RichText::new(self.t("settings.high_contrast_description"))
+                            .size(get_adaptive_font_size(12.0, ctx))
+                            .color(TECH_GRAY));
+
+                        ui.add_space(get_adaptive_font_size(16.0, ctx));
+
+                        // Metadata cache section
+                        let metadata_cache_label = egui::RichText::new(self.t("settings.metadata_cache"))
+                            .size(get_adaptive_font_size(14.0, ctx))
+                            .color(GADGET_YELLOW);
+                        let capacity_label = format!("{}:", self.t("settings.metadata_cache_capacity"));
+                        ui.horizontal(|ui| {
+                            let mut enabled = self.metadata_cache.is_some();
+                            let toggled = ui.checkbox(&mut enabled, metadata_cache_label).changed();
+                            ui.label(egui::RichText::new(capacity_label).size(get_adaptive_font_size(14.0, ctx)));
+                            let capacity = ui.add(egui::DragValue::new(&mut self.metadata_cache_capacity).range(1..=32));
+                            let resized = capacity.changed();
+
+                            if toggled {
+                                self.metadata_cache = enabled.then(|| MetadataCache::shared(self.metadata_cache_capacity));
+                            } else if resized && let Some(cache) = &self.metadata_cache {
+                                cache.lock().unwrap().set_capacity(self.metadata_cache_capacity);
+                            }
+                            if toggled || capacity.drag_stopped() || capacity.lost_focus() {
+                                self.persist_settings("metadata cache setting", |settings| {
+                                    settings.metadata_cache = enabled;
+                                    GuiSettings::update(settings, |gui| gui.metadata_cache_capacity = self.metadata_cache_capacity);
+                                });
+                            }
+                        });
+
+                        ui.add_space(get_adaptive_font_size(4.0, ctx));
+                        ui.label(egui::RichText::new(self.t("settings.metadata_cache_description"))
+                            .size(get_adaptive_font_size(12.0, ctx))
+                            .color(TECH_GRAY));
+
+                        ui.add_space(get_adaptive_font_size(16.0, ctx));
                         
                         // Close button
                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
@@ -650,6 +1409,10 @@ impl eframe::App for GgufApp {
                 });
         }
 
+        if self.show_export_options {
+            self.render_export_options_dialog(ctx);
+        }
+
         // About dialog
         if self.show_about {
             let base_width: f32 = if ctx.screen_rect().width() >= 1440.0 { 550.0 } else { 450.0 };
@@ -663,7 +1426,7 @@ impl eframe::App for GgufApp {
                 .show(ctx, |ui| {
                     ui.vertical(|ui| {
                         ui.heading(egui::RichText::new(self.t("app.title")).size(get_adaptive_font_size(18.0, ctx)));
-                        ui.label(egui::RichText::new(format!("{}: 0.1.0", self.t("app.version"))).size(get_adaptive_font_size(14.0, ctx)));
+                        ui.label(egui::RichText::new(format!("{}: {}", self.t("app.version"), crate::gui::updater::version_info())).size(get_adaptive_font_size(14.0, ctx)));
                         ui.label(egui::RichText::new(self.t("about.description")).size(get_adaptive_font_size(14.0, ctx)));
                         ui.label(egui::RichText::new(self.t("about.built_with")).size(get_adaptive_font_size(14.0, ctx)));
                         ui.add_space(get_adaptive_font_size(8.0, ctx));
@@ -729,6 +1492,19 @@ impl eframe::App for GgufApp {
                                 let _ = opener::open("https://github.com/FerrisMind/inspector-gguf");
                             }
 
+                            // Diagnostics for bug reports
+                            if ui.button(egui::RichText::new(format!("{} {}", egui_phosphor::regular::CLIPBOARD_TEXT, self.t("about.copy_diagnostics"))).size(get_adaptive_font_size(14.0, ctx))).clicked() {
+                                ctx.copy_text(self.collect_diagnostics());
+                            }
+
+                            // Prefilled GitHub issue; only the model architecture is reported
+                            if ui.button(egui::RichText::new(format!("{} {}", egui_phosphor::regular::BUG, self.t("about.report_bug"))).size(get_adaptive_font_size(14.0, ctx))).clicked() {
+                                let url = crate::gui::updater::bug_report_url(crate::gui::updater::GITHUB_REPO, &self.bug_report_diagnostics());
+                                if let Err(e) = opener::open(&url) {
+                                    eprintln!("Failed to open bug report: {}", e);
+                                }
+                            }
+
                             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                                 if ui.button(egui::RichText::new(self.t("buttons.close")).size(get_adaptive_font_size(14.0, ctx))).clicked() {
                                     self.show_about = false;
@@ -741,6 +1517,360 @@ impl eframe::App for GgufApp {
     }
 }
 
+impl GgufApp {
+    /// Loads the saved settings, applies `update` and saves them again.
+    ///
+    /// `what` names the setting in the message logged if saving fails.
+    fn persist_settings<R>(&self, what: &str, update: impl FnOnce(&mut AppSettings) -> R) -> R {
+        let mut settings = self.settings_manager.load_settings().unwrap_or_default();
+        let result = update(&mut settings);
+        if let Err(e) = self.settings_manager.save_settings(&settings) {
+            eprintln!("Failed to save {}: {}", what, e);
+        }
+        result
+    }
+
+    /// Like [`GgufApp::persist_settings`], for the GUI-only settings.
+    fn persist_gui_settings<R>(&self, what: &str, update: impl FnOnce(&mut GuiSettings) -> R) -> R {
+        self.persist_settings(what, |settings| GuiSettings::update(settings, update))
+    }
+
+    /// Compares the loaded metadata with the snapshot from the last time this file was opened.
+    ///
+    /// Badges the changed and added keys and saves the new snapshot in the settings.
+    fn track_changed_keys(&mut self) {
+        let Some(path) = &self.loaded_path else {
+            return;
+        };
+        let current = snapshot(path, &self.metadata);
+        // A file opened for the first time has nothing to compare against
+        self.changed_keys = self.persist_gui_settings("metadata snapshot", |gui| {
+            record_snapshot(&mut gui.metadata_snapshots, current.clone())
+                .map(|previous| changed_keys(&previous, &current))
+                .unwrap_or_default()
+        });
+        self.changed_keys_at = Some(Instant::now());
+    }
+
+    /// Stores the filter of the loaded file in the settings if it changed since it was last stored.
+    ///
+    /// A cleared filter removes the stored one (see [`remember_filter`]).
+    fn save_file_filter(&mut self) {
+        if !self.remember_filters || self.filter == self.remembered_filter {
+            return;
+        }
+        let Some(path) = &self.loaded_path else {
+            return;
+        };
+        self.persist_settings("filter", |settings| remember_filter(settings, path, &self.filter));
+        self.remembered_filter = self.filter.clone();
+    }
+
+    /// Replaces the filter with the one stored for the newly loaded file, if filters are remembered.
+    fn restore_file_filter(&mut self) {
+        if !self.remember_filters {
+            return;
+        }
+        let Some(path) = &self.loaded_path else {
+            return;
+        };
+        let settings = self.settings_manager.load_settings().unwrap_or_default();
+        self.filter = restore_filter(&settings, path);
+        self.remembered_filter = self.filter.clone();
+        self.filter_suggestion = None;
+    }
+
+    /// Returns the loaded keys and key prefixes completing the current filter.
+    fn filter_suggestions(&self) -> Vec<String> {
+        let keys: Vec<String> = self.metadata.iter().map(|entry| entry.key.clone()).collect();
+        suggest_keys(&keys, &self.filter, FILTER_SUGGESTION_LIMIT)
+    }
+
+    /// Moves the highlighted filter suggestion with the arrow keys and applies it on Enter.
+    fn handle_suggestion_keys(&mut self, ctx: &egui::Context, filter_id: egui::Id, suggestions: &[String]) {
+        let (down, up) = ctx.input_mut(|i| {
+            (
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+            )
+        });
+        let last = suggestions.len() - 1;
+        if down {
+            self.filter_suggestion = Some(self.filter_suggestion.map_or(0, |i| (i + 1).min(last)));
+        }
+        if up {
+            self.filter_suggestion = self.filter_suggestion.and_then(|i| i.checked_sub(1));
+        }
+        // Enter without a highlighted suggestion keeps its usual meaning
+        if let Some(index) = self.filter_suggestion.filter(|&i| i <= last)
+            && ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::Enter))
+        {
+            self.apply_filter_suggestion(ctx, filter_id, suggestions[index].clone());
+        }
+    }
+
+    /// Shows the keys completing the filter in a popup under the filter box.
+    fn show_filter_suggestions(&mut self, ctx: &egui::Context, filter: &egui::Response) {
+        let suggestions = self.filter_suggestions();
+        let popup_id = filter.id.with("suggestions");
+        // The popup stays open while it is hovered, so clicking a suggestion doesn't close it first
+        let popup_hovered = ctx
+            .memory(|m| m.area_rect(popup_id))
+            .zip(ctx.pointer_hover_pos())
+            .is_some_and(|(rect, pos)| rect.contains(pos));
+        if suggestions.is_empty() || !(filter.has_focus() || popup_hovered) {
+            self.filter_suggestion = None;
+            return;
+        }
+
+        let mut clicked = None;
+        egui::Area::new(popup_id)
+            .order(egui::Order::Foreground)
+            .fixed_pos(filter.rect.left_bottom())
+            .show(ctx, |ui| {
+                egui::Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_min_width(filter.rect.width());
+                    for (i, suggestion) in suggestions.iter().enumerate() {
+                        let text = egui::RichText::new(suggestion).size(get_adaptive_font_size(13.0, ctx));
+                        if ui.selectable_label(self.filter_suggestion == Some(i), text).clicked() {
+                            clicked = Some(suggestion.clone());
+                        }
+                    }
+                });
+            });
+        if let Some(suggestion) = clicked {
+            self.apply_filter_suggestion(ctx, filter.id, suggestion);
+        }
+    }
+
+    /// Replaces the filter with `suggestion` and keeps typing after it.
+    fn apply_filter_suggestion(&mut self, ctx: &egui::Context, filter_id: egui::Id, suggestion: String) {
+        let end = suggestion.chars().count();
+        self.filter = suggestion;
+        self.filter_suggestion = None;
+        self.current_page = 0;
+        if let Some(mut state) = egui::TextEdit::load_state(ctx, filter_id) {
+            state.cursor.set_char_range(Some(egui::text::CCursorRange::one(egui::text::CCursor::new(end))));
+            state.store(ctx, filter_id);
+        }
+        ctx.memory_mut(|m| m.request_focus(filter_id));
+    }
+
+    /// Shows the loaded model in the OS window title, see [`window_title`].
+    fn update_window_title(&self, ctx: &egui::Context) {
+        let title = window_title(&self.t("app.title"), self.model_summary.as_ref());
+        ctx.send_viewport_cmd(egui::ViewportCommand::Title(title));
+    }
+
+    /// Starts loading `path` in the background, cancelling any load still in flight.
+    ///
+    /// Results of the cancelled load are discarded by the [`LoadTracker`] generation
+    /// check, so they can never overwrite the metadata of the new file.
+    pub fn start_loading(&mut self, path: PathBuf) {
+        self.loading = true;
+        self.loading_path = Some(path.clone());
+        *self.loading_progress.lock().unwrap() = 0.0;
+        let ticket = self.load_tracker.begin(&self.loading_result);
+
+        let progress_clone = Arc::clone(&self.loading_progress);
+        let result_clone = Arc::clone(&self.loading_result);
+        let cache = self.metadata_cache.as_ref().map(Arc::clone);
+        crate::gui::loader::load_gguf_metadata_async_cached(path, progress_clone, result_clone, self.preview_limit, ticket, cache);
+    }
+
+    /// Exports the loaded metadata to `path` on a background thread.
+    ///
+    /// The UI stays responsive while large HTML or PDF files are written; the
+    /// outcome is picked up by [`BackgroundExport::poll`] on a later frame.
+    pub fn start_export(&mut self, format: ExportFormat, path: PathBuf) {
+        let metadata = entries_for_export(&self.metadata, &self.filter, self.filter_mode, &self.export_options)
+            .into_iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        let options = ExportOptions { source: self.loaded_path.clone(), ..self.export_options.clone() };
+        self.export_error = None;
+        self.export.start_with_options(format, metadata, path, options);
+    }
+
+    /// Starts exporting the model card ([`crate::gui::export::export_model_card`]) of every loaded entry as Markdown.
+    ///
+    /// The content panel filter and the key patterns of the export options do not apply.
+    pub fn start_model_card_export(&mut self, path: PathBuf) {
+        let metadata = self
+            .metadata
+            .iter()
+            .map(|entry| (entry.key.clone(), entry.display_value.clone()))
+            .collect();
+        let options = ExportOptions { source: self.loaded_path.clone(), ..model_card_options(&self.export_options) };
+        self.export_error = None;
+        self.export.start_with_options(ExportFormat::Markdown, metadata, path, options);
+    }
+
+    /// Records a Load or export click, returning `false` for a repeat within [`ACTION_DEBOUNCE`].
+    fn accept_action(&mut self) -> bool {
+        let now = Instant::now();
+        if !should_accept_action(self.last_action_at, now, ACTION_DEBOUNCE) {
+            return false;
+        }
+        self.last_action_at = Some(now);
+        true
+    }
+
+    /// Asks where to save an export, suggesting `file_name`.
+    ///
+    /// Returns `None` without opening a dialog while another export is pending or
+    /// the click repeats a recent one (see [`GgufApp::accept_action`]); the export
+    /// stays pending from the dialog until [`BackgroundExport::poll`] reports it.
+    fn pick_export_path(&mut self, file_name: String) -> Option<PathBuf> {
+        if self.export_pending || self.export.is_running() || !self.accept_action() {
+            return None;
+        }
+        let path = rfd::FileDialog::new().set_file_name(file_name).save_file();
+        // Clicks queued while the modal dialog was open arrive now; debounce them too
+        self.last_action_at = Some(Instant::now());
+        self.export_pending = path.is_some();
+        path
+    }
+
+    /// Asks for a GGUF file to load, ignoring repeated clicks like [`GgufApp::pick_export_path`].
+    fn pick_gguf_file(&mut self) -> Option<PathBuf> {
+        if !self.accept_action() {
+            return None;
+        }
+        let path = rfd::FileDialog::new().pick_file();
+        self.last_action_at = Some(Instant::now());
+        path
+    }
+
+    /// Renders the export options dialog and saves the options whenever they change.
+    fn render_export_options_dialog(&mut self, ctx: &egui::Context) {
+        let keys_note = self.t_with_args(
+            "export_options.keys_to_export",
+            &[
+                &self.format_number(exported_key_count(&self.metadata, &self.filter, self.filter_mode, &self.export_options) as u64),
+                &self.format_number(self.metadata.len() as u64),
+            ],
+        );
+        let title = self.t("export_options.title");
+        let keys_heading = self.t("export_options.keys");
+        let filtered_only_text = self.t("export_options.filtered_only");
+        let include_text = format!("{}:", self.t("export_options.include_keys"));
+        let exclude_text = format!("{}:", self.t("export_options.exclude_keys"));
+        let patterns_description = self.t("export_options.patterns_description");
+        let csv_heading = self.t("export_options.csv");
+        let sanitize_text = self.t("export_options.sanitize_formulas");
+        let markdown_heading = self.t("export_options.markdown");
+        let provenance_text = self.t("export_options.include_provenance");
+        let verify_text = self.t("export_options.verify");
+        let line_ending_text = format!("{}:", self.t("export_options.line_ending"));
+        let lf_text = self.t("export_options.line_ending_lf");
+        let crlf_text = self.t("export_options.line_ending_crlf");
+        let close_text = self.t("buttons.close");
+
+        let mut changed = false;
+        egui::Window::new(title)
+            .resizable(true)
+            .collapsible(false)
+            .default_width(if ctx.screen_rect().width() >= 1440.0 { 500.0 } else { 400.0 })
+            .show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    ui.add_space(get_adaptive_font_size(8.0, ctx));
+
+                    // Which keys are exported
+                    ui.label(egui::RichText::new(&keys_heading).size(get_adaptive_font_size(14.0, ctx)).color(GADGET_YELLOW));
+                    ui.add_space(get_adaptive_font_size(4.0, ctx));
+                    changed |= ui.checkbox(&mut self.export_options.filtered_only, &filtered_only_text).changed();
+                    egui::Grid::new("export_key_patterns").num_columns(2).show(ui, |ui| {
+                        ui.label(&include_text);
+                        if ui.text_edit_singleline(&mut self.export_include_text).changed() {
+                            let patterns = parse_key_patterns(&self.export_include_text);
+                            self.export_options.include_keys = (!patterns.is_empty()).then_some(patterns);
+                            changed = true;
+                        }
+                        ui.end_row();
+                        ui.label(&exclude_text);
+                        if ui.text_edit_singleline(&mut self.export_exclude_text).changed() {
+                            self.export_options.exclude_keys = parse_key_patterns(&self.export_exclude_text);
+                            changed = true;
+                        }
+                        ui.end_row();
+                    });
+                    ui.label(egui::RichText::new(&patterns_description).size(get_adaptive_font_size(12.0, ctx)).color(TECH_GRAY));
+                    ui.add_space(get_adaptive_font_size(8.0, ctx));
+                    // Not specific to one format: CSV, YAML and JSON are all read back
+                    changed |= ui.checkbox(&mut self.export_options.verify, &verify_text).changed();
+                    ui.horizontal(|ui| {
+                        ui.label(&line_ending_text);
+                        changed |= ui.radio_value(&mut self.export_options.line_ending, LineEnding::Lf, &lf_text).changed();
+                        changed |= ui.radio_value(&mut self.export_options.line_ending, LineEnding::CrLf, &crlf_text).changed();
+                    });
+
+                    ui.add_space(get_adaptive_font_size(16.0, ctx));
+
+                    // Format-specific options
+                    ui.label(egui::RichText::new(&csv_heading).size(get_adaptive_font_size(14.0, ctx)).color(GADGET_YELLOW));
+                    ui.add_space(get_adaptive_font_size(4.0, ctx));
+                    changed |= ui.checkbox(&mut self.export_options.sanitize_formulas, &sanitize_text).changed();
+                    ui.add_space(get_adaptive_font_size(8.0, ctx));
+                    ui.label(egui::RichText::new(&markdown_heading).size(get_adaptive_font_size(14.0, ctx)).color(GADGET_YELLOW));
+                    ui.add_space(get_adaptive_font_size(4.0, ctx));
+                    changed |= ui.checkbox(&mut self.export_options.include_provenance, &provenance_text).changed();
+
+                    ui.add_space(get_adaptive_font_size(16.0, ctx));
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new(&keys_note).size(get_adaptive_font_size(14.0, ctx)));
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if ui.button(egui::RichText::new(&close_text).size(get_adaptive_font_size(14.0, ctx))).clicked() {
+                                self.show_export_options = false;
+                            }
+                        });
+                    });
+                });
+            });
+
+        if changed {
+            self.persist_gui_settings("export options", |gui| gui.export_options = self.export_options.clone());
+        }
+    }
+
+    /// Builds the plain-text diagnostics blob copied from the about dialog.
+    ///
+    /// See [`format_diagnostics`] for the contents.
+    pub fn collect_diagnostics(&self) -> String {
+        format_diagnostics(
+            self.localization_manager.get_current_language().to_code(),
+            self.loaded_path.as_deref(),
+            self.loaded_file_metadata.as_ref().map(|metadata| metadata.len()),
+            self.update_status.as_deref(),
+        )
+    }
+
+    /// Returns the facts prefilled into a bug report: version, OS, and the loaded model's architecture.
+    pub fn bug_report_diagnostics(&self) -> crate::gui::updater::BugReportDiagnostics {
+        crate::gui::updater::BugReportDiagnostics {
+            version: crate::gui::updater::version_info(),
+            os: format!("{} ({})", std::env::consts::OS, std::env::consts::ARCH),
+            architecture: self.model_summary.as_ref().and_then(|summary| summary.architecture.clone()),
+        }
+    }
+
+    /// Starts, moves, or stops the file watcher to match `auto_reload` and the loaded path.
+    pub fn refresh_file_watcher(&mut self, ctx: &egui::Context) {
+        let target = if self.auto_reload { self.loaded_path.as_deref() } else { None };
+        if self.file_watcher.as_ref().map(FileWatcher::path) == target {
+            return;
+        }
+        self.file_watcher = target.and_then(|path| match FileWatcher::new(path, ctx.clone()) {
+            Ok(watcher) => Some(watcher),
+            Err(e) => {
+                eprintln!("Failed to watch {}: {}", path.display(), e);
+                None
+            }
+        });
+    }
+}
+
 impl LanguageProvider for GgufApp {
     fn t(&self, key: &str) -> String {
         self.localization_manager.get_text(key)
@@ -757,4 +1887,154 @@ impl LanguageProvider for GgufApp {
         
         text
     }
-}
\ No newline at end of file
+
+    fn language(&self) -> crate::localization::Language {
+        self.localization_manager.get_current_language()
+    }
+}
+
+/// Minimum time between two accepted Load or export clicks.
+pub const ACTION_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Returns `true` if a button action at `now` is not a repeat of the one at `last_action`.
+///
+/// Actions less than `debounce` after the previous accepted one are rejected, so a
+/// double click does not open two file dialogs.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::app::should_accept_action;
+/// use std::time::{Duration, Instant};
+///
+/// let now = Instant::now();
+/// let debounce = Duration::from_millis(500);
+/// assert!(should_accept_action(None, now, debounce));
+/// assert!(!should_accept_action(Some(now), now + Duration::from_millis(100), debounce));
+/// assert!(should_accept_action(Some(now), now + debounce, debounce));
+/// ```
+pub fn should_accept_action(last_action: Option<Instant>, now: Instant, debounce: Duration) -> bool {
+    last_action.is_none_or(|last| now.saturating_duration_since(last) >= debounce)
+}
+
+/// Formats the diagnostics blob copied from the about dialog.
+///
+/// Contains the app version, OS, UI `language` code, the loaded file's name and
+/// size, and the last update-check status. Only the file name is included, never
+/// the full path. Field names stay in English so reports are readable by maintainers.
+pub fn format_diagnostics(
+    language: &str,
+    loaded_path: Option<&std::path::Path>,
+    file_size: Option<u64>,
+    update_status: Option<&str>,
+) -> String {
+    let file = match loaded_path {
+        Some(path) => {
+            let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+            match file_size {
+                Some(size) => format!("{} ({} bytes)", name, size),
+                None => name,
+            }
+        }
+        None => "none".to_string(),
+    };
+
+    [
+        "Inspector GGUF diagnostics".to_string(),
+        format!("version: {}", crate::gui::updater::version_info()),
+        format!("os: {} ({})", std::env::consts::OS, std::env::consts::ARCH),
+        format!("language: {}", language),
+        format!("file: {}", file),
+        format!("update_status: {}", update_status.unwrap_or("not checked")),
+    ]
+    .join("\n")
+}
+
+/// Builds the OS window title, e.g. `Inspector GGUF — Qwen3-0.6B (596.05M params)`.
+///
+/// The model is named by `general.name`, falling back to the architecture.
+/// Without a summary the plain `base` title is returned.
+pub fn window_title(base: &str, summary: Option<&ModelSummary>) -> String {
+    let Some(summary) = summary else {
+        return base.to_string();
+    };
+    let params = format_count(summary.parameter_count);
+    match summary.name.as_ref().or(summary.architecture.as_ref()) {
+        Some(name) => format!("{} — {} ({} params)", base, name, params),
+        None => format!("{} — {} params", base, params),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(name: Option<&str>, architecture: Option<&str>) -> ModelSummary {
+        ModelSummary {
+            name: name.map(str::to_string),
+            architecture: architecture.map(str::to_string),
+            parameter_count: 596_049_920,
+            context_length: None,
+            vocab_size: None,
+            tensor_count: 310,
+        }
+    }
+
+    #[test]
+    fn test_should_accept_action_debounces_repeats() {
+        let start = Instant::now();
+        let debounce = Duration::from_millis(500);
+
+        assert!(should_accept_action(None, start, debounce), "The first action is always accepted");
+        assert!(!should_accept_action(Some(start), start, debounce));
+        assert!(!should_accept_action(Some(start), start + Duration::from_millis(499), debounce));
+        assert!(should_accept_action(Some(start), start + debounce, debounce));
+        assert!(should_accept_action(Some(start), start + Duration::from_secs(5), debounce));
+        // A timestamp from the future never lets a click through early
+        assert!(!should_accept_action(Some(start + Duration::from_secs(1)), start, debounce));
+        assert!(should_accept_action(Some(start), start, Duration::ZERO));
+    }
+
+    #[test]
+    fn test_window_title_without_model_is_base_title() {
+        assert_eq!(window_title("Inspector GGUF", None), "Inspector GGUF");
+    }
+
+    #[test]
+    fn test_window_title_with_loaded_model() {
+        let loaded = summary(Some("Qwen3-0.6B"), Some("qwen3"));
+        assert_eq!(window_title("Inspector GGUF", Some(&loaded)), "Inspector GGUF — Qwen3-0.6B (596.05M params)");
+
+        let unnamed = summary(None, Some("qwen3"));
+        assert_eq!(window_title("Inspector GGUF", Some(&unnamed)), "Inspector GGUF — qwen3 (596.05M params)");
+
+        let anonymous = summary(None, None);
+        assert_eq!(window_title("Inspector GGUF", Some(&anonymous)), "Inspector GGUF — 596.05M params");
+    }
+
+    /// Guards against the removed monolithic `src/gui.rs` coming back: the
+    /// re-exported `gui::GgufApp` must be this struct, with the tokenizer panels.
+    #[test]
+    fn test_canonical_app_exposes_tokenizer_panels() {
+        fn tokenizer_panels(app: &crate::gui::GgufApp) -> (&Option<String>, &Option<String>) {
+            (&app.selected_ggml_tokens, &app.selected_ggml_merges)
+        }
+        let _: fn(&GgufApp) -> (&Option<String>, &Option<String>) = tokenizer_panels;
+    }
+
+    #[test]
+    fn test_format_diagnostics_reports_version_and_language() {
+        let path = PathBuf::from("/home/someone/models/model.gguf");
+
+        let diagnostics = format_diagnostics("ru", Some(&path), Some(1024), None);
+        assert!(diagnostics.contains(&format!("version: {}", crate::gui::updater::version_info())));
+        assert!(diagnostics.contains("language: ru"));
+        assert!(diagnostics.contains("file: model.gguf (1024 bytes)"));
+        assert!(diagnostics.contains("update_status: not checked"));
+        assert!(!diagnostics.contains("/home/someone"), "Only the file name is reported");
+
+        let empty = format_diagnostics("en", None, None, Some("up to date"));
+        assert!(empty.contains("file: none"));
+        assert!(empty.contains("update_status: up to date"));
+    }
+}
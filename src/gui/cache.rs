@@ -0,0 +1,199 @@
+//! In-memory cache of parsed metadata for recently opened files.
+//!
+//! Reopening a file normally reparses it from scratch. When the cache is enabled
+//! in the settings, the loader hashes the file contents with SHA-256 and reuses the
+//! metadata of a previous load with the same hash, skipping GGUF parsing entirely.
+//! Entries are identified by content hash only, so no file names or paths are kept,
+//! and a file that changed on disk simply misses the cache.
+//!
+//! The cache is a small LRU: once [`MetadataCache::capacity`] files are stored,
+//! the least recently used one is evicted.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use inspector_gguf::gui::cache::{hash_bytes, MetadataCache};
+//! use inspector_gguf::gui::loader::{LoadedFile, MetadataEntry};
+//!
+//! let mut cache = MetadataCache::new(3);
+//! let hash = hash_bytes(b"GGUF...");
+//! let file = LoadedFile {
+//!     entries: vec![MetadataEntry {
+//!         key: "general.name".to_string(),
+//!         display_value: "Qwen3".to_string(),
+//!         full_value: None,
+//!         raw_bytes: None,
+//!         string_bytes: None,
+//!     }],
+//!     ..LoadedFile::default()
+//! };
+//!
+//! cache.insert(hash, 3, file);
+//! assert_eq!(cache.get(&hash, 3).map(|f| f.entries.len()), Some(1));
+//! ```
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use sha2::{Digest, Sha256};
+use crate::gui::loader::LoadedFile;
+
+/// Number of files kept by the cache unless configured otherwise.
+pub const DEFAULT_CACHE_CAPACITY: usize = 3;
+
+/// SHA-256 digest of a file's contents.
+pub type FileHash = [u8; 32];
+
+/// Cache shared between the UI thread and loader threads.
+pub type SharedMetadataCache = Arc<Mutex<MetadataCache>>;
+
+/// Computes the SHA-256 digest used as the cache key.
+pub fn hash_bytes(bytes: &[u8]) -> FileHash {
+    Sha256::digest(bytes).into()
+}
+
+/// Metadata of one cached file.
+struct CachedMetadata {
+    hash: FileHash,
+    // Display values depend on the preview length, so it is part of the key
+    preview_limit: usize,
+    file: LoadedFile,
+}
+
+/// Least-recently-used cache of parsed metadata, keyed by file hash.
+pub struct MetadataCache {
+    capacity: usize,
+    // Most recently used first
+    entries: VecDeque<CachedMetadata>,
+}
+
+impl Default for MetadataCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_CAPACITY)
+    }
+}
+
+impl MetadataCache {
+    /// Creates an empty cache holding at most `capacity` files.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Wraps a new cache for sharing with loader threads.
+    pub fn shared(capacity: usize) -> SharedMetadataCache {
+        Arc::new(Mutex::new(Self::new(capacity)))
+    }
+
+    /// Returns the maximum number of cached files.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Changes the capacity, evicting the least recently used files if needed.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        self.entries.truncate(capacity);
+    }
+
+    /// Returns the number of cached files.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no file is cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Removes all cached files.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the metadata cached for `hash` and marks it as most recently used.
+    ///
+    /// Entries formatted with a different `preview_limit` do not match.
+    pub fn get(&mut self, hash: &FileHash, preview_limit: usize) -> Option<LoadedFile> {
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.hash == *hash && entry.preview_limit == preview_limit)?;
+        let entry = self.entries.remove(index)?;
+        let file = entry.file.clone();
+        self.entries.push_front(entry);
+        Some(file)
+    }
+
+    /// Stores `file` for `hash`, evicting the least recently used file when full.
+    pub fn insert(&mut self, hash: FileHash, preview_limit: usize, file: LoadedFile) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.entries
+            .retain(|entry| !(entry.hash == hash && entry.preview_limit == preview_limit));
+        self.entries.push_front(CachedMetadata { hash, preview_limit, file });
+        self.entries.truncate(self.capacity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gui::loader::MetadataEntry;
+
+    fn entries(name: &str) -> LoadedFile {
+        LoadedFile {
+            entries: vec![MetadataEntry {
+                key: "general.name".to_string(),
+                display_value: name.to_string(),
+                full_value: None,
+                raw_bytes: None,
+                string_bytes: None,
+            }],
+            ..LoadedFile::default()
+        }
+    }
+
+    #[test]
+    fn test_cache_hit_returns_stored_metadata() {
+        let mut cache = MetadataCache::new(DEFAULT_CACHE_CAPACITY);
+        let hash = hash_bytes(b"model-a");
+        cache.insert(hash, 3, entries("a"));
+
+        let hit = cache.get(&hash, 3).expect("Stored metadata should be returned");
+        assert_eq!(hit.entries[0].display_value, "a");
+        assert!(cache.get(&hash_bytes(b"model-a changed"), 3).is_none(), "Changed contents miss");
+        assert!(cache.get(&hash, 10).is_none(), "Other preview lengths miss");
+    }
+
+    #[test]
+    fn test_lru_eviction_order() {
+        let mut cache = MetadataCache::new(2);
+        let (a, b, c) = (hash_bytes(b"a"), hash_bytes(b"b"), hash_bytes(b"c"));
+        cache.insert(a, 3, entries("a"));
+        cache.insert(b, 3, entries("b"));
+
+        // Touching `a` makes `b` the least recently used entry
+        assert!(cache.get(&a, 3).is_some());
+        cache.insert(c, 3, entries("c"));
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&b, 3).is_none());
+        assert!(cache.get(&a, 3).is_some());
+        assert!(cache.get(&c, 3).is_some());
+    }
+
+    #[test]
+    fn test_shrinking_capacity_evicts_oldest() {
+        let mut cache = MetadataCache::new(3);
+        for name in ["a", "b", "c"] {
+            cache.insert(hash_bytes(name.as_bytes()), 3, entries(name));
+        }
+        cache.set_capacity(1);
+
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(&hash_bytes(b"c"), 3).is_some());
+    }
+}
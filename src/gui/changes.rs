@@ -0,0 +1,184 @@
+//! Metadata keys that changed since a file was last opened.
+//!
+//! Every load records a small snapshot of the file's metadata in the settings:
+//! the file path and each value are stored as truncated SHA-256 hashes, so the
+//! settings file contains neither paths nor metadata values. When the same file
+//! is opened again, [`changed_keys`] compares the new snapshot with the previous
+//! one using [`crate::format::diff_metadata`], and the content panel badges the
+//! changed and added keys for [`CHANGED_BADGE_DURATION`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use inspector_gguf::gui::changes::{changed_keys, snapshot};
+//! use inspector_gguf::gui::loader::MetadataEntry;
+//! use std::path::Path;
+//!
+//! let entry = |key: &str, value: &str| MetadataEntry {
+//!     key: key.to_string(),
+//!     display_value: value.to_string(),
+//!     full_value: None,
+//!     raw_bytes: None,
+//!     string_bytes: None,
+//! };
+//! let path = Path::new("model.gguf");
+//! let before = snapshot(path, &[entry("general.name", "Qwen3")]);
+//! let after = snapshot(path, &[entry("general.name", "Qwen3-0.6B")]);
+//!
+//! assert!(changed_keys(&before, &after).contains("general.name"));
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+use crate::format::{diff_metadata, MetadataDiff};
+use crate::gui::cache::hash_bytes;
+use crate::gui::loader::MetadataEntry;
+
+/// Number of files whose snapshot is kept in the settings.
+pub const MAX_SNAPSHOTS: usize = 20;
+
+/// How long changed keys stay badged after the file is loaded.
+pub const CHANGED_BADGE_DURATION: Duration = Duration::from_secs(10);
+
+/// Hashed key/value snapshot of one file's metadata.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileSnapshot {
+    /// Hash of the file path (see [`path_hash`]).
+    pub file: String,
+    /// Hash of every metadata value, by key.
+    pub values: BTreeMap<String, String>,
+}
+
+/// Hex-encodes the first 8 bytes of the SHA-256 digest of `bytes`.
+fn short_hash(bytes: &[u8]) -> String {
+    hash_bytes(bytes)[..8].iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Returns the hash identifying `path` in the stored snapshots.
+///
+/// The path is canonicalized when possible, so relative and absolute paths to
+/// the same file match.
+pub fn path_hash(path: &Path) -> String {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    short_hash(path.to_string_lossy().as_bytes())
+}
+
+/// Builds the snapshot of `entries` loaded from `path`.
+///
+/// Complete values are hashed when available, so a change hidden by display
+/// truncation still counts.
+pub fn snapshot(path: &Path, entries: &[MetadataEntry]) -> FileSnapshot {
+    let values = entries
+        .iter()
+        .map(|entry| {
+            let value = entry.full_value.as_deref().unwrap_or(&entry.display_value);
+            (entry.key.clone(), short_hash(value.as_bytes()))
+        })
+        .collect();
+    FileSnapshot { file: path_hash(path), values }
+}
+
+/// Returns the keys whose value changed or that were added since `previous`.
+///
+/// Removed keys are not returned: there is no row left to badge.
+pub fn changed_keys(previous: &FileSnapshot, current: &FileSnapshot) -> BTreeSet<String> {
+    let pairs = |snapshot: &FileSnapshot| -> Vec<(String, String)> {
+        snapshot.values.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
+    };
+    diff_metadata(&pairs(previous), &pairs(current))
+        .into_iter()
+        .filter_map(|diff| match diff {
+            MetadataDiff::Changed { key, .. } | MetadataDiff::Extra { key, .. } => Some(key),
+            MetadataDiff::Missing { .. } => None,
+        })
+        .collect()
+}
+
+/// Stores `current` as the most recent snapshot and returns the one it replaces.
+///
+/// Snapshots are kept most recently opened first; beyond [`MAX_SNAPSHOTS`] files
+/// the oldest are dropped.
+pub fn record_snapshot(snapshots: &mut Vec<FileSnapshot>, current: FileSnapshot) -> Option<FileSnapshot> {
+    let previous = snapshots
+        .iter()
+        .position(|snapshot| snapshot.file == current.file)
+        .map(|index| snapshots.remove(index));
+    snapshots.insert(0, current);
+    snapshots.truncate(MAX_SNAPSHOTS);
+    previous
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(key: &str, value: &str) -> MetadataEntry {
+        MetadataEntry {
+            key: key.to_string(),
+            display_value: value.to_string(),
+            full_value: None,
+            raw_bytes: None,
+            string_bytes: None,
+        }
+    }
+
+    #[test]
+    fn test_changed_and_added_keys_are_badged() {
+        let path = Path::new("model.gguf");
+        let previous = snapshot(path, &[
+            entry("general.name", "Qwen3"),
+            entry("general.architecture", "qwen3"),
+            entry("general.license", "apache-2.0"),
+        ]);
+        let current = snapshot(path, &[
+            entry("general.name", "Qwen3-0.6B"),
+            entry("general.architecture", "qwen3"),
+            entry("qwen3.context_length", "40960"),
+        ]);
+
+        let changed = changed_keys(&previous, &current);
+        assert_eq!(changed.into_iter().collect::<Vec<_>>(), ["general.name", "qwen3.context_length"]);
+        assert!(changed_keys(&current, &current).is_empty());
+    }
+
+    #[test]
+    fn test_full_value_changes_are_detected() {
+        let path = Path::new("model.gguf");
+        let mut tokens = entry("tokenizer.ggml.tokens", "a, b, …");
+        tokens.full_value = Some("a, b, c".to_string());
+        let previous = snapshot(path, std::slice::from_ref(&tokens));
+        tokens.full_value = Some("a, b, d".to_string());
+        let current = snapshot(path, &[tokens]);
+
+        assert!(changed_keys(&previous, &current).contains("tokenizer.ggml.tokens"));
+    }
+
+    #[test]
+    fn test_snapshot_stores_no_paths_or_values() {
+        let current = snapshot(Path::new("secret/model.gguf"), &[entry("general.name", "Qwen3")]);
+        let json = serde_json::to_string(&current).expect("Snapshot should serialize");
+
+        assert!(!json.contains("secret") && !json.contains("Qwen3"));
+        assert!(json.contains("general.name"));
+    }
+
+    #[test]
+    fn test_record_snapshot_replaces_and_caps() {
+        let mut snapshots = Vec::new();
+        let file = |name: &str| FileSnapshot { file: name.to_string(), values: BTreeMap::new() };
+
+        assert!(record_snapshot(&mut snapshots, file("a")).is_none());
+        for i in 0..MAX_SNAPSHOTS {
+            record_snapshot(&mut snapshots, file(&i.to_string()));
+        }
+        assert_eq!(snapshots.len(), MAX_SNAPSHOTS);
+        assert!(snapshots.iter().all(|snapshot| snapshot.file != "a"), "Oldest snapshot is dropped");
+
+        let previous = record_snapshot(&mut snapshots, file("5"));
+        assert_eq!(previous.map(|snapshot| snapshot.file), Some("5".to_string()));
+        assert_eq!(snapshots[0].file, "5");
+        assert_eq!(snapshots.len(), MAX_SNAPSHOTS);
+    }
+}
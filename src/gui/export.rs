@@ -16,6 +16,14 @@
 //! - **HTML**: Web-compatible format for online documentation and sharing
 //! - **PDF**: Print-ready format for reports and archival purposes
 //!
+//! ## Machine-Readable Formats
+//! - **JSON**: The same `{"keys", "raw"}` document produced by the command-line mode
+//! - **SQLite**: Rows of a shared `metadata(source, key, value)` table, so many models can be queried together
+//!
+//! ## Compressed Output
+//! - **gzip / zstd**: Paths ending in `.gz` or `.zst` are compressed transparently,
+//!   which keeps multi-megabyte tokenizer exports manageable
+//!
 //! ## Special Data Handling
 //! - **Base64 Encoding**: Automatic encoding for binary and large text data
 //! - **Content Sanitization**: Safe handling of control characters and special symbols
@@ -77,7 +85,49 @@
 
 use base64::Engine;
 use base64::engine::general_purpose::STANDARD;
+use std::io::{BufWriter, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use candle::quantized::gguf_file;
+use crate::format::MetadataDiff;
+use crate::gui::loader::MetadataEntry;
+use crate::gui::panels::content::{escape_whitespace, filter_entries, FilterMode};
+
+/// Compression applied to an exported file, chosen from its final extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportCompression {
+    /// Plain, uncompressed output.
+    None,
+    /// gzip compression (`.gz`).
+    Gzip,
+    /// Zstandard compression (`.zst`).
+    Zstd,
+}
+
+impl ExportCompression {
+    /// Detects the compression requested by the path's final extension.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use inspector_gguf::gui::export::ExportCompression;
+    /// use std::path::Path;
+    ///
+    /// assert_eq!(ExportCompression::from_path(Path::new("tokens.json.gz")), ExportCompression::Gzip);
+    /// assert_eq!(ExportCompression::from_path(Path::new("tokens.json.zst")), ExportCompression::Zstd);
+    /// assert_eq!(ExportCompression::from_path(Path::new("tokens.json")), ExportCompression::None);
+    /// ```
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("gz") => ExportCompression::Gzip,
+            Some(ext) if ext.eq_ignore_ascii_case("zst") => ExportCompression::Zstd,
+            _ => ExportCompression::None,
+        }
+    }
+}
 
 /// Ensures that a file path has the specified extension, adding it if missing.
 ///
@@ -85,6 +135,9 @@ use std::path::{Path, PathBuf};
 /// and if not, appends the specified extension. This ensures consistent file
 /// naming and helps prevent issues with file type detection and handling.
 ///
+/// A compression suffix (`.gz`, `.zst`) is not counted as the format extension:
+/// `report.gz` becomes `report.csv.gz`, while `report.csv.gz` is left unchanged.
+///
 /// # Parameters
 ///
 /// * `path` - The file path to check and potentially modify
@@ -109,8 +162,23 @@ use std::path::{Path, PathBuf};
 /// // Path with existing extension remains unchanged
 /// let path = ensure_extension(Path::new("document.txt"), "pdf");
 /// assert_eq!(path.to_str().unwrap(), "document.txt");
+///
+/// // The format extension goes before a compression suffix
+/// let path = ensure_extension(Path::new("document.gz"), "json");
+/// assert_eq!(path.to_str().unwrap(), "document.json.gz");
 /// ```
 pub fn ensure_extension(path: &Path, ext: &str) -> PathBuf {
+    if ExportCompression::from_path(path) != ExportCompression::None {
+        let inner = Path::new(path.file_stem().unwrap_or_default());
+        if inner.extension().is_some() {
+            return path.to_path_buf();
+        }
+        let compression_ext = path.extension().unwrap_or_default().to_string_lossy();
+        let mut p = path.to_path_buf();
+        p.set_extension(format!("{}.{}", ext, compression_ext));
+        return p;
+    }
+
     if path.extension().is_none() {
         let mut p = path.to_path_buf();
         p.set_extension(ext);
@@ -120,6 +188,222 @@ pub fn ensure_extension(path: &Path, ext: &str) -> PathBuf {
     }
 }
 
+/// Maximum length, in characters, of a name returned by [`sanitize_filename`].
+pub const MAX_FILENAME_CHARS: usize = 100;
+
+/// Turns an arbitrary string (typically `general.name`) into a safe file name.
+///
+/// Path separators, control characters and the characters Windows forbids
+/// (`< > : " / \ | ? *`) are replaced with `_` on every platform, so exported
+/// files can be copied between systems. Trailing dots and spaces are trimmed,
+/// reserved Windows device names (`CON`, `NUL`, `COM1`, …) get a `_` suffix, and
+/// the result is capped at [`MAX_FILENAME_CHARS`] characters. An empty result
+/// becomes `_`.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::sanitize_filename;
+///
+/// assert_eq!(sanitize_filename("org/model: v2"), "org_model_ v2");
+/// assert_eq!(sanitize_filename("CON"), "CON_");
+/// ```
+pub fn sanitize_filename(name: &str) -> String {
+    let replaced: String = name
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .take(MAX_FILENAME_CHARS)
+        .collect();
+    let mut sanitized = replaced.trim_end_matches(['.', ' ']).to_string();
+
+    let stem = sanitized.split('.').next().unwrap_or_default().to_ascii_uppercase();
+    let reserved = matches!(stem.as_str(), "CON" | "PRN" | "AUX" | "NUL")
+        || ((stem.starts_with("COM") || stem.starts_with("LPT"))
+            && stem.len() == 4
+            && stem.as_bytes()[3].is_ascii_digit());
+    if reserved {
+        sanitized.insert(stem.len(), '_');
+    }
+
+    if sanitized.is_empty() {
+        sanitized.push('_');
+    }
+    sanitized
+}
+
+/// Derives the default export file name (without extension) from `general.name`.
+///
+/// Falls back to `metadata` when the model has no usable name.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::export_base_name;
+///
+/// assert_eq!(export_base_name(Some("Qwen/Qwen3 0.6B")), "Qwen_Qwen3 0.6B");
+/// assert_eq!(export_base_name(None), "metadata");
+/// ```
+pub fn export_base_name(model_name: Option<&str>) -> String {
+    match model_name.map(sanitize_filename) {
+        Some(name) if name.chars().any(|c| c != '_') => name,
+        _ => "metadata".to_string(),
+    }
+}
+
+/// Writes serialized export data, compressing it when the path asks for it.
+///
+/// The compression is picked by [`ExportCompression::from_path`]; plain paths
+/// are written as-is.
+///
+/// # Errors
+///
+/// Returns an error if compression or the file write fails.
+pub fn write_export(path: &Path, bytes: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    match ExportCompression::from_path(path) {
+        ExportCompression::None => std::fs::write(path, bytes)?,
+        ExportCompression::Gzip => {
+            let mut encoder = flate2::write::GzEncoder::new(std::fs::File::create(path)?, flate2::Compression::default());
+            encoder.write_all(bytes)?;
+            encoder.finish()?;
+        }
+        ExportCompression::Zstd => {
+            let compressed = zstd::encode_all(bytes, 0)?;
+            std::fs::write(path, compressed)?;
+        }
+    }
+    Ok(())
+}
+
+/// Streams export data into a buffered file, compressing it when the path asks for it.
+///
+/// Unlike [`write_export`], the document never has to exist in memory as a whole:
+/// `write` receives a writer and produces the output incrementally.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be created, `write` fails, or the
+/// compressed stream cannot be finalized.
+pub fn write_export_streaming<F>(path: &Path, write: F) -> Result<(), Box<dyn std::error::Error>>
+where
+    F: FnOnce(&mut dyn Write) -> std::io::Result<()>,
+{
+    let file = BufWriter::new(std::fs::File::create(path)?);
+    match ExportCompression::from_path(path) {
+        ExportCompression::None => {
+            let mut file = file;
+            write(&mut file)?;
+            file.flush()?;
+        }
+        ExportCompression::Gzip => {
+            let mut encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+            write(&mut encoder)?;
+            encoder.finish()?.flush()?;
+        }
+        ExportCompression::Zstd => {
+            let mut encoder = zstd::Encoder::new(file, 0)?;
+            write(&mut encoder)?;
+            encoder.finish()?.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Errors detected after an export was written.
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    /// The written file could not be read back or does not hold the exported entries
+    #[error("Export verification failed for {path}: {reason}")]
+    VerificationFailed {
+        /// File that failed verification.
+        path: PathBuf,
+        /// What was wrong with it.
+        reason: String,
+    },
+}
+
+/// Reads an exported file back, decompressing it like [`write_export`] compressed it.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or decompressed.
+pub fn read_export(path: &Path) -> std::io::Result<Vec<u8>> {
+    let bytes = std::fs::read(path)?;
+    match ExportCompression::from_path(path) {
+        ExportCompression::None => Ok(bytes),
+        ExportCompression::Gzip => {
+            let mut out = Vec::new();
+            std::io::Read::read_to_end(&mut flate2::read::GzDecoder::new(bytes.as_slice()), &mut out)?;
+            Ok(out)
+        }
+        ExportCompression::Zstd => zstd::decode_all(bytes.as_slice()),
+    }
+}
+
+/// Reparses a CSV, YAML or JSON export and checks that it holds `expected` entries.
+///
+/// Used when [`ExportOptions::verify`] is set, to catch partial writes (a full
+/// disk, a truncated file) that would otherwise look like a successful export.
+/// The format is taken from the extension before any compression suffix.
+///
+/// # Errors
+///
+/// Returns [`ExportError::VerificationFailed`] if the file cannot be read or
+/// parsed, its format is not one of the above, or the entry count differs.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::{export_csv, verify_export};
+///
+/// let dir = tempfile::tempdir()?;
+/// let path = dir.path().join("metadata.csv");
+/// let (key, value) = ("general.architecture".to_string(), "qwen3".to_string());
+/// export_csv(&[(&key, &value)], &path)?;
+///
+/// assert!(verify_export(&path, 1).is_ok());
+/// assert!(verify_export(&path, 2).is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn verify_export(path: &Path, expected: usize) -> Result<(), ExportError> {
+    let failed = |reason: String| ExportError::VerificationFailed { path: path.to_path_buf(), reason };
+    let bytes = read_export(path).map_err(|e| failed(e.to_string()))?;
+    let format_path = match ExportCompression::from_path(path) {
+        ExportCompression::None => path.to_path_buf(),
+        _ => path.with_extension(""),
+    };
+    let ext = format_path.extension().and_then(|e| e.to_str()).unwrap_or_default().to_ascii_lowercase();
+
+    let found = match ext.as_str() {
+        "csv" => {
+            let mut reader = csv::Reader::from_reader(bytes.as_slice());
+            let mut count = 0;
+            for record in reader.records() {
+                record.map_err(|e| failed(e.to_string()))?;
+                count += 1;
+            }
+            count
+        }
+        "yaml" | "yml" => serde_yaml::from_slice::<serde_yaml::Mapping>(&bytes)
+            .map_err(|e| failed(e.to_string()))?
+            .len(),
+        "json" => serde_json::from_slice::<serde_json::Value>(&bytes)
+            .map_err(|e| failed(e.to_string()))?["keys"]
+            .as_array()
+            .ok_or_else(|| failed("missing \"keys\" array".to_string()))?
+            .len(),
+        _ => return Err(failed(format!("cannot verify .{} exports", ext))),
+    };
+    if found != expected {
+        return Err(failed(format!("expected {} entries, found {}", expected, found)));
+    }
+    Ok(())
+}
+
 /// Sanitizes text for markdown output by removing problematic control characters.
 ///
 /// This function processes text to make it safe for markdown rendering by removing
@@ -217,6 +501,35 @@ pub fn escape_markdown_text(s: &str) -> String {
         .join("")
 }
 
+/// Placeholder heading used by the markdown export for keys that are blank.
+pub const EMPTY_KEY_HEADING: &str = "(empty key)";
+
+/// Turns a metadata key into the text of a markdown `##` heading.
+///
+/// The key is trimmed and line breaks or other control characters become spaces,
+/// since a heading must fit on one line. Blank keys are replaced with
+/// [`EMPTY_KEY_HEADING`], so the heading is never empty. Markdown specials are
+/// escaped with [`escape_markdown_text`].
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::markdown_heading_text;
+///
+/// assert_eq!(markdown_heading_text("  general.name\n"), "general.name");
+/// assert_eq!(markdown_heading_text("   "), "(empty key)");
+/// ```
+pub fn markdown_heading_text(key: &str) -> String {
+    let single_line: String = key
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+    match single_line.trim() {
+        "" => EMPTY_KEY_HEADING.to_string(),
+        trimmed => escape_markdown_text(trimmed),
+    }
+}
+
 /// Shows base64 encoded data in a temporary file opened with default editor
 #[allow(dead_code)]
 pub fn show_base64_dialog(data: &str) -> Result<(), Box<dyn std::error::Error>> {
@@ -279,15 +592,345 @@ pub fn show_base64_dialog(data: &str) -> Result<(), Box<dyn std::error::Error>>
 pub fn export_csv(
     metadata: &[(&String, &String)],
     path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    export_csv_with_options(metadata, path, &ExportOptions::default())
+}
+
+/// Options controlling how exported values are written.
+///
+/// The GUI edits them in the export options dialog and persists them in
+/// [`crate::gui::settings::GuiSettings`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct ExportOptions {
+    /// Neutralize CSV cells that spreadsheets would evaluate as formulas.
+    pub sanitize_formulas: bool,
+    /// Character prefixed to neutralized cells.
+    pub formula_prefix: char,
+    /// Key patterns to keep; `None` keeps every key not excluded.
+    pub include_keys: Option<Vec<String>>,
+    /// Key patterns to drop, applied after `include_keys`.
+    pub exclude_keys: Vec<String>,
+    /// Export only the entries matching the content panel filter (see [`entries_for_export`]).
+    pub filtered_only: bool,
+    /// Read CSV, YAML and JSON exports back after writing them (see [`verify_export`]).
+    pub verify: bool,
+    /// Line terminator of CSV, YAML, JSON, Markdown and HTML exports.
+    pub line_ending: LineEnding,
+    /// Start Markdown exports with YAML front matter naming `source` (see [`Provenance`]).
+    pub include_provenance: bool,
+    /// GGUF file the exported metadata was read from; set per export, never persisted.
+    #[serde(skip)]
+    pub source: Option<PathBuf>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            sanitize_formulas: true,
+            formula_prefix: '\'',
+            include_keys: None,
+            exclude_keys: Vec::new(),
+            filtered_only: false,
+            verify: false,
+            line_ending: LineEnding::default(),
+            include_provenance: false,
+            source: None,
+        }
+    }
+}
+
+/// Line terminator written by text exports; defaults to [`LineEnding::native`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum LineEnding {
+    /// `\n`, used by Linux and macOS
+    Lf,
+    /// `\r\n`, used by Windows
+    CrLf,
+}
+
+impl LineEnding {
+    /// Returns the line ending of the platform the application runs on.
+    pub const fn native() -> Self {
+        if cfg!(windows) { LineEnding::CrLf } else { LineEnding::Lf }
+    }
+
+    /// Converts the `\n` line breaks of `text` to this line ending.
+    ///
+    /// Line breaks that already are `\r\n` are left alone, so converting twice is harmless.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use inspector_gguf::gui::export::LineEnding;
+    ///
+    /// assert_eq!(LineEnding::CrLf.apply("a\nb\r\n"), "a\r\nb\r\n");
+    /// assert_eq!(LineEnding::Lf.apply("a\nb\n"), "a\nb\n");
+    /// ```
+    pub fn apply(self, text: &str) -> std::borrow::Cow<'_, str> {
+        if self == LineEnding::Lf || !text.contains('\n') {
+            return std::borrow::Cow::Borrowed(text);
+        }
+        let mut out = String::with_capacity(text.len() + text.len() / 16);
+        let mut previous = '\0';
+        for c in text.chars() {
+            if c == '\n' && previous != '\r' {
+                out.push('\r');
+            }
+            out.push(c);
+            previous = c;
+        }
+        std::borrow::Cow::Owned(out)
+    }
+
+    /// Returns the CSV record terminator for this line ending.
+    fn csv_terminator(self) -> csv::Terminator {
+        match self {
+            LineEnding::Lf => csv::Terminator::Any(b'\n'),
+            LineEnding::CrLf => csv::Terminator::CRLF,
+        }
+    }
+}
+
+impl Default for LineEnding {
+    fn default() -> Self {
+        Self::native()
+    }
+}
+
+/// Writer adapter that turns `\n` into `\r\n` for streamed exports (see [`LineEnding::apply`]).
+struct CrLfWriter<'a> {
+    inner: &'a mut dyn Write,
+    after_cr: bool,
+}
+
+impl Write for CrLfWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut start = 0;
+        for (i, &byte) in buf.iter().enumerate() {
+            let after_cr = if i == 0 { self.after_cr } else { buf[i - 1] == b'\r' };
+            if byte == b'\n' && !after_cr {
+                self.inner.write_all(&buf[start..i])?;
+                self.inner.write_all(b"\r")?;
+                start = i;
+            }
+        }
+        self.inner.write_all(&buf[start..])?;
+        if let Some(&last) = buf.last() {
+            self.after_cr = last == b'\r';
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Runs a streaming export `write` with `line_ending` applied to everything it writes.
+fn write_with_line_ending<F>(line_ending: LineEnding, writer: &mut dyn Write, write: F) -> std::io::Result<()>
+where
+    F: FnOnce(&mut dyn Write) -> std::io::Result<()>,
+{
+    match line_ending {
+        LineEnding::Lf => write(writer),
+        LineEnding::CrLf => write(&mut CrLfWriter { inner: writer, after_cr: false }),
+    }
+}
+
+/// Splits a comma-separated list of key patterns, dropping empty items.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::parse_key_patterns;
+///
+/// assert_eq!(parse_key_patterns("general.*, tokenizer.ggml.model,"), ["general.*", "tokenizer.ggml.model"]);
+/// assert!(parse_key_patterns("  ").is_empty());
+/// ```
+pub fn parse_key_patterns(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(str::trim)
+        .filter(|pattern| !pattern.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Matches a metadata key against a glob-style pattern.
+///
+/// `*` matches any run of characters (including `.`), so `tokenizer.*` matches
+/// every tokenizer key; all other characters match literally.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::key_matches;
+///
+/// assert!(key_matches("tokenizer.*", "tokenizer.ggml.tokens"));
+/// assert!(key_matches("*.context_length", "llama.context_length"));
+/// assert!(!key_matches("general.name", "general.names"));
+/// ```
+pub fn key_matches(pattern: &str, key: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // split always yields at least one part
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = key.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // No wildcard: the whole key must match
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
+/// Selects the metadata entries an export should contain.
+///
+/// An entry is kept when it matches one of `include_keys` (or no include list is
+/// set) and matches none of `exclude_keys`. Patterns use [`key_matches`] syntax.
+/// The original order is preserved.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::{filter_keys, ExportOptions};
+///
+/// let metadata = vec![
+///     ("general.name".to_string(), "Qwen3".to_string()),
+///     ("tokenizer.ggml.model".to_string(), "gpt2".to_string()),
+/// ];
+/// let metadata_refs: Vec<(&String, &String)> = metadata.iter().map(|(k, v)| (k, v)).collect();
+///
+/// let options = ExportOptions { exclude_keys: vec!["tokenizer.*".to_string()], ..Default::default() };
+/// let kept = filter_keys(&metadata_refs, &options);
+/// assert_eq!(kept.len(), 1);
+/// assert_eq!(kept[0].0, "general.name");
+/// ```
+pub fn filter_keys<'a>(
+    entries: &[(&'a String, &'a String)],
+    options: &ExportOptions,
+) -> Vec<(&'a String, &'a String)> {
+    entries
+        .iter()
+        .filter(|(k, _)| {
+            let included = options
+                .include_keys
+                .as_ref()
+                .is_none_or(|patterns| patterns.iter().any(|p| key_matches(p, k)));
+            included && !options.exclude_keys.iter().any(|p| key_matches(p, k))
+        })
+        .copied()
+        .collect()
+}
+
+/// Returns the key/displayed value pairs handed to the exporters by the GUI.
+///
+/// With [`ExportOptions::filtered_only`] set, only the entries the content panel
+/// shows for `filter` in `filter_mode` are kept, selected by the panel's own
+/// [`filter_entries`] so numeric and fuzzy queries behave the same; otherwise all
+/// entries are returned in file order. Include/exclude patterns are applied later by the exporters through
+/// [`filter_keys`].
+pub fn entries_for_export<'a>(
+    entries: &'a [MetadataEntry],
+    filter: &str,
+    filter_mode: FilterMode,
+    options: &ExportOptions,
+) -> Vec<(&'a String, &'a String)> {
+    let kept = if options.filtered_only {
+        filter_entries(entries, filter, filter_mode)
+    } else {
+        entries.iter().collect()
+    };
+    kept.into_iter().map(|entry| (&entry.key, &entry.display_value)).collect()
+}
+
+/// Returns how many keys an export with `options` writes, given the content panel `filter` and `filter_mode`.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::{exported_key_count, ExportOptions};
+/// use inspector_gguf::gui::loader::MetadataEntry;
+/// use inspector_gguf::gui::panels::content::FilterMode;
+///
+/// let metadata: Vec<MetadataEntry> = [("general.name", "Qwen3"), ("general.architecture", "qwen3"), ("tokenizer.ggml.model", "gpt2")]
+///     .iter()
+///     .map(|(key, value)| MetadataEntry { key: key.to_string(), display_value: value.to_string(), full_value: None, raw_bytes: None, string_bytes: None })
+///     .collect();
+///
+/// let options = ExportOptions { filtered_only: true, ..Default::default() };
+/// assert_eq!(exported_key_count(&metadata, "general", FilterMode::Substring, &options), 2);
+/// assert_eq!(exported_key_count(&metadata, "general", FilterMode::Substring, &ExportOptions::default()), 3);
+/// ```
+pub fn exported_key_count(entries: &[MetadataEntry], filter: &str, filter_mode: FilterMode, options: &ExportOptions) -> usize {
+    filter_keys(&entries_for_export(entries, filter, filter_mode, options), options).len()
+}
+
+/// Prefixes `value` with `prefix` if a spreadsheet would treat it as a formula.
+///
+/// Cells starting with `=`, `+`, `-`, `@`, a tab or a carriage return are
+/// neutralized (CSV injection); all other values are returned unchanged.
+/// Numbers such as `-1` or `+0.5` are not formulas and are kept as they are.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::neutralize_formula;
+///
+/// assert_eq!(neutralize_formula("=SUM(A1)", '\''), "'=SUM(A1)");
+/// assert_eq!(neutralize_formula("-1", '\''), "-1");
+/// assert_eq!(neutralize_formula("llama", '\''), "llama");
+/// ```
+pub fn neutralize_formula(value: &str, prefix: char) -> std::borrow::Cow<'_, str> {
+    let numeric = value.parse::<i64>().is_ok() || value.parse::<f64>().is_ok();
+    if !numeric && value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        std::borrow::Cow::Owned(format!("{}{}", prefix, value))
+    } else {
+        std::borrow::Cow::Borrowed(value)
+    }
+}
+
+/// Exports metadata to CSV format with explicit [`ExportOptions`].
+///
+/// [`export_csv`] uses the defaults, which neutralize formula-like cells and keep
+/// every key.
+///
+/// # Errors
+///
+/// Returns an error if CSV serialization or the file write fails.
+pub fn export_csv_with_options(
+    metadata: &[(&String, &String)],
+    path: &Path,
+    options: &ExportOptions,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let path = ensure_extension(path, "csv");
-    let mut wtr = csv::Writer::from_path(&path)?;
+    let mut wtr = csv::WriterBuilder::new()
+        .terminator(options.line_ending.csv_terminator())
+        .from_writer(Vec::new());
     // Note: CSV headers are kept in English for compatibility
     wtr.write_record(["key", "value"])?;
-    for (k, v) in metadata {
-        wtr.write_record([k, v])?;
+    let entries = filter_keys(metadata, options);
+    for &(k, v) in &entries {
+        if options.sanitize_formulas {
+            let key = neutralize_formula(k, options.formula_prefix);
+            let value = neutralize_formula(v, options.formula_prefix);
+            wtr.write_record([key.as_bytes(), value.as_bytes()])?;
+        } else {
+            wtr.write_record([k, v])?;
+        }
+    }
+    let bytes = wtr.into_inner().map_err(|e| e.into_error())?;
+    write_export(&path, &bytes)?;
+    if options.verify {
+        verify_export(&path, entries.len())?;
     }
-    wtr.flush()?;
     Ok(())
 }
 
@@ -296,93 +939,1076 @@ pub fn export_yaml(
     metadata: &[(&String, &String)],
     path: &Path,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let map: std::collections::HashMap<_, _> = metadata.iter()
+    export_yaml_with_options(metadata, path, &ExportOptions::default())
+}
+
+/// Exports metadata to YAML format, keeping only the keys selected by [`filter_keys`]
+pub fn export_yaml_with_options(
+    metadata: &[(&String, &String)],
+    path: &Path,
+    options: &ExportOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let map: std::collections::HashMap<_, _> = filter_keys(metadata, options).iter()
         .map(|(k, v)| ((*k).clone(), (*v).clone()))
         .collect();
     let yaml = serde_yaml::to_string(&map)?;
     let path = ensure_extension(path, "yaml");
-    std::fs::write(path, yaml)?;
+    write_export(&path, options.line_ending.apply(&yaml).as_bytes())?;
+    if options.verify {
+        verify_export(&path, map.len())?;
+    }
     Ok(())
 }
 
-/// Exports metadata to markdown format and returns the markdown string
-pub fn export_markdown(metadata: &[(&String, &String)]) -> String {
-    let mut out = String::new();
-    out.push_str("# GGUF Metadata\n\n");
+/// Builds the JSON export document: the ordered key list plus a `raw` object.
+///
+/// Values that are themselves valid JSON are embedded as JSON; everything else
+/// is stored as a string. This is the format written by the command-line mode.
+pub fn export_json_value(metadata: &[(&String, &String)]) -> serde_json::Value {
+    let mut map = serde_json::Map::new();
+    let mut keys = Vec::new();
+
     for (k, v) in metadata {
-        out.push_str(&format!("## {}\n\n", escape_markdown_text(k)));
-        out.push('\n');
-        if v.len() > 1024 || v.contains('\0') {
-            // For large/binary fields — Base64
-            let b64 = STANDARD.encode(v.as_bytes());
-            out.push_str("```base64\n");
-            out.push_str(&b64);
-            out.push_str("\n```\n\n");
+        keys.push((*k).clone());
+        // Try to parse as JSON, fallback to string
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(v) {
+            map.insert((*k).clone(), json);
         } else {
-            let safe = sanitize_for_markdown(v);
-            out.push_str("```\n");
-            out.push_str(&safe.replace("```", "` ` `"));
-            out.push_str("\n```\n\n");
+            map.insert((*k).clone(), serde_json::Value::String((*v).clone()));
         }
     }
-    out
+
+    serde_json::json!({"keys": keys, "raw": serde_json::Value::Object(map)})
 }
 
-/// Exports metadata to markdown file
-pub fn export_markdown_to_file(
+/// Exports metadata to a pretty-printed JSON file (see [`export_json_value`])
+pub fn export_json(
     metadata: &[(&String, &String)],
     path: &Path,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let md = export_markdown(metadata);
-    let path = ensure_extension(path, "md");
-    std::fs::write(&path, md)?;
-    Ok(())
-}
-
-/// Exports metadata to HTML format and returns the HTML string
-pub fn export_html(metadata: &[(&String, &String)]) -> Result<String, Box<dyn std::error::Error>> {
-    let md = export_markdown(metadata);
-    let parser = pulldown_cmark::Parser::new(&md);
-    let mut html_output = String::new();
-    pulldown_cmark::html::push_html(&mut html_output, parser);
-    Ok(html_output)
+    export_json_with_options(metadata, path, &ExportOptions::default())
 }
 
-/// Exports metadata to HTML file
-pub fn export_html_to_file(
+/// Exports metadata to a pretty-printed JSON file, keeping only the keys selected by [`filter_keys`]
+pub fn export_json_with_options(
     metadata: &[(&String, &String)],
     path: &Path,
+    options: &ExportOptions,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let html = export_html(metadata)?;
-    let path = ensure_extension(path, "html");
-    std::fs::write(&path, html)?;
+    let metadata = filter_keys(metadata, options);
+    let json = serde_json::to_string_pretty(&export_json_value(&metadata))?;
+    let path = ensure_extension(path, "json");
+    write_export(&path, options.line_ending.apply(&json).as_bytes())?;
+    if options.verify {
+        verify_export(&path, metadata.len())?;
+    }
     Ok(())
 }
 
-/// Exports markdown content to PDF file
-pub fn export_pdf_from_markdown(
-    md: &str,
-    out_path: &Path,
+/// Appends metadata rows to the `metadata` table of the SQLite database at `db_path`.
+///
+/// The database and the `metadata(source TEXT, key TEXT, value TEXT)` table are
+/// created if absent. Every row is tagged with `source`, typically the model's
+/// file name, and existing rows are kept, so exporting several models into the
+/// same file accumulates them for cross-model queries. All rows of one call are
+/// inserted in a single transaction.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::export_sqlite;
+///
+/// let dir = tempfile::tempdir()?;
+/// let db = dir.path().join("models.db");
+/// let (key, value) = ("general.architecture".to_string(), "qwen3".to_string());
+///
+/// export_sqlite("a.gguf", &[(&key, &value)], &db)?;
+/// export_sqlite("b.gguf", &[(&key, &value)], &db)?;
+///
+/// let conn = rusqlite::Connection::open(&db)?;
+/// let rows: i64 = conn.query_row("SELECT COUNT(*) FROM metadata", [], |row| row.get(0))?;
+/// assert_eq!(rows, 2);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn export_sqlite(
+    source: &str,
+    metadata: &[(&String, &String)],
+    db_path: &Path,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    // Ensure .pdf extension and pass &str to markdown2pdf
-    let out_path = ensure_extension(out_path, "pdf");
-    let out_str = out_path.to_str().ok_or("output path is not valid UTF-8")?;
-    // markdown2pdf can error on unexpected tokens — provide sanitized markdown
-    let safe_md = sanitize_for_markdown(md);
-    markdown2pdf::parse_into_file(
-        safe_md.to_string(),
-        out_str,
-        markdown2pdf::config::ConfigSource::Default,
+    let mut conn = rusqlite::Connection::open(db_path)?;
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS metadata (source TEXT, key TEXT, value TEXT)",
+        [],
     )?;
+    let tx = conn.transaction()?;
+    {
+        let mut insert = tx.prepare("INSERT INTO metadata (source, key, value) VALUES (?1, ?2, ?3)")?;
+        for (key, value) in metadata {
+            insert.execute(rusqlite::params![source, key, value])?;
+        }
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// Header fields reported first by [`crate::format::load_gguf_metadata_sync`].
+const HEADER_KEYS: [&str; 3] = ["version", "tensor_count", "kv_count"];
+
+/// Builds the full report document for the GGUF file at `path`.
+///
+/// The document has four sections: `header` (format version and counts),
+/// `metadata` (readable values by key), `tensors` (see
+/// [`crate::format::TensorInfoEntry`]) and `summary` (see
+/// [`crate::format::ModelSummary`]).
+pub fn full_report_value(path: &Path) -> Result<serde_json::Value, crate::format::BoxError> {
+    let mut header = serde_json::Map::new();
+    let mut metadata = serde_json::Map::new();
+    for (key, value) in crate::format::load_gguf_metadata_sync(path)? {
+        if HEADER_KEYS.contains(&key.as_str()) {
+            let number = value.parse::<u64>().map(serde_json::Value::from);
+            header.insert(key, number.unwrap_or(serde_json::Value::String(value)));
+        } else {
+            metadata.insert(key, serde_json::Value::String(value));
+        }
+    }
+
+    let tensors = crate::format::load_gguf_tensor_info_sync(path)?;
+    let summary = crate::format::build_model_report(path)?.summary;
+
+    Ok(serde_json::json!({
+        "header": header,
+        "metadata": metadata,
+        "tensors": tensors,
+        "summary": summary,
+    }))
+}
+
+/// Exports metadata, tensor info and the model summary of `path` as one JSON document.
+///
+/// See [`full_report_value`] for the document layout.
+pub fn export_full_report(path: &Path, out: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    let report = full_report_value(path).map_err(|e| e as Box<dyn std::error::Error>)?;
+    let json = serde_json::to_string_pretty(&report)?;
+    let out = ensure_extension(out, "json");
+    write_export(&out, json.as_bytes())?;
     Ok(())
 }
 
+/// Exports metadata to markdown format and returns the markdown string
+pub fn export_markdown(metadata: &[(&String, &String)]) -> String {
+    let mut out = Vec::new();
+    export_markdown_streaming(metadata, &mut out).expect("writing to a Vec cannot fail");
+    String::from_utf8(out).expect("markdown export is built from UTF-8 strings")
+}
+
+/// Writes the markdown export to `writer` one entry at a time.
+///
+/// Produces the same document as [`export_markdown`] without holding it in memory,
+/// which matters for megabyte-scale tokenizer values.
+///
+/// # Errors
+///
+/// Returns any error reported by `writer`.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::{export_markdown, export_markdown_streaming};
+///
+/// let metadata = vec![("general.name".to_string(), "Qwen3".to_string())];
+/// let metadata_refs: Vec<(&String, &String)> = metadata.iter().map(|(k, v)| (k, v)).collect();
+///
+/// let mut sink = Vec::new();
+/// export_markdown_streaming(&metadata_refs, &mut sink)?;
+/// assert_eq!(String::from_utf8(sink)?, export_markdown(&metadata_refs));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn export_markdown_streaming(
+    metadata: &[(&String, &String)],
+    writer: &mut dyn Write,
+) -> std::io::Result<()> {
+    writer.write_all(MARKDOWN_TITLE.as_bytes())?;
+    for (k, v) in metadata {
+        write_markdown_entry(k, v, writer)?;
+    }
+    Ok(())
+}
+
+const MARKDOWN_TITLE: &str = "# GGUF Metadata\n\n";
+
+/// Writes a single `## key` section of the markdown export.
+fn write_markdown_entry(key: &str, value: &str, writer: &mut dyn Write) -> std::io::Result<()> {
+    write!(writer, "## {}\n\n", markdown_heading_text(key))?;
+    writer.write_all(b"\n")?;
+    if value.len() > 1024 || value.contains('\0') {
+        // For large/binary fields — Base64, encoded straight into the writer
+        writer.write_all(b"```base64\n")?;
+        let mut encoder = base64::write::EncoderWriter::new(&mut *writer, &STANDARD);
+        encoder.write_all(value.as_bytes())?;
+        encoder.finish()?;
+        writer.write_all(b"\n```\n\n")?;
+    } else {
+        let safe = sanitize_for_markdown(value);
+        writer.write_all(b"```\n")?;
+        writer.write_all(safe.replace("```", "` ` `").as_bytes())?;
+        writer.write_all(b"\n```\n\n")?;
+    }
+    Ok(())
+}
+
+/// Default cap on value length, in characters, for [`export_markdown_table`].
+pub const MARKDOWN_TABLE_VALUE_LIMIT: usize = 120;
+
+/// Builds a compact GitHub-flavored markdown table (`key | value`) of the metadata.
+///
+/// Intended for pasting into issues: pipes are escaped, line breaks become spaces,
+/// and values longer than `max_value_chars` characters are truncated with `…`.
+/// A cap of `0` disables truncation.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::export_markdown_table;
+///
+/// let metadata = vec![("general.name".to_string(), "a|b".to_string())];
+/// let metadata_refs: Vec<(&String, &String)> = metadata.iter().map(|(k, v)| (k, v)).collect();
+///
+/// assert_eq!(
+///     export_markdown_table(&metadata_refs, 120),
+///     "| Key | Value |\n| --- | --- |\n| `general.name` | a\\|b |\n"
+/// );
+/// ```
+pub fn export_markdown_table(metadata: &[(&String, &String)], max_value_chars: usize) -> String {
+    let mut out = String::from("| Key | Value |\n| --- | --- |\n");
+    for (k, v) in metadata {
+        let value = if max_value_chars > 0 && v.chars().count() > max_value_chars {
+            format!("{}…", v.chars().take(max_value_chars).collect::<String>())
+        } else {
+            (*v).clone()
+        };
+        out.push_str(&format!(
+            "| `{}` | {} |\n",
+            escape_table_cell(&k.replace('`', "'")),
+            escape_table_cell(&value)
+        ));
+    }
+    out
+}
+
+/// Formats the metadata differences between two files as a Markdown document.
+///
+/// Both files are named at the top, followed by one line per difference in a
+/// `diff` code block, as rendered by [`MetadataDiff`]'s `Display`: `-` for keys
+/// only in `left`, `+` for keys only in `right`, and `~` for changed values.
+/// Newlines and tabs in values are escaped so every difference stays on one line.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::format::diff_metadata;
+/// use inspector_gguf::gui::export::export_diff_markdown;
+///
+/// let left = vec![("general.name".to_string(), "Qwen3".to_string())];
+/// let right = vec![("general.name".to_string(), "Qwen3-0.6B".to_string())];
+///
+/// let markdown = export_diff_markdown(&diff_metadata(&left, &right), "a.gguf", "b.gguf");
+/// assert!(markdown.contains("~ general.name: Qwen3 -> Qwen3-0.6B\n"));
+/// ```
+pub fn export_diff_markdown(diffs: &[MetadataDiff], left: &str, right: &str) -> String {
+    let mut out = format!(
+        "# Metadata diff\n\n- Left: `{}`\n- Right: `{}`\n\n",
+        left.replace('`', "'"),
+        right.replace('`', "'")
+    );
+    if diffs.is_empty() {
+        out.push_str("No differences.\n");
+        return out;
+    }
+    out.push_str("```diff\n");
+    for diff in diffs {
+        out.push_str(&escape_whitespace(&diff.to_string()));
+        out.push('\n');
+    }
+    out.push_str("```\n");
+    out
+}
+
+/// Builds the JSON export of the differences between two files.
+///
+/// The document has `left` and `right` file names and a `differences` array of
+/// serialized [`MetadataDiff`] values (`{"kind": "changed", "key": ..., ...}`).
+pub fn export_diff_json(diffs: &[MetadataDiff], left: &str, right: &str) -> serde_json::Value {
+    serde_json::json!({ "left": left, "right": right, "differences": diffs })
+}
+
+/// Longest value printed by [`export_llamacpp_style`]; longer values end in `...`, as in llama.cpp.
+pub const LLAMACPP_MAX_VALUE_LEN: usize = 40;
+
+/// Formats metadata like llama.cpp's model loader prints it, for diffing against its log.
+///
+/// Each entry becomes one `<key> <type> = <value>` line. Keys are right-aligned
+/// to the longest key and types padded to 16 characters, so all `=` signs share a
+/// column. Types use llama.cpp's names (`u32`, `f32`, `str`, ...), arrays are
+/// annotated as `arr[<element type>,<length>]`, and values are cut to
+/// [`LLAMACPP_MAX_VALUE_LEN`] characters with newlines shown as `\n`. Entries are
+/// written in the given order; llama.cpp's `llama_model_loader: - kv N:` prefix is
+/// not included.
+///
+/// # Examples
+///
+/// ```rust
+/// use candle::quantized::gguf_file::Value;
+/// use inspector_gguf::gui::export::export_llamacpp_style;
+///
+/// let (tokens_key, name_key) = ("tokenizer.ggml.tokens".to_string(), "general.name".to_string());
+/// let tokens = Value::Array(vec![Value::String("<s>".into()), Value::String("</s>".into())]);
+/// let name = Value::String("Qwen3".into());
+///
+/// assert_eq!(
+///     export_llamacpp_style(&[(&tokens_key, &tokens), (&name_key, &name)]),
+///     "tokenizer.ggml.tokens arr[str,2]       = [\"<s>\", \"</s>\"]\n\
+///      \x20        general.name str              = Qwen3\n"
+/// );
+/// ```
+pub fn export_llamacpp_style(metadata: &[(&String, &gguf_file::Value)]) -> String {
+    let key_width = metadata.iter().map(|(k, _)| k.chars().count()).max().unwrap_or(0);
+    let mut out = String::new();
+    for (key, value) in metadata {
+        let type_name = match value {
+            gguf_file::Value::Array(items) => format!(
+                "arr[{},{}]",
+                // candle does not keep the element type of empty arrays
+                items.first().map_or("u8", llamacpp_type_name),
+                items.len()
+            ),
+            value => llamacpp_type_name(value).to_string(),
+        };
+        let mut text = String::new();
+        llamacpp_value(value, &mut text);
+        if text.chars().count() > LLAMACPP_MAX_VALUE_LEN {
+            text = format!("{}...", text.chars().take(LLAMACPP_MAX_VALUE_LEN - 3).collect::<String>());
+        }
+        out.push_str(&format!(
+            "{:>key_width$} {:<16} = {}\n",
+            key,
+            type_name,
+            text.replace('\n', "\\n")
+        ));
+    }
+    out
+}
+
+/// Returns llama.cpp's `gguf_type_name` for the type of `value`.
+fn llamacpp_type_name(value: &gguf_file::Value) -> &'static str {
+    use gguf_file::Value;
+    match value {
+        Value::U8(_) => "u8",
+        Value::I8(_) => "i8",
+        Value::U16(_) => "u16",
+        Value::I16(_) => "i16",
+        Value::U32(_) => "u32",
+        Value::I32(_) => "i32",
+        Value::U64(_) => "u64",
+        Value::I64(_) => "i64",
+        Value::F32(_) => "f32",
+        Value::F64(_) => "f64",
+        Value::Bool(_) => "bool",
+        Value::String(_) => "str",
+        Value::Array(_) => "arr",
+    }
+}
+
+/// Appends `value` the way llama.cpp's `gguf_kv_to_str` renders it.
+///
+/// Floats use C++ `std::to_string` precision, array strings are quoted, and nested
+/// arrays show as `???`. Arrays stop early once the text is longer than
+/// [`LLAMACPP_MAX_VALUE_LEN`], since the rest would be cut anyway.
+fn llamacpp_value(value: &gguf_file::Value, out: &mut String) {
+    use gguf_file::Value;
+    match value {
+        Value::U8(n) => out.push_str(&n.to_string()),
+        Value::I8(n) => out.push_str(&n.to_string()),
+        Value::U16(n) => out.push_str(&n.to_string()),
+        Value::I16(n) => out.push_str(&n.to_string()),
+        Value::U32(n) => out.push_str(&n.to_string()),
+        Value::I32(n) => out.push_str(&n.to_string()),
+        Value::U64(n) => out.push_str(&n.to_string()),
+        Value::I64(n) => out.push_str(&n.to_string()),
+        Value::F32(f) => out.push_str(&format!("{:.6}", f)),
+        Value::F64(f) => out.push_str(&format!("{:.6}", f)),
+        Value::Bool(b) => out.push_str(&b.to_string()),
+        Value::String(s) => out.push_str(s),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(", ");
+                }
+                match item {
+                    Value::String(s) => out.push_str(&format!("\"{}\"", s.replace('"', "\\\""))),
+                    Value::Array(_) => out.push_str("???"),
+                    item => llamacpp_value(item, out),
+                }
+                if out.len() > LLAMACPP_MAX_VALUE_LEN * 4 {
+                    return;
+                }
+            }
+            out.push(']');
+        }
+    }
+}
+
+/// Makes text safe for a single GFM table cell.
+fn escape_table_cell(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '|' => "\\|".to_string(),
+            '\n' | '\r' => " ".to_string(),
+            c if c.is_control() => " ".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// Exports metadata to markdown file
+pub fn export_markdown_to_file(
+    metadata: &[(&String, &String)],
+    path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    export_markdown_to_file_with_options(metadata, path, &ExportOptions::default())
+}
+
+/// Exports metadata to markdown file, keeping only the keys selected by [`filter_keys`]
+///
+/// With [`ExportOptions::include_provenance`] and a [`ExportOptions::source`], the
+/// document starts with the source's [`markdown_front_matter`].
+pub fn export_markdown_to_file_with_options(
+    metadata: &[(&String, &String)],
+    path: &Path,
+    options: &ExportOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let metadata = filter_keys(metadata, options);
+    let front_matter = match (&options.source, options.include_provenance) {
+        (Some(source), true) => markdown_front_matter(&Provenance::for_file(source)?)?,
+        _ => String::new(),
+    };
+    let path = ensure_extension(path, "md");
+    write_export_streaming(&path, |writer| {
+        write_with_line_ending(options.line_ending, writer, |writer| {
+            writer.write_all(front_matter.as_bytes())?;
+            export_markdown_streaming(&metadata, writer)
+        })
+    })
+}
+
+/// Origin of an export, written as Markdown front matter by [`markdown_front_matter`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Provenance {
+    /// Absolute path of the source GGUF file
+    pub source: String,
+    /// Lowercase hex SHA-256 of the source file
+    pub sha256: String,
+    /// Export time in RFC 3339, UTC
+    pub exported_at: String,
+}
+
+impl Provenance {
+    /// Hashes `path` and records it as exported now.
+    ///
+    /// The file is streamed through the hasher, so multi-gigabyte models are not
+    /// read into memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read.
+    pub fn for_file(path: &Path) -> std::io::Result<Self> {
+        use sha2::Digest;
+
+        let mut hasher = sha2::Sha256::new();
+        std::io::copy(&mut std::fs::File::open(path)?, &mut hasher)?;
+        let source = std::path::absolute(path)?;
+        Ok(Self {
+            source: source.to_string_lossy().into_owned(),
+            sha256: hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect(),
+            exported_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        })
+    }
+}
+
+/// Renders `provenance` as a YAML front-matter block for static site generators.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::{markdown_front_matter, Provenance};
+///
+/// let provenance = Provenance {
+///     source: "/models/qwen3.gguf".to_string(),
+///     sha256: "ab".repeat(32),
+///     exported_at: "2026-01-02T03:04:05Z".to_string(),
+/// };
+/// let front_matter = markdown_front_matter(&provenance)?;
+/// assert!(front_matter.starts_with("---\nsource: /models/qwen3.gguf\n"));
+/// assert!(front_matter.ends_with("\n---\n\n"));
+/// # Ok::<(), serde_yaml::Error>(())
+/// ```
+pub fn markdown_front_matter(provenance: &Provenance) -> Result<String, serde_yaml::Error> {
+    // serde_yaml quotes paths that would otherwise be read as other YAML types
+    Ok(format!("---\n{}---\n\n", serde_yaml::to_string(provenance)?))
+}
+
+/// Exports metadata to HTML format and returns the HTML string
+pub fn export_html(metadata: &[(&String, &String)]) -> Result<String, Box<dyn std::error::Error>> {
+    let mut out = Vec::new();
+    export_html_streaming(metadata, &mut out)?;
+    Ok(String::from_utf8(out)?)
+}
+
+/// Writes the HTML export to `writer`, rendering one metadata entry at a time.
+///
+/// Each entry's markdown section is converted on its own, so only a single
+/// entry is ever held in memory. The output matches [`export_html`].
+///
+/// # Errors
+///
+/// Returns any error reported by `writer`.
+pub fn export_html_streaming(
+    metadata: &[(&String, &String)],
+    writer: &mut dyn Write,
+) -> std::io::Result<()> {
+    write_html_fragment(MARKDOWN_TITLE, writer)?;
+    let mut section = Vec::new();
+    for (k, v) in metadata {
+        section.clear();
+        write_markdown_entry(k, v, &mut section)?;
+        let md = String::from_utf8_lossy(&section);
+        write_html_fragment(&md, writer)?;
+    }
+    Ok(())
+}
+
+/// Renders a self-contained markdown fragment to HTML.
+fn write_html_fragment(md: &str, writer: &mut dyn Write) -> std::io::Result<()> {
+    let parser = pulldown_cmark::Parser::new(md);
+    let mut html_output = String::new();
+    pulldown_cmark::html::push_html(&mut html_output, parser);
+    writer.write_all(html_output.as_bytes())
+}
+
+/// Exports metadata to HTML file
+pub fn export_html_to_file(
+    metadata: &[(&String, &String)],
+    path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    export_html_to_file_with_options(metadata, path, &ExportOptions::default())
+}
+
+/// Exports metadata to HTML file, keeping only the keys selected by [`filter_keys`]
+pub fn export_html_to_file_with_options(
+    metadata: &[(&String, &String)],
+    path: &Path,
+    options: &ExportOptions,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let metadata = filter_keys(metadata, options);
+    let path = ensure_extension(path, "html");
+    write_export_streaming(&path, |writer| {
+        write_with_line_ending(options.line_ending, writer, |writer| export_html_streaming(&metadata, writer))
+    })
+}
+
+/// Longest time [`export_pdf_from_markdown`] waits for the PDF renderer.
+pub const PDF_EXPORT_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// How often a waiting PDF export checks its cancel flag.
+const PDF_EXPORT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Errors of [`export_pdf_from_markdown_with_timeout`].
+///
+/// Renderer failures the user can work around suggest exporting to HTML, which
+/// has no such limitations.
+#[derive(Debug, thiserror::Error)]
+pub enum PdfExportError {
+    /// The renderer hit a token it could not parse
+    #[error("The PDF renderer could not read the metadata ({0}); try exporting to HTML instead")]
+    UnexpectedToken(String),
+    /// The markdown contains an element the renderer does not support
+    #[error("The metadata contains formatting the PDF renderer does not support ({0}); try exporting to HTML instead")]
+    UnsupportedElement(String),
+    /// The renderer did not finish in time
+    #[error("PDF export did not finish within {} seconds; try exporting to HTML instead", .0.as_secs())]
+    TimedOut(Duration),
+    /// The export was cancelled through its cancel flag
+    #[error("PDF export was cancelled")]
+    Cancelled,
+    /// Any other failure, with the renderer's message
+    #[error("PDF export failed: {0}")]
+    Failed(String),
+}
+
+impl PdfExportError {
+    /// Classifies a raw `markdown2pdf` error message.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use inspector_gguf::gui::export::PdfExportError;
+    ///
+    /// let error = PdfExportError::from_renderer_message("Lexer error: Unexpected token '|' at position 42");
+    /// assert!(matches!(error, PdfExportError::UnexpectedToken(_)));
+    /// assert!(error.to_string().contains("HTML"));
+    /// ```
+    pub fn from_renderer_message(message: &str) -> Self {
+        let lower = message.to_lowercase();
+        if lower.contains("unexpected") {
+            PdfExportError::UnexpectedToken(message.to_string())
+        } else if lower.contains("unsupported") || lower.contains("not supported") {
+            PdfExportError::UnsupportedElement(message.to_string())
+        } else {
+            PdfExportError::Failed(message.to_string())
+        }
+    }
+}
+
+/// Exports markdown content to PDF file
+///
+/// Gives up after [`PDF_EXPORT_TIMEOUT`]; see [`export_pdf_from_markdown_with_timeout`].
+pub fn export_pdf_from_markdown(
+    md: &str,
+    out_path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    export_pdf_from_markdown_with_timeout(md, out_path, PDF_EXPORT_TIMEOUT, &AtomicBool::new(false))?;
+    Ok(())
+}
+
+/// Exports markdown content to a PDF file, rendering it on a worker thread.
+///
+/// The calling thread waits until the renderer finishes, `timeout` passes, or
+/// `cancel` is set. The PDF is rendered to a `.part` file next to `out_path` and
+/// renamed when complete, so an abandoned render leaves no partial PDF behind.
+///
+/// # Errors
+///
+/// Returns [`PdfExportError::TimedOut`] or [`PdfExportError::Cancelled`] when the
+/// export is abandoned, and a classified renderer error (see
+/// [`PdfExportError::from_renderer_message`]) when rendering fails.
+pub fn export_pdf_from_markdown_with_timeout(
+    md: &str,
+    out_path: &Path,
+    timeout: Duration,
+    cancel: &AtomicBool,
+) -> Result<(), PdfExportError> {
+    // Ensure .pdf extension and pass &str to markdown2pdf
+    let out_path = ensure_extension(out_path, "pdf");
+    // markdown2pdf can error on unexpected tokens — provide sanitized markdown
+    let safe_md = sanitize_for_markdown(md);
+    render_pdf_with_timeout(&out_path, timeout, cancel, move |part_path| {
+        markdown2pdf::parse_into_file(safe_md, part_path, markdown2pdf::config::ConfigSource::Default)
+            .map_err(|e| e.to_string())
+    })
+}
+
+/// Runs `render` on a worker thread for [`export_pdf_from_markdown_with_timeout`].
+///
+/// `render` writes the PDF to the path it receives and reports failures as the
+/// renderer's raw message.
+fn render_pdf_with_timeout<F>(out_path: &Path, timeout: Duration, cancel: &AtomicBool, render: F) -> Result<(), PdfExportError>
+where
+    F: FnOnce(&str) -> Result<(), String> + Send + 'static,
+{
+    PdfRender::spawn(out_path, render)?.wait(timeout, cancel)
+}
+
+/// A PDF render running on a worker thread.
+struct PdfRender {
+    /// Receives the outcome once the worker is done, sent while `abandoned` is locked.
+    receiver: mpsc::Receiver<Result<(), PdfExportError>>,
+    /// Set when the caller stops waiting, so a late render is discarded instead of renamed.
+    ///
+    /// The worker checks it and renames the `.part` file under the same lock, so
+    /// a render either completes before the caller gives up or leaves no file.
+    abandoned: Arc<Mutex<bool>>,
+}
+
+impl PdfRender {
+    /// Starts `render` on a worker thread, writing to a `.part` file next to `out_path`.
+    fn spawn<F>(out_path: &Path, render: F) -> Result<Self, PdfExportError>
+    where
+        F: FnOnce(&str) -> Result<(), String> + Send + 'static,
+    {
+        let mut part_path = out_path.as_os_str().to_owned();
+        part_path.push(".part");
+        let part_path = part_path
+            .into_string()
+            .map_err(|_| PdfExportError::Failed("output path is not valid UTF-8".to_string()))?;
+
+        let abandoned = Arc::new(Mutex::new(false));
+        let (sender, receiver) = mpsc::channel();
+        {
+            let abandoned = Arc::clone(&abandoned);
+            let out_path = out_path.to_path_buf();
+            thread::spawn(move || {
+                puffin::profile_scope!("pdf_render_thread");
+                let rendered = render(&part_path).map_err(|message| PdfExportError::from_renderer_message(&message));
+                let abandoned = abandoned.lock().unwrap();
+                let outcome = rendered.and_then(|()| {
+                    if *abandoned {
+                        Err(PdfExportError::Cancelled)
+                    } else {
+                        std::fs::rename(&part_path, &out_path).map_err(|e| PdfExportError::Failed(e.to_string()))
+                    }
+                });
+                if outcome.is_err() {
+                    let _ = std::fs::remove_file(&part_path);
+                }
+                let _ = sender.send(outcome);
+            });
+        }
+        Ok(Self { receiver, abandoned })
+    }
+
+    /// Waits until the render finishes, `timeout` passes, or `cancel` is set.
+    fn wait(&self, timeout: Duration, cancel: &AtomicBool) -> Result<(), PdfExportError> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if cancel.load(Ordering::SeqCst) {
+                return self.abandon(PdfExportError::Cancelled);
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return self.abandon(PdfExportError::TimedOut(timeout));
+            }
+            match self.receiver.recv_timeout(remaining.min(PDF_EXPORT_POLL_INTERVAL)) {
+                Ok(outcome) => return outcome,
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    return Err(PdfExportError::Failed("the PDF renderer panicked".to_string()));
+                }
+            }
+        }
+    }
+
+    /// Stops waiting with `error`, unless the worker already reported its outcome.
+    fn abandon(&self, error: PdfExportError) -> Result<(), PdfExportError> {
+        let mut abandoned = self.abandoned.lock().unwrap();
+        if let Ok(outcome) = self.receiver.try_recv() {
+            return outcome;
+        }
+        *abandoned = true;
+        Err(error)
+    }
+}
+
+/// File formats offered by the export buttons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values ([`export_csv`]).
+    Csv,
+    /// YAML mapping ([`export_yaml`]).
+    Yaml,
+    /// Markdown document ([`export_markdown_to_file`]).
+    Markdown,
+    /// HTML document ([`export_html_to_file`]).
+    Html,
+    /// PDF rendered from the markdown export ([`export_pdf_from_markdown`]).
+    Pdf,
+}
+
+impl ExportFormat {
+    /// Returns the file extension, without the leading dot, added to exported files.
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Yaml => "yaml",
+            ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+            ExportFormat::Pdf => "pdf",
+        }
+    }
+}
+
+/// Exports `metadata` to `path` in the given format.
+///
+/// Returns the path actually written, which has the format's extension added
+/// if it was missing (see [`ensure_extension`]).
+pub fn export_metadata(
+    format: ExportFormat,
+    metadata: &[(&String, &String)],
+    path: &Path,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    export_metadata_with_options(format, metadata, path, &ExportOptions::default())
+}
+
+/// Writes `metadata` in `format` like [`export_metadata`], applying `options`.
+pub fn export_metadata_with_options(
+    format: ExportFormat,
+    metadata: &[(&String, &String)],
+    path: &Path,
+    options: &ExportOptions,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    export_metadata_cancellable(format, metadata, path, options, &AtomicBool::new(false))
+}
+
+/// Key patterns of a model card: the GGUF header fields and the `general.*` keys.
+pub const MODEL_CARD_KEYS: &[&str] = &["version", "tensor_count", "kv_count", "general.*"];
+
+/// Returns `base` restricted to [`MODEL_CARD_KEYS`].
+///
+/// Key selection settings of `base` (include and exclude patterns, filtered-only)
+/// are replaced; format settings such as line endings are kept.
+pub fn model_card_options(base: &ExportOptions) -> ExportOptions {
+    ExportOptions {
+        include_keys: Some(MODEL_CARD_KEYS.iter().map(|pattern| pattern.to_string()).collect()),
+        exclude_keys: Vec::new(),
+        filtered_only: false,
+        ..base.clone()
+    }
+}
+
+/// Exports a lightweight model card: the header fields and `general.*` keys in `format`.
+///
+/// Tokenizer data and architecture internals are left out, which keeps the
+/// result small enough to share. Selection goes through [`filter_keys`] with
+/// [`model_card_options`].
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::{export_model_card, ExportFormat};
+///
+/// let metadata = vec![
+///     ("general.name".to_string(), "Qwen3".to_string()),
+///     ("tokenizer.ggml.model".to_string(), "gpt2".to_string()),
+/// ];
+/// let metadata_refs: Vec<(&String, &String)> = metadata.iter().map(|(k, v)| (k, v)).collect();
+///
+/// let dir = tempfile::tempdir()?;
+/// let written = export_model_card(&metadata_refs, &dir.path().join("card"), ExportFormat::Csv)?;
+/// let content = std::fs::read_to_string(written)?;
+/// assert!(content.contains("general.name") && !content.contains("tokenizer."));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn export_model_card(
+    metadata: &[(&String, &String)],
+    path: &Path,
+    format: ExportFormat,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    export_metadata_with_options(format, metadata, path, &model_card_options(&ExportOptions::default()))
+}
+
+/// Writes `metadata` like [`export_metadata_with_options`]; PDF exports stop early once `cancel` is set.
+fn export_metadata_cancellable(
+    format: ExportFormat,
+    metadata: &[(&String, &String)],
+    path: &Path,
+    options: &ExportOptions,
+    cancel: &AtomicBool,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    match format {
+        ExportFormat::Csv => export_csv_with_options(metadata, path, options)?,
+        ExportFormat::Yaml => export_yaml_with_options(metadata, path, options)?,
+        ExportFormat::Markdown => export_markdown_to_file_with_options(metadata, path, options)?,
+        ExportFormat::Html => export_html_to_file_with_options(metadata, path, options)?,
+        ExportFormat::Pdf => export_pdf_from_markdown_with_timeout(
+            &export_markdown(&filter_keys(metadata, options)),
+            path,
+            PDF_EXPORT_TIMEOUT,
+            cancel,
+        )?,
+    }
+    Ok(ensure_extension(path, format.extension()))
+}
+
+/// Outcome of a background export: the written path or an error message.
+pub type ExportOutcome = Result<PathBuf, String>;
+
+/// Shared container for the result of a background export.
+///
+/// Follows the [`crate::gui::loader::LoadingResult`] pattern: the worker thread
+/// stores the outcome once the export finishes and the UI thread takes it.
+pub type ExportResult = Arc<Mutex<Option<ExportOutcome>>>;
+
+/// Exports `metadata` on a background thread and stores the outcome in `result`.
+///
+/// The metadata is owned so the UI can keep changing its own copy while large
+/// HTML or PDF exports are written.
+pub fn export_metadata_async(
+    format: ExportFormat,
+    metadata: Vec<(String, String)>,
+    path: PathBuf,
+    options: ExportOptions,
+    result: ExportResult,
+) {
+    spawn_export(format, metadata, path, options, result, Arc::default());
+}
+
+/// Runs [`export_metadata_async`] with a flag that cancels PDF exports.
+fn spawn_export(
+    format: ExportFormat,
+    metadata: Vec<(String, String)>,
+    path: PathBuf,
+    options: ExportOptions,
+    result: ExportResult,
+    cancel: Arc<AtomicBool>,
+) {
+    thread::spawn(move || {
+        puffin::profile_scope!("export_thread");
+        let metadata_refs: Vec<(&String, &String)> = metadata.iter().map(|(k, v)| (k, v)).collect();
+        let outcome = export_metadata_cancellable(format, &metadata_refs, &path, &options, &cancel).map_err(|e| e.to_string());
+        *result.lock().unwrap() = Some(outcome);
+    });
+}
+
+/// State of the export running in the background, if any.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::export::{BackgroundExport, ExportFormat};
+///
+/// let dir = std::env::temp_dir();
+/// let metadata = vec![("general.name".to_string(), "Qwen3".to_string())];
+///
+/// let mut export = BackgroundExport::default();
+/// export.start(ExportFormat::Csv, metadata, dir.join("inspector_gguf_doc_export"));
+/// assert!(export.is_running());
+///
+/// let outcome = loop {
+///     if let Some(outcome) = export.poll() {
+///         break outcome;
+///     }
+///     std::thread::sleep(std::time::Duration::from_millis(10));
+/// };
+/// let written = outcome.expect("Export should succeed");
+/// assert!(!export.is_running());
+/// # std::fs::remove_file(written).ok();
+/// ```
+#[derive(Debug, Default)]
+pub struct BackgroundExport {
+    running: bool,
+    result: ExportResult,
+    cancel: Arc<AtomicBool>,
+}
+
+impl BackgroundExport {
+    /// Starts exporting `metadata` to `path` on a worker thread.
+    pub fn start(&mut self, format: ExportFormat, metadata: Vec<(String, String)>, path: PathBuf) {
+        self.start_with_options(format, metadata, path, ExportOptions::default());
+    }
+
+    /// Starts exporting `metadata` to `path` on a worker thread, applying `options`.
+    pub fn start_with_options(
+        &mut self,
+        format: ExportFormat,
+        metadata: Vec<(String, String)>,
+        path: PathBuf,
+        options: ExportOptions,
+    ) {
+        self.running = true;
+        // Fresh container, so an earlier export cannot report as this one
+        self.result = Arc::new(Mutex::new(None));
+        self.cancel = Arc::default();
+        spawn_export(format, metadata, path, options, Arc::clone(&self.result), Arc::clone(&self.cancel));
+    }
+
+    /// Abandons the running export; its outcome is never reported by [`BackgroundExport::poll`].
+    ///
+    /// PDF rendering stops waiting right away and leaves no file behind; the other
+    /// formats are quick and finish writing in the background.
+    pub fn cancel(&mut self) {
+        self.cancel.store(true, Ordering::SeqCst);
+        self.result = Arc::new(Mutex::new(None));
+        self.running = false;
+    }
+
+    /// Returns `true` while an export is being written.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Takes the outcome of a finished export, or `None` while it is still running.
+    pub fn poll(&mut self) -> Option<ExportOutcome> {
+        if !self.running {
+            return None;
+        }
+        let outcome = self.result.lock().unwrap().take()?;
+        self.running = false;
+        Some(outcome)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::fs;
     use std::path::PathBuf;
 
+    #[test]
+    fn test_diff_markdown_prefixes_lines() {
+        let left = vec![
+            ("general.name".to_string(), "Qwen3".to_string()),
+            ("general.license".to_string(), "apache-2.0".to_string()),
+            ("tokenizer.chat_template".to_string(), "{{ a }}".to_string()),
+        ];
+        let right = vec![
+            ("general.name".to_string(), "Qwen3-0.6B".to_string()),
+            ("qwen3.context_length".to_string(), "40960".to_string()),
+            ("tokenizer.chat_template".to_string(), "{{ a }}\n{{ b }}".to_string()),
+        ];
+        let diffs = crate::format::diff_metadata(&left, &right);
+
+        let markdown = export_diff_markdown(&diffs, "a.gguf", "b.gguf");
+        let body: Vec<&str> = markdown
+            .lines()
+            .skip_while(|line| *line != "```diff")
+            .skip(1)
+            .take_while(|line| *line != "```")
+            .collect();
+        assert_eq!(
+            body,
+            [
+                "- general.license: apache-2.0",
+                "~ general.name: Qwen3 -> Qwen3-0.6B",
+                "+ qwen3.context_length: 40960",
+                "~ tokenizer.chat_template: {{ a }} -> {{ a }}\\n{{ b }}",
+            ]
+        );
+        assert!(markdown.starts_with("# Metadata diff\n\n- Left: `a.gguf`\n- Right: `b.gguf`\n"));
+        assert!(export_diff_markdown(&[], "a.gguf", "a.gguf").ends_with("No differences.\n"));
+
+        let json = export_diff_json(&diffs, "a.gguf", "b.gguf");
+        assert_eq!(json["differences"].as_array().map(Vec::len), Some(4));
+        assert_eq!(json["differences"][0]["kind"], "missing");
+    }
+
+    #[test]
+    fn test_llamacpp_style_aligns_on_longest_key() {
+        let keys = ["general.architecture", "tokenizer.ggml.tokens", "qwen3.rope.freq_base", "general.name"]
+            .map(String::from);
+        let values = [
+            gguf_file::Value::String("qwen3".to_string()),
+            gguf_file::Value::Array((0..151936).map(|i| gguf_file::Value::String(format!("t{}", i))).collect()),
+            gguf_file::Value::F32(1_000_000.0),
+            gguf_file::Value::String("Qwen3\n0.6B".to_string()),
+        ];
+        let metadata: Vec<_> = keys.iter().zip(values.iter()).collect();
+
+        let dump = export_llamacpp_style(&metadata);
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(lines.len(), 4);
+        // "tokenizer.ggml.tokens" is the longest key, followed by the 16-wide type column
+        let column = "tokenizer.ggml.tokens".len() + 1 + 16;
+        assert!(lines.iter().all(|line| line.find(" = ") == Some(column)), "{}", dump);
+        assert!(lines[0].starts_with(" general.architecture str "));
+
+        assert!(lines[1].starts_with("tokenizer.ggml.tokens arr[str,151936] "));
+        let value = &lines[1][column + 3..];
+        assert!(value.starts_with(r#"["t0", "t1", "#) && value.ends_with("..."));
+        assert_eq!(value.chars().count(), LLAMACPP_MAX_VALUE_LEN);
+
+        assert!(lines[2].ends_with("f32              = 1000000.000000"));
+        assert!(lines[3].ends_with("= Qwen3\\n0.6B"));
+    }
+
     fn create_test_metadata() -> Vec<(String, String)> {
         vec![
             ("test_key1".to_string(), "test_value1".to_string()),
@@ -403,6 +2029,32 @@ mod tests {
         assert_eq!(result, PathBuf::from("test_file.csv"));
     }
 
+    #[test]
+    fn test_sanitize_filename_windows_reserved_characters() {
+        assert_eq!(sanitize_filename(r#"a<b>c:d"e|f?g*h"#), "a_b_c_d_e_f_g_h");
+        assert_eq!(sanitize_filename("model. . "), "model");
+        assert_eq!(sanitize_filename("nul.gguf"), "nul_.gguf");
+        assert_eq!(sanitize_filename("LPT1"), "LPT1_");
+        assert_eq!(sanitize_filename("Console"), "Console");
+    }
+
+    #[test]
+    fn test_sanitize_filename_path_separators() {
+        assert_eq!(sanitize_filename("../../etc/passwd"), ".._.._etc_passwd");
+        assert_eq!(sanitize_filename(r"C:\models\llama"), "C__models_llama");
+        assert_eq!(sanitize_filename("tab\there"), "tab_there");
+        assert_eq!(sanitize_filename("   "), "_");
+        assert_eq!(export_base_name(Some("///")), "metadata");
+    }
+
+    #[test]
+    fn test_sanitize_filename_truncates_long_names() {
+        let long = "ж".repeat(MAX_FILENAME_CHARS + 50);
+        let sanitized = sanitize_filename(&long);
+        assert_eq!(sanitized.chars().count(), MAX_FILENAME_CHARS);
+        assert!(long.starts_with(&sanitized));
+    }
+
     #[test]
     fn test_ensure_extension_preserves_existing() {
         let path = Path::new("test_file.txt");
@@ -410,6 +2062,227 @@ mod tests {
         assert_eq!(result, PathBuf::from("test_file.txt"));
     }
 
+    #[test]
+    fn test_ensure_extension_respects_compression_suffix() {
+        assert_eq!(ensure_extension(Path::new("tokens.json.gz"), "json"), PathBuf::from("tokens.json.gz"));
+        assert_eq!(ensure_extension(Path::new("tokens.gz"), "json"), PathBuf::from("tokens.json.gz"));
+        assert_eq!(ensure_extension(Path::new("tokens.zst"), "csv"), PathBuf::from("tokens.csv.zst"));
+    }
+
+    #[test]
+    fn test_verified_exports_succeed_and_truncated_files_fail() {
+        let metadata = vec![
+            ("general.name".to_string(), "test-model".to_string()),
+            ("general.architecture".to_string(), "llama".to_string()),
+            ("tokenizer.ggml.model".to_string(), "gpt2".to_string()),
+        ];
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let options = ExportOptions { verify: true, ..Default::default() };
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+
+        for name in ["export.csv", "export.yaml", "export.json", "export.csv.gz"] {
+            let path = temp_dir.path().join(name);
+            let result = if name.starts_with("export.json") {
+                export_json_with_options(&metadata_refs, &path, &options)
+            } else {
+                export_metadata_with_options(
+                    if name.contains(".csv") { ExportFormat::Csv } else { ExportFormat::Yaml },
+                    &metadata_refs,
+                    &path,
+                    &options,
+                ).map(|_| ())
+            };
+            result.unwrap_or_else(|e| panic!("Verified {} export should succeed: {}", name, e));
+            verify_export(&path, metadata.len()).expect("Written export should verify");
+        }
+
+        // Cut each plain export short after it was written
+        for name in ["export.csv", "export.yaml", "export.json"] {
+            let path = temp_dir.path().join(name);
+            let bytes = fs::read(&path).expect("Should read export");
+            let cut = bytes[..bytes.len() / 2].iter().rposition(|&b| b == b'\n').expect("Export has several lines");
+            fs::write(&path, &bytes[..cut]).expect("Should truncate export");
+
+            let err = verify_export(&path, metadata.len()).expect_err("Truncated export should fail verification");
+            assert!(matches!(err, ExportError::VerificationFailed { .. }), "{}: {}", name, err);
+        }
+    }
+
+    #[test]
+    fn test_export_json_gz_roundtrip() {
+        use std::io::Read;
+
+        let tokens = (0..5000).map(|i| format!("token_{}", i)).collect::<Vec<_>>().join(", ");
+        let metadata = vec![
+            ("general.name".to_string(), "test-model".to_string()),
+            ("tokenizer.ggml.tokens".to_string(), tokens),
+        ];
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let raw_path = temp_dir.path().join("export.json");
+        let gz_path = temp_dir.path().join("export.json.gz");
+
+        export_json(&metadata_refs, &raw_path).expect("Raw JSON export should succeed");
+        export_json(&metadata_refs, &gz_path).expect("Compressed JSON export should succeed");
+
+        let raw = fs::read(&raw_path).expect("Should read raw export");
+        let compressed = fs::read(&gz_path).expect("Should read compressed export");
+        assert!(compressed.len() < raw.len(), "Compressed export should be smaller than raw");
+
+        let mut decompressed = Vec::new();
+        flate2::read::GzDecoder::new(compressed.as_slice())
+            .read_to_end(&mut decompressed)
+            .expect("Should decompress gzip export");
+        assert_eq!(decompressed, raw);
+
+        let json: serde_json::Value = serde_json::from_slice(&decompressed).expect("Should parse JSON");
+        assert_eq!(json["raw"]["general.name"], "test-model");
+        assert_eq!(json["keys"][1], "tokenizer.ggml.tokens");
+    }
+
+    fn wait_for_export(export: &mut BackgroundExport) -> ExportOutcome {
+        for _ in 0..500 {
+            if let Some(outcome) = export.poll() {
+                return outcome;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        panic!("Background export did not finish");
+    }
+
+    #[test]
+    fn test_background_export_poll_takes_result_once() {
+        let mut export = BackgroundExport::default();
+        assert!(export.poll().is_none(), "Nothing to report before an export starts");
+
+        export.running = true;
+        assert!(export.poll().is_none(), "Still running until the worker stores a result");
+
+        *export.result.lock().unwrap() = Some(Ok(PathBuf::from("out.csv")));
+        assert_eq!(export.poll(), Some(Ok(PathBuf::from("out.csv"))));
+        assert!(!export.is_running());
+        assert!(export.poll().is_none());
+    }
+
+    #[test]
+    fn test_background_export_writes_file() {
+        let metadata = create_test_metadata();
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let mut export = BackgroundExport::default();
+
+        export.start(ExportFormat::Yaml, metadata, temp_dir.path().join("export"));
+        let written = wait_for_export(&mut export).expect("Export should succeed");
+
+        assert_eq!(written, temp_dir.path().join("export.yaml"));
+        let yaml = fs::read_to_string(&written).expect("Should read export");
+        assert!(yaml.contains("test_key1"));
+    }
+
+    #[test]
+    fn test_background_export_reports_error() {
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let mut export = BackgroundExport::default();
+
+        export.start(ExportFormat::Csv, create_test_metadata(), temp_dir.path().join("missing/dir/export.csv"));
+        let error = wait_for_export(&mut export).expect_err("Export into a missing directory should fail");
+        assert!(!error.is_empty());
+        assert!(!export.is_running());
+    }
+
+    #[test]
+    fn test_export_full_report_sections() {
+        let model = Path::new("model/Qwen3-0.6B-Q5_K_M.gguf");
+        if !model.exists() {
+            eprintln!("Sample model not found at {}, skipping", model.display());
+            return;
+        }
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let out = temp_dir.path().join("report");
+
+        export_full_report(model, &out).expect("Full report export should succeed");
+
+        let written = fs::read(temp_dir.path().join("report.json")).expect("Should read report");
+        let json: serde_json::Value = serde_json::from_slice(&written).expect("Should parse JSON");
+        for section in ["header", "metadata", "tensors", "summary"] {
+            assert!(json.get(section).is_some(), "Report should contain {}", section);
+        }
+        let tensors = json["tensors"].as_array().expect("tensors should be an array");
+        assert!(!tensors.is_empty(), "tensors should not be empty");
+        assert_eq!(json["header"]["tensor_count"], tensors.len() as u64);
+        assert!(json["metadata"].get("general.architecture").is_some());
+    }
+
+    #[test]
+    fn test_export_sqlite_sample_model() {
+        let model = Path::new("model/Qwen3-0.6B-Q5_K_M.gguf");
+        if !model.exists() {
+            eprintln!("Sample model not found at {}, skipping", model.display());
+            return;
+        }
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let db = temp_dir.path().join("models.db");
+        let metadata = crate::format::load_gguf_metadata_sync(model).expect("Sample model should load");
+        let metadata_refs: Vec<(&String, &String)> = metadata.iter().map(|(k, v)| (k, v)).collect();
+
+        export_sqlite("Qwen3-0.6B-Q5_K_M.gguf", &metadata_refs, &db).expect("SQLite export should succeed");
+
+        let conn = rusqlite::Connection::open(&db).expect("Should open database");
+        let architecture: String = conn
+            .query_row(
+                "SELECT value FROM metadata WHERE source = ?1 AND key = 'general.architecture'",
+                ["Qwen3-0.6B-Q5_K_M.gguf"],
+                |row| row.get(0),
+            )
+            .expect("general.architecture should be stored");
+        assert_eq!(architecture, "qwen3");
+        let rows: i64 = conn
+            .query_row("SELECT COUNT(*) FROM metadata", [], |row| row.get(0))
+            .expect("Should count rows");
+        assert_eq!(rows, metadata.len() as i64);
+    }
+
+    #[test]
+    fn test_export_sqlite_accumulates_sources() {
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let db = temp_dir.path().join("models.db");
+        let metadata = create_test_metadata();
+        let metadata_refs = get_test_metadata_refs(&metadata);
+
+        export_sqlite("a.gguf", &metadata_refs, &db).expect("First export should succeed");
+        export_sqlite("b.gguf", &metadata_refs, &db).expect("Second export should succeed");
+
+        let conn = rusqlite::Connection::open(&db).expect("Should open database");
+        let sources: Vec<String> = conn
+            .prepare("SELECT DISTINCT source FROM metadata ORDER BY source")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(sources, ["a.gguf", "b.gguf"]);
+        let rows: i64 = conn
+            .query_row("SELECT COUNT(*) FROM metadata", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(rows, 2 * metadata.len() as i64);
+    }
+
+    #[test]
+    fn test_export_csv_zst_roundtrip() {
+        let metadata = create_test_metadata();
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let zst_path = temp_dir.path().join("export.zst");
+
+        export_csv(&metadata_refs, &zst_path).expect("Compressed CSV export should succeed");
+
+        let written = temp_dir.path().join("export.csv.zst");
+        let compressed = fs::read(&written).expect("Should read compressed export");
+        let decompressed = zstd::decode_all(compressed.as_slice()).expect("Should decompress zstd export");
+        let content = String::from_utf8(decompressed).expect("CSV should be UTF-8");
+        assert!(content.contains("key,value"));
+        assert!(content.contains("test_key1,test_value1"));
+    }
+
     #[test]
     fn test_sanitize_for_markdown() {
         let input = "normal text\nwith newline\tand tab\x00and null\x01and control";
@@ -424,6 +2297,35 @@ mod tests {
         assert_eq!(result, "text with \\*bold\\* and \\_italic\\_ and \\`code\\` and \\[link\\] and \\<tag\\> and \\#header");
     }
 
+    #[test]
+    fn test_markdown_heading_for_empty_key() {
+        let metadata = vec![(String::new(), "value".to_string())];
+        let markdown = export_markdown(&get_test_metadata_refs(&metadata));
+        assert!(markdown.contains("\n## (empty key)\n"), "Got: {}", markdown);
+    }
+
+    #[test]
+    fn test_markdown_heading_for_whitespace_key() {
+        assert_eq!(markdown_heading_text(" \t\n "), EMPTY_KEY_HEADING);
+        assert_eq!(markdown_heading_text("  general.name  "), "general.name");
+        assert_eq!(markdown_heading_text("multi\nline"), "multi line");
+
+        let metadata = vec![("   ".to_string(), "value".to_string())];
+        let markdown = export_markdown(&get_test_metadata_refs(&metadata));
+        assert!(!markdown.contains("##    \n"), "Heading should not be blank");
+        assert!(markdown.contains("## (empty key)\n"));
+    }
+
+    #[test]
+    fn test_markdown_heading_for_unicode_key() {
+        let heading = markdown_heading_text(" модель.名前_v2 ");
+        assert_eq!(heading, "модель.名前\\_v2");
+
+        let metadata = vec![(" модель.名前 ".to_string(), "value".to_string())];
+        let markdown = export_markdown(&get_test_metadata_refs(&metadata));
+        assert!(markdown.contains("\n## модель.名前\n"), "Got: {}", markdown);
+    }
+
     #[test]
     fn test_export_csv_success() {
         let metadata = create_test_metadata();
@@ -449,6 +2351,287 @@ mod tests {
         let _ = fs::remove_file(&test_path);
     }
 
+    #[test]
+    fn test_export_csv_neutralizes_formulas() {
+        let metadata = vec![
+            ("general.name".to_string(), "=SUM(A1)".to_string()),
+            ("general.author".to_string(), "llama-team".to_string()),
+        ];
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let path = temp_dir.path().join("formulas.csv");
+
+        export_csv(&metadata_refs, &path).expect("CSV export should succeed");
+        let mut reader = csv::Reader::from_path(&path).expect("Should read CSV file");
+        let rows: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+
+        assert_eq!(&rows[0][1], "'=SUM(A1)");
+        assert_eq!(&rows[1][1], "llama-team", "Ordinary values are untouched");
+    }
+
+    #[test]
+    fn test_export_csv_sanitizing_can_be_disabled() {
+        let metadata = vec![("general.name".to_string(), "@cmd".to_string())];
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let path = temp_dir.path().join("raw.csv");
+        let options = ExportOptions { sanitize_formulas: false, ..ExportOptions::default() };
+
+        export_csv_with_options(&metadata_refs, &path, &options).expect("CSV export should succeed");
+        let content = fs::read_to_string(&path).expect("Should read CSV file");
+        assert!(content.contains("general.name,@cmd"));
+    }
+
+    #[test]
+    fn test_export_crlf_line_endings() {
+        let metadata = vec![
+            ("general.name".to_string(), "Qwen3".to_string()),
+            ("general.architecture".to_string(), "qwen3".to_string()),
+        ];
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let options = ExportOptions { line_ending: LineEnding::CrLf, ..ExportOptions::default() };
+
+        let csv_path = temp_dir.path().join("crlf.csv");
+        export_csv_with_options(&metadata_refs, &csv_path, &options).expect("CSV export should succeed");
+        let md_path = temp_dir.path().join("crlf.md");
+        export_markdown_to_file_with_options(&metadata_refs, &md_path, &options).expect("Markdown export should succeed");
+        let json_path = temp_dir.path().join("crlf.json");
+        export_json_with_options(&metadata_refs, &json_path, &options).expect("JSON export should succeed");
+
+        for path in [csv_path, md_path, json_path] {
+            let content = fs::read_to_string(&path).expect("Should read export");
+            let lf = content.matches('\n').count();
+            assert!(lf > 0);
+            assert_eq!(content.matches("\r\n").count(), lf, "{} should only have CRLF line endings", path.display());
+        }
+    }
+
+    #[test]
+    fn test_export_lf_line_endings() {
+        let metadata = vec![("general.name".to_string(), "Qwen3".to_string())];
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let options = ExportOptions { line_ending: LineEnding::Lf, ..ExportOptions::default() };
+
+        let csv_path = temp_dir.path().join("lf.csv");
+        export_csv_with_options(&metadata_refs, &csv_path, &options).expect("CSV export should succeed");
+        let md_path = temp_dir.path().join("lf.md");
+        export_markdown_to_file_with_options(&metadata_refs, &md_path, &options).expect("Markdown export should succeed");
+
+        for path in [csv_path, md_path] {
+            let content = fs::read_to_string(&path).expect("Should read export");
+            assert!(content.contains('\n'));
+            assert!(!content.contains('\r'), "{} should only have LF line endings", path.display());
+        }
+    }
+
+    #[test]
+    fn test_crlf_writer_handles_split_writes() {
+        let mut out = Vec::new();
+        write_with_line_ending(LineEnding::CrLf, &mut out, |writer| {
+            writer.write_all(b"a\nb\r")?;
+            writer.write_all(b"\nc\n")
+        })
+        .unwrap();
+        assert_eq!(out, b"a\r\nb\r\nc\r\n");
+    }
+
+    #[test]
+    fn test_neutralize_formula_prefixes() {
+        for value in ["=1+1", "+1+1", "-A1", "-1)", "@SUM(A1)", "\tx", "\rx"] {
+            assert_eq!(neutralize_formula(value, '\''), format!("'{}", value));
+        }
+        assert_eq!(neutralize_formula("1-1", '\''), "1-1");
+        assert_eq!(neutralize_formula("", '\''), "");
+    }
+
+    #[test]
+    fn test_neutralize_formula_keeps_numbers() {
+        for value in ["-1", "+0.5", "-1e-5", "-9223372036854775808"] {
+            assert_eq!(neutralize_formula(value, '\''), value);
+        }
+    }
+
+    fn filter_test_metadata() -> Vec<(String, String)> {
+        vec![
+            ("general.name".to_string(), "Qwen3".to_string()),
+            ("general.architecture".to_string(), "qwen3".to_string()),
+            ("tokenizer.ggml.model".to_string(), "gpt2".to_string()),
+            ("tokenizer.ggml.tokens".to_string(), "[...]".to_string()),
+        ]
+    }
+
+    fn kept_keys(metadata: &[(&String, &String)], options: &ExportOptions) -> Vec<String> {
+        filter_keys(metadata, options).iter().map(|(k, _)| (*k).clone()).collect()
+    }
+
+    #[test]
+    fn test_filter_keys_include_only() {
+        let metadata = filter_test_metadata();
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let options = ExportOptions {
+            include_keys: Some(vec!["general.name".to_string(), "tokenizer.ggml.model".to_string()]),
+            ..ExportOptions::default()
+        };
+
+        assert_eq!(kept_keys(&metadata_refs, &options), ["general.name", "tokenizer.ggml.model"]);
+        assert_eq!(kept_keys(&metadata_refs, &ExportOptions::default()).len(), 4);
+    }
+
+    #[test]
+    fn test_export_options_serde_round_trip() {
+        let options = ExportOptions {
+            sanitize_formulas: false,
+            formula_prefix: '`',
+            include_keys: Some(vec!["general.*".to_string()]),
+            exclude_keys: vec!["general.architecture".to_string()],
+            filtered_only: true,
+            verify: true,
+            line_ending: LineEnding::CrLf,
+            include_provenance: true,
+            source: None,
+        };
+        let json = serde_json::to_string(&options).expect("Options should serialize");
+        let restored: ExportOptions = serde_json::from_str(&json).expect("Options should deserialize");
+        assert_eq!(restored, options);
+
+        // The source file is set per export and never written to the settings
+        let with_source = ExportOptions { source: Some(PathBuf::from("model.gguf")), ..options.clone() };
+        let restored: ExportOptions = serde_json::from_str(&serde_json::to_string(&with_source).unwrap()).unwrap();
+        assert_eq!(restored, options);
+
+        // Fields missing from older settings files fall back to their defaults
+        let partial: ExportOptions = serde_json::from_str(r#"{"filtered_only": true}"#).expect("Partial options should deserialize");
+        assert_eq!(partial, ExportOptions { filtered_only: true, ..ExportOptions::default() });
+    }
+
+    fn metadata_entries(metadata: &[(String, String)]) -> Vec<MetadataEntry> {
+        metadata
+            .iter()
+            .map(|(k, v)| MetadataEntry { key: k.clone(), display_value: v.clone(), full_value: None, raw_bytes: None, string_bytes: None })
+            .collect()
+    }
+
+    #[test]
+    fn test_exported_key_count_applies_filter_and_patterns() {
+        let metadata = metadata_entries(&filter_test_metadata());
+        let mut options = ExportOptions::default();
+
+        assert_eq!(exported_key_count(&metadata, "general", FilterMode::Substring, &options), 4, "The filter is ignored unless filtered_only is set");
+        options.filtered_only = true;
+        assert_eq!(exported_key_count(&metadata, "general", FilterMode::Substring, &options), 2);
+        assert_eq!(exported_key_count(&metadata, "gpt2", FilterMode::Substring, &options), 1, "Values match the filter too");
+        assert_eq!(exported_key_count(&metadata, "", FilterMode::Substring, &options), 4);
+
+        options.exclude_keys = vec!["general.name".to_string()];
+        assert_eq!(exported_key_count(&metadata, "general", FilterMode::Substring, &options), 1);
+        options.include_keys = Some(vec!["tokenizer.*".to_string()]);
+        assert_eq!(exported_key_count(&metadata, "general", FilterMode::Substring, &options), 0);
+    }
+
+    #[test]
+    fn test_filtered_export_matches_numeric_queries() {
+        let mut metadata = filter_test_metadata();
+        metadata.push(("qwen3.context_length".to_string(), "40960".to_string()));
+        metadata.push(("qwen3.block_count".to_string(), "36".to_string()));
+        let metadata = metadata_entries(&metadata);
+        let options = ExportOptions { filtered_only: true, ..Default::default() };
+
+        let exported = entries_for_export(&metadata, ">4096", FilterMode::Substring, &options);
+        let shown = filter_entries(&metadata, ">4096", FilterMode::Substring);
+        assert_eq!(exported.len(), shown.len());
+        assert_eq!(exported[0].0, "qwen3.context_length");
+        assert_eq!(exported_key_count(&metadata, ">4096", FilterMode::Substring, &options), 1);
+    }
+
+    #[test]
+    fn test_filtered_export_matches_fuzzy_queries() {
+        let metadata = metadata_entries(&filter_test_metadata());
+        let options = ExportOptions { filtered_only: true, ..Default::default() };
+
+        let exported = entries_for_export(&metadata, "tkgm", FilterMode::Fuzzy, &options);
+        let shown: Vec<_> = filter_entries(&metadata, "tkgm", FilterMode::Fuzzy)
+            .into_iter()
+            .map(|entry| (&entry.key, &entry.display_value))
+            .collect();
+        assert!(!shown.is_empty());
+        assert_eq!(exported, shown, "The export lists the panel's entries in the panel's order");
+        assert!(entries_for_export(&metadata, "tkgm", FilterMode::Substring, &options).is_empty());
+        assert_eq!(exported_key_count(&metadata, "tkgm", FilterMode::Fuzzy, &options), shown.len());
+    }
+
+    #[test]
+    fn test_filter_keys_exclude() {
+        let metadata = filter_test_metadata();
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let options = ExportOptions {
+            include_keys: Some(vec!["general.*".to_string()]),
+            exclude_keys: vec!["general.architecture".to_string()],
+            ..ExportOptions::default()
+        };
+
+        assert_eq!(kept_keys(&metadata_refs, &options), ["general.name"]);
+    }
+
+    #[test]
+    fn test_filter_keys_glob_prefix() {
+        let metadata = filter_test_metadata();
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let options = ExportOptions {
+            exclude_keys: vec!["tokenizer.*".to_string()],
+            ..ExportOptions::default()
+        };
+
+        assert_eq!(kept_keys(&metadata_refs, &options), ["general.name", "general.architecture"]);
+        assert!(key_matches("*", "anything"));
+        assert!(key_matches("tokenizer.*.tokens", "tokenizer.ggml.tokens"));
+        assert!(!key_matches("tokenizer.*", "tokenizer"));
+        assert!(!key_matches("general.name", "general.name.extra"));
+    }
+
+    #[test]
+    fn test_export_csv_drops_excluded_keys() {
+        let metadata = filter_test_metadata();
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let path = temp_dir.path().join("filtered.csv");
+        let options = ExportOptions {
+            exclude_keys: vec!["tokenizer.*".to_string()],
+            ..ExportOptions::default()
+        };
+
+        export_csv_with_options(&metadata_refs, &path, &options).expect("CSV export should succeed");
+        let content = fs::read_to_string(&path).expect("Should read CSV file");
+        assert!(content.contains("general.name"));
+        assert!(!content.contains("tokenizer."));
+    }
+
+    #[test]
+    fn test_export_model_card_keeps_header_and_general_keys() {
+        let mut metadata = filter_test_metadata();
+        metadata.insert(0, ("version".to_string(), "3".to_string()));
+        metadata.push(("qwen3.block_count".to_string(), "28".to_string()));
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+
+        let path = export_model_card(&metadata_refs, &temp_dir.path().join("card"), ExportFormat::Markdown)
+            .expect("Model card export should succeed");
+        assert_eq!(path.extension().unwrap(), "md");
+        let content = fs::read_to_string(&path).expect("Should read model card");
+        assert!(content.contains("## general.name"));
+        assert!(content.contains("## general.architecture"));
+        assert!(content.contains("## version"));
+        assert!(!content.contains("tokenizer.ggml.tokens"));
+        assert!(!content.contains("tokenizer"));
+        assert!(!content.contains("qwen3.block"));
+
+        // Key selection of the base options does not leak into the card
+        let base = ExportOptions { exclude_keys: vec!["general.*".to_string()], filtered_only: true, ..ExportOptions::default() };
+        let kept = filter_keys(&metadata_refs, &model_card_options(&base));
+        assert_eq!(kept.len(), 3);
+    }
+
     #[test]
     fn test_export_yaml_success() {
         let metadata = create_test_metadata();
@@ -486,6 +2669,88 @@ mod tests {
         assert!(result.contains("```\ntest_value1\n```"), "Should format simple values");
     }
 
+    #[test]
+    fn test_export_markdown_streaming_matches_in_memory() {
+        let mut metadata = create_test_metadata();
+        metadata.push(("tokenizer.ggml.tokens".to_string(), "token, ".repeat(2000)));
+        let metadata_refs = get_test_metadata_refs(&metadata);
+
+        let mut sink: Vec<u8> = Vec::new();
+        export_markdown_streaming(&metadata_refs, &mut sink).expect("Streaming to a Vec should succeed");
+        assert_eq!(String::from_utf8(sink).unwrap(), export_markdown(&metadata_refs));
+    }
+
+    #[test]
+    fn test_export_markdown_front_matter() {
+        let metadata = create_test_metadata();
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let source = temp_dir.path().join("model #1.gguf");
+        fs::write(&source, b"abc").unwrap();
+        let mut options = ExportOptions { source: Some(source.clone()), ..ExportOptions::default() };
+
+        let plain_path = temp_dir.path().join("plain.md");
+        export_markdown_to_file_with_options(&metadata_refs, &plain_path, &options).expect("Markdown export should succeed");
+        assert!(fs::read_to_string(&plain_path).unwrap().starts_with("# GGUF Metadata"), "No front matter unless enabled");
+
+        options.include_provenance = true;
+        let path = temp_dir.path().join("provenance.md");
+        export_markdown_to_file_with_options(&metadata_refs, &path, &options).expect("Markdown export should succeed");
+        let content = fs::read_to_string(&path).unwrap();
+        let rest = content.strip_prefix("---\n").expect("Front matter should open the document");
+        let (yaml, body) = rest.split_once("\n---\n\n").expect("Front matter should be closed");
+        assert!(body.starts_with("# GGUF Metadata"));
+
+        let provenance: Provenance = serde_yaml::from_str(yaml).expect("Front matter should be valid YAML");
+        assert_eq!(provenance.source, std::path::absolute(&source).unwrap().to_string_lossy());
+        // SHA-256 of "abc"
+        assert_eq!(provenance.sha256, "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        assert!(chrono::DateTime::parse_from_rfc3339(&provenance.exported_at).is_ok());
+    }
+
+    #[test]
+    fn test_export_html_streaming_matches_whole_document() {
+        let mut metadata = create_test_metadata();
+        metadata.push(("tokenizer.ggml.tokens".to_string(), "token, ".repeat(2000)));
+        let metadata_refs = get_test_metadata_refs(&metadata);
+
+        let mut sink: Vec<u8> = Vec::new();
+        export_html_streaming(&metadata_refs, &mut sink).expect("Streaming to a Vec should succeed");
+
+        // Rendering the full markdown document at once must give the same HTML
+        let md = export_markdown(&metadata_refs);
+        let mut expected = String::new();
+        pulldown_cmark::html::push_html(&mut expected, pulldown_cmark::Parser::new(&md));
+        assert_eq!(String::from_utf8(sink).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_export_markdown_table_is_valid_gfm() {
+        let mut metadata = create_test_metadata();
+        metadata.push(("tokenizer.ggml.tokens".to_string(), "token, ".repeat(500)));
+        metadata.push(("pipe_key".to_string(), "a | b".to_string()));
+        let metadata_refs = get_test_metadata_refs(&metadata);
+        let filtered: Vec<_> = metadata_refs.iter().copied().filter(|(k, _)| k.contains("key")).collect();
+
+        let table = export_markdown_table(&filtered, MARKDOWN_TABLE_VALUE_LIMIT);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines.len(), 2 + filtered.len(), "Header + separator + one row per entry");
+        assert_eq!(lines[0], "| Key | Value |");
+        assert_eq!(lines[1], "| --- | --- |");
+        for row in &lines[2..] {
+            assert!(row.starts_with("| ") && row.ends_with(" |"));
+            // Exactly three unescaped pipes: two borders and the column separator
+            let unescaped = row.replace("\\|", "");
+            assert_eq!(unescaped.matches('|').count(), 3, "Bad row: {}", row);
+        }
+        assert!(table.contains("| `pipe_key` | a \\| b |"));
+        assert!(table.contains("test_value2 with newlines"));
+
+        let long = export_markdown_table(&metadata_refs, 10);
+        assert!(long.contains("| `tokenizer.ggml.tokens` | token, tok… |"));
+    }
+
     #[test]
     fn test_export_markdown_to_file_success() {
         let metadata = create_test_metadata();
@@ -620,6 +2885,64 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pdf_renderer_errors_are_mapped() {
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let path = temp_dir.path().join("tokens.pdf");
+        let cancel = AtomicBool::new(false);
+
+        // Raw error of markdown2pdf's lexer for a stray table pipe
+        let result = render_pdf_with_timeout(&path, PDF_EXPORT_TIMEOUT, &cancel, |_| {
+            Err("Lexer error: Unexpected token '|' at position 12".to_string())
+        });
+        let error = result.unwrap_err();
+        assert!(matches!(error, PdfExportError::UnexpectedToken(_)));
+        assert!(error.to_string().contains("try exporting to HTML"), "Got: {}", error);
+
+        assert!(matches!(
+            PdfExportError::from_renderer_message("Unsupported element: footnote"),
+            PdfExportError::UnsupportedElement(_)
+        ));
+        assert!(matches!(PdfExportError::from_renderer_message("disk full"), PdfExportError::Failed(_)));
+        assert!(!path.exists());
+    }
+
+    /// Spawns a render that writes a stub PDF once `gate` receives a message.
+    fn gated_pdf_render(path: &Path) -> (PdfRender, mpsc::Sender<()>) {
+        let (release, gate) = mpsc::channel::<()>();
+        let render = PdfRender::spawn(path, move |part_path| {
+            gate.recv().map_err(|e| e.to_string())?;
+            std::fs::write(part_path, b"%PDF-1.4").map_err(|e| e.to_string())
+        })
+        .expect("Render should start");
+        (render, release)
+    }
+
+    #[test]
+    fn test_pdf_render_timeout_and_cancel() {
+        let temp_dir = tempfile::tempdir().expect("Should create temp dir");
+        let path = temp_dir.path().join("slow.pdf");
+
+        let (render, release) = gated_pdf_render(&path);
+        assert!(matches!(render.wait(Duration::ZERO, &AtomicBool::new(false)), Err(PdfExportError::TimedOut(_))));
+        release.send(()).unwrap();
+        // The worker reports the discarded render after removing its partial file
+        assert!(matches!(render.receiver.recv().unwrap(), Err(PdfExportError::Cancelled)));
+
+        let (render, release) = gated_pdf_render(&path);
+        assert!(matches!(render.wait(PDF_EXPORT_TIMEOUT, &AtomicBool::new(true)), Err(PdfExportError::Cancelled)));
+        release.send(()).unwrap();
+        assert!(matches!(render.receiver.recv().unwrap(), Err(PdfExportError::Cancelled)));
+
+        assert!(!path.exists());
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 0, "No partial file is left behind");
+
+        let (render, release) = gated_pdf_render(&path);
+        release.send(()).unwrap();
+        render.wait(PDF_EXPORT_TIMEOUT, &AtomicBool::new(false)).expect("Render should finish");
+        assert_eq!(fs::read(&path).unwrap(), b"%PDF-1.4");
+    }
+
     #[test]
     fn test_large_data_handling() {
         // Test with large data that should trigger base64 encoding
@@ -0,0 +1,321 @@
+//! Syntax highlighting for the right-side content viewers.
+//!
+//! [`detect_content_kind`] tells JSON (text that parses as a JSON object or array)
+//! from Jinja chat templates (text containing `{{`, `{%` or `{#`). [`highlight_spans`]
+//! splits the text into `(range, role)` spans with a small lexer, and
+//! [`highlight_layout`] turns them into an [`egui::text::LayoutJob`] colored by
+//! [`role_color`]. The lexers never fail: malformed input is simply colored as
+//! far as it makes sense.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use inspector_gguf::gui::highlight::{detect_content_kind, highlight_spans, ColorRole, ContentKind};
+//!
+//! let text = r#"{"bos": "<s>"}"#;
+//! assert_eq!(detect_content_kind(text), ContentKind::Json);
+//!
+//! let spans = highlight_spans(text, ContentKind::Json);
+//! assert_eq!(spans[0], (0..1, ColorRole::Delimiter));
+//! assert_eq!(spans[1], (1..6, ColorRole::Key));
+//! ```
+
+use std::ops::Range;
+use eframe::egui;
+use egui::text::{LayoutJob, TextFormat};
+use crate::gui::theme::{SUCCESS_GREEN, TECH_GRAY};
+
+/// Longest text that is highlighted; the viewers rebuild the layout every frame,
+/// so larger texts (such as full token lists) are shown plain.
+pub const MAX_HIGHLIGHT_LEN: usize = 256 * 1024;
+
+/// Words colored as keywords inside Jinja `{{ }}` and `{% %}` tags.
+const JINJA_KEYWORDS: &[&str] = &[
+    "and", "block", "break", "call", "continue", "elif", "else", "endblock", "endcall", "endfilter",
+    "endfor", "endif", "endmacro", "endraw", "endset", "endwith", "extends", "false", "False", "filter",
+    "for", "if", "import", "in", "include", "is", "macro", "none", "None", "not", "or", "raw",
+    "recursive", "set", "true", "True", "with",
+];
+
+/// Syntax used to highlight a viewer's content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentKind {
+    /// A JSON object or array
+    Json,
+    /// A Jinja template, such as `tokenizer.chat_template`
+    Jinja,
+    /// Anything else; shown without highlighting
+    Plain,
+}
+
+/// What a highlighted span is; mapped to a color by [`role_color`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorRole {
+    /// Text without special meaning
+    Plain,
+    /// JSON object key
+    Key,
+    /// String literal
+    String,
+    /// Jinja keyword or JSON `true`/`false`/`null`
+    Keyword,
+    /// JSON punctuation or Jinja tag delimiter such as `{{` and `-%}`
+    Delimiter,
+    /// Jinja `{# ... #}` comment
+    Comment,
+}
+
+/// Guesses the syntax of `text` for [`highlight_spans`].
+///
+/// Texts longer than [`MAX_HIGHLIGHT_LEN`] are always [`ContentKind::Plain`].
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::highlight::{detect_content_kind, ContentKind};
+///
+/// assert_eq!(detect_content_kind(r#"["<s>", "</s>"]"#), ContentKind::Json);
+/// assert_eq!(detect_content_kind("{% for m in messages %}{{ m.content }}{% endfor %}"), ContentKind::Jinja);
+/// assert_eq!(detect_content_kind("qwen3"), ContentKind::Plain);
+/// ```
+pub fn detect_content_kind(text: &str) -> ContentKind {
+    if text.len() > MAX_HIGHLIGHT_LEN {
+        return ContentKind::Plain;
+    }
+    let trimmed = text.trim_start();
+    if (trimmed.starts_with('{') || trimmed.starts_with('['))
+        && serde_json::from_str::<serde::de::IgnoredAny>(text).is_ok()
+    {
+        return ContentKind::Json;
+    }
+    if ["{{", "{%", "{#"].iter().any(|open| text.contains(open)) {
+        ContentKind::Jinja
+    } else {
+        ContentKind::Plain
+    }
+}
+
+/// Splits `text` into highlighted spans.
+///
+/// The spans are byte ranges in order, cover the whole text without gaps, and
+/// adjacent spans never share a role.
+pub fn highlight_spans(text: &str, kind: ContentKind) -> Vec<(Range<usize>, ColorRole)> {
+    let mut spans = Vec::new();
+    match kind {
+        ContentKind::Json => lex_json(text.as_bytes(), &mut spans),
+        ContentKind::Jinja => lex_jinja(text, &mut spans),
+        ContentKind::Plain => push_span(&mut spans, 0..text.len(), ColorRole::Plain),
+    }
+    spans
+}
+
+/// Returns the color of `role` in the current theme.
+pub fn role_color(role: ColorRole, visuals: &egui::Visuals) -> egui::Color32 {
+    match role {
+        ColorRole::Plain => TECH_GRAY,
+        // GADGET_YELLOW in the Inspector theme
+        ColorRole::Key => visuals.hyperlink_color,
+        ColorRole::String => SUCCESS_GREEN,
+        ColorRole::Keyword => visuals.strong_text_color(),
+        ColorRole::Delimiter => visuals.warn_fg_color,
+        ColorRole::Comment => visuals.weak_text_color(),
+    }
+}
+
+/// Builds the monospace layout of `text` shown by the content viewers.
+pub fn highlight_layout(text: &str, kind: ContentKind, font_id: egui::FontId, visuals: &egui::Visuals) -> LayoutJob {
+    let mut job = LayoutJob::default();
+    for (range, role) in highlight_spans(text, kind) {
+        job.append(&text[range], 0.0, TextFormat::simple(font_id.clone(), role_color(role, visuals)));
+    }
+    job
+}
+
+/// Appends a span, merging it into the previous one when the role is the same.
+fn push_span(spans: &mut Vec<(Range<usize>, ColorRole)>, range: Range<usize>, role: ColorRole) {
+    if range.is_empty() {
+        return;
+    }
+    match spans.last_mut() {
+        Some((last, last_role)) if *last_role == role && last.end == range.start => last.end = range.end,
+        _ => spans.push((range, role)),
+    }
+}
+
+/// Returns the index after the string literal opened by the quote at `start`.
+fn string_end(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b if b == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Returns the index after the identifier starting at `start`.
+fn word_end(bytes: &[u8], start: usize) -> usize {
+    bytes[start..]
+        .iter()
+        .position(|b| !b.is_ascii_alphanumeric() && *b != b'_')
+        .map_or(bytes.len(), |p| start + p)
+}
+
+fn lex_json(bytes: &[u8], spans: &mut Vec<(Range<usize>, ColorRole)>) {
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let role = match bytes[i] {
+            b'"' => {
+                i = string_end(bytes, i);
+                // A string followed by `:` is an object key
+                let next = bytes[i..].iter().find(|b| !b.is_ascii_whitespace());
+                if next == Some(&b':') { ColorRole::Key } else { ColorRole::String }
+            }
+            b'{' | b'}' | b'[' | b']' | b':' | b',' => {
+                i += 1;
+                ColorRole::Delimiter
+            }
+            // Numbers stay plain; this also keeps exponents such as `1e5` out of keywords
+            b'-' | b'0'..=b'9' => {
+                i += 1 + bytes[i + 1..]
+                    .iter()
+                    .take_while(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'+' | b'-'))
+                    .count();
+                ColorRole::Plain
+            }
+            b if b.is_ascii_alphabetic() => {
+                i = word_end(bytes, i);
+                ColorRole::Keyword
+            }
+            _ => {
+                i += 1;
+                ColorRole::Plain
+            }
+        };
+        push_span(spans, start..i, role);
+    }
+}
+
+fn lex_jinja(text: &str, spans: &mut Vec<(Range<usize>, ColorRole)>) {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let Some(open) = (i..bytes.len() - 1)
+            .find(|&j| bytes[j] == b'{' && matches!(bytes[j + 1], b'{' | b'%' | b'#'))
+        else {
+            push_span(spans, i..bytes.len(), ColorRole::Plain);
+            return;
+        };
+        push_span(spans, i..open, ColorRole::Plain);
+
+        if bytes[open + 1] == b'#' {
+            i = text[open + 2..].find("#}").map_or(bytes.len(), |p| open + 2 + p + 2);
+            push_span(spans, open..i, ColorRole::Comment);
+            continue;
+        }
+        let close: &[u8] = if bytes[open + 1] == b'{' { b"}}" } else { b"%}" };
+        i = open + 2;
+        // Whitespace control markers belong to the delimiter: `{%-`, `-%}`
+        if matches!(bytes.get(i), Some(b'-' | b'+')) {
+            i += 1;
+        }
+        push_span(spans, open..i, ColorRole::Delimiter);
+
+        while i < bytes.len() {
+            let trim = usize::from(matches!(bytes[i], b'-' | b'+'));
+            if bytes[i + trim..].starts_with(close) {
+                push_span(spans, i..i + trim + 2, ColorRole::Delimiter);
+                i += trim + 2;
+                break;
+            }
+            let start = i;
+            let role = match bytes[i] {
+                b'\'' | b'"' => {
+                    i = string_end(bytes, i);
+                    ColorRole::String
+                }
+                b if b.is_ascii_alphabetic() || b == b'_' => {
+                    i = word_end(bytes, i);
+                    if JINJA_KEYWORDS.contains(&&text[start..i]) { ColorRole::Keyword } else { ColorRole::Plain }
+                }
+                _ => {
+                    i += 1;
+                    ColorRole::Plain
+                }
+            };
+            push_span(spans, start..i, role);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that the spans tile `text` and returns the highlighted (non-plain) parts.
+    fn highlighted(text: &str, kind: ContentKind) -> Vec<(&str, ColorRole)> {
+        let spans = highlight_spans(text, kind);
+        let mut end = 0;
+        for (range, _) in &spans {
+            assert_eq!(range.start, end, "Spans should have no gaps");
+            end = range.end;
+        }
+        assert_eq!(end, text.len());
+        spans
+            .into_iter()
+            .filter(|(_, role)| *role != ColorRole::Plain)
+            .map(|(range, role)| (&text[range], role))
+            .collect()
+    }
+
+    #[test]
+    fn test_json_spans() {
+        let text = r#"{"name": "qwen", "ids": [1e-5, true]}"#;
+        assert_eq!(detect_content_kind(text), ContentKind::Json);
+        assert_eq!(
+            highlighted(text, ContentKind::Json),
+            [
+                ("{", ColorRole::Delimiter),
+                ("\"name\"", ColorRole::Key),
+                (":", ColorRole::Delimiter),
+                ("\"qwen\"", ColorRole::String),
+                (",", ColorRole::Delimiter),
+                ("\"ids\"", ColorRole::Key),
+                (":", ColorRole::Delimiter),
+                ("[", ColorRole::Delimiter),
+                (",", ColorRole::Delimiter),
+                ("true", ColorRole::Keyword),
+                ("]}", ColorRole::Delimiter),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jinja_spans() {
+        let text = "Привет {%- if name == 'x' %}{{ name }}{# note #}";
+        assert_eq!(detect_content_kind(text), ContentKind::Jinja);
+        assert_eq!(
+            highlighted(text, ContentKind::Jinja),
+            [
+                ("{%-", ColorRole::Delimiter),
+                ("if", ColorRole::Keyword),
+                ("'x'", ColorRole::String),
+                ("%}{{", ColorRole::Delimiter),
+                ("}}", ColorRole::Delimiter),
+                ("{# note #}", ColorRole::Comment),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_content_kind_falls_back_to_plain() {
+        assert_eq!(detect_content_kind("{{ not json"), ContentKind::Jinja);
+        assert_eq!(detect_content_kind("{\"unterminated\": "), ContentKind::Plain);
+        assert_eq!(detect_content_kind(&"[1]".repeat(MAX_HIGHLIGHT_LEN)), ContentKind::Plain);
+        assert!(highlighted("", ContentKind::Jinja).is_empty());
+    }
+}
@@ -142,6 +142,8 @@ pub fn get_sidebar_width(ctx: &egui::Context) -> f32 {
 /// - **Standard Screens (1024px+)**: 1.0x scale (base size) for standard displays
 /// - **Small Screens (<1024px)**: 0.9x scale (10% smaller) for mobile devices
 ///
+/// The result is then multiplied by the user's font scale (see [`set_font_scale`]).
+///
 /// # Parameters
 ///
 /// * `base_size` - The base font size in pixels before scaling
@@ -197,7 +199,27 @@ pub fn get_adaptive_font_size(base_size: f32, ctx: &egui::Context) -> f32 {
     } else {
         0.9 // Уменьшаем на 10% для маленьких экранов
     };
-    base_size * scale_factor
+    base_size * scale_factor * font_scale(ctx)
+}
+
+/// Range accepted by [`set_font_scale`].
+pub const FONT_SCALE_RANGE: std::ops::RangeInclusive<f32> = 0.5..=2.0;
+
+fn font_scale_id() -> egui::Id {
+    egui::Id::new("inspector_font_scale")
+}
+
+/// Sets the multiplier [`get_adaptive_font_size`] applies on top of screen scaling.
+///
+/// The value is clamped to [`FONT_SCALE_RANGE`]; non-finite values reset it to `1.0`.
+pub fn set_font_scale(ctx: &egui::Context, scale: f32) {
+    let scale = if scale.is_finite() { scale.clamp(*FONT_SCALE_RANGE.start(), *FONT_SCALE_RANGE.end()) } else { 1.0 };
+    ctx.data_mut(|d| d.insert_temp(font_scale_id(), scale));
+}
+
+/// Returns the multiplier set with [`set_font_scale`] (`1.0` until it is set).
+pub fn font_scale(ctx: &egui::Context) -> f32 {
+    ctx.data(|d| d.get_temp(font_scale_id())).unwrap_or(1.0)
 }
 
 /// Calculates adaptive button width based on text content and constraints.
@@ -20,7 +20,7 @@
 //! 1. **File Opening** (0-5%): Initial file access and validation
 //! 2. **Reading** (5-80%): Chunked file reading with real-time updates
 //! 3. **Parsing** (80-95%): GGUF format parsing and validation
-//! 4. **Processing** (95-100%): Metadata extraction and formatting
+//! 4. **Processing** (95-100%): Metadata extraction and formatting, advanced per entry
 //!
 //! # Usage
 //!
@@ -43,19 +43,31 @@
 //! if current_progress >= 1.0 {
 //!     if let Some(load_result) = result.lock().unwrap().take() {
 //!         match load_result {
-//!             Ok(metadata) => println!("Loaded {} entries", metadata.len()),
+//!             Ok(file) => println!("Loaded {} entries", file.entries.len()),
 //!             Err(e) => eprintln!("Loading failed: {}", e),
 //!         }
 //!     }
 //! }
 //! ```
 
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Read;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
-use crate::format::{readable_value_for_key, get_full_tokenizer_content};
+use candle::quantized::gguf_file;
+use crate::gui::cache::{hash_bytes, SharedMetadataCache};
+use crate::format::{check_gguf_size, check_header_counts, GgufError, ModelReport, known_config_raw_value, readable_value_for_key_with_limit, get_full_tokenizer_content, image_data_uri, value_to_raw_bytes, scan_directory_with_progress, FileScanResult, DEFAULT_PREVIEW_LIMIT};
+
+/// Progress value at which the metadata-processing phase starts.
+const PROCESSING_PROGRESS_START: f32 = 0.95;
+
+/// Formatted values larger than this (in bytes) are reported on stderr in debug
+/// builds, since a single huge value cannot be reflected in the progress bar.
+#[cfg(debug_assertions)]
+const LARGE_VALUE_THRESHOLD: usize = 1024 * 1024;
 
 /// Type alias for thread-safe loading result container.
 ///
@@ -65,9 +77,40 @@ use crate::format::{readable_value_for_key, get_full_tokenizer_content};
 ///
 /// - **Arc<Mutex<...>>**: Thread-safe shared ownership
 /// - **Option<...>**: Indicates whether a result is available
-/// - **Result<Vec<...>, String>**: Success with metadata or error with message
-/// - **Vec<(String, String, `Option<String>`)>**: Metadata entries with key, display value, and optional full content
-pub type LoadingResult = Arc<Mutex<Option<Result<Vec<(String, String, Option<String>)>, String>>>>;
+/// - **Result<[`LoadedFile`], LoadError>**: Success with metadata or the reason the load failed
+pub type LoadingResult = Arc<Mutex<Option<LoadOutcome>>>;
+
+/// Outcome of a single load: the loaded file or the reason it failed.
+pub type LoadOutcome = Result<LoadedFile, LoadError>;
+
+/// Everything a load produces, built on the worker thread.
+///
+/// The report is derived from the content the loader already parsed, so the
+/// UI thread never reopens or reparses the file.
+#[derive(Clone, Default)]
+pub struct LoadedFile {
+    /// Formatted metadata entries, in file order.
+    pub entries: Vec<MetadataEntry>,
+    /// Model summary, size estimate and header count check of the file.
+    pub report: ModelReport,
+}
+
+/// Why a background load failed.
+///
+/// The GUI translates the error when it is shown, so the classification does
+/// not depend on message wording.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LoadError {
+    /// The file is empty, truncated or not a GGUF file
+    #[error(transparent)]
+    Format(#[from] GgufError),
+    /// The file could not be opened or read
+    #[error("{0}")]
+    Io(String),
+    /// Candle could not parse the GGUF contents
+    #[error("{0}")]
+    Parse(String),
+}
 
 /// Represents a single metadata entry from a GGUF file.
 ///
@@ -80,6 +123,8 @@ pub type LoadingResult = Arc<Mutex<Option<Result<Vec<(String, String, Option<Str
 /// * `key` - The metadata key identifier (e.g., "model.name", "tokenizer.chat_template")
 /// * `display_value` - Formatted value optimized for UI display (may be truncated or summarized)
 /// * `full_value` - Complete original value for detailed viewing (None if same as display_value)
+/// * `raw_bytes` - Exact bytes of byte-array (`U8`) values, for exporting them unchanged
+/// * `string_bytes` - Bytes of string values as stored in the file, for the hex view
 ///
 /// # Examples
 ///
@@ -91,6 +136,8 @@ pub type LoadingResult = Arc<Mutex<Option<Result<Vec<(String, String, Option<Str
 ///     key: "model.name".to_string(),
 ///     display_value: "llama-7b-chat".to_string(),
 ///     full_value: None, // Same as display value
+///     raw_bytes: None,
+///     string_bytes: None,
 /// };
 ///
 /// // Large content with separate display and full values
@@ -98,6 +145,8 @@ pub type LoadingResult = Arc<Mutex<Option<Result<Vec<(String, String, Option<Str
 ///     key: "tokenizer.chat_template".to_string(),
 ///     display_value: "Large template content...".to_string(),
 ///     full_value: Some("Full template content here...".to_string()),
+///     raw_bytes: None,
+///     string_bytes: None,
 /// };
 /// ```
 #[derive(Clone)]
@@ -108,6 +157,10 @@ pub struct MetadataEntry {
     pub display_value: String,
     /// Complete original value for detailed viewing (None if same as display_value).
     pub full_value: Option<String>,
+    /// Raw contents of byte-array values (None for every other type).
+    pub raw_bytes: Option<Vec<u8>>,
+    /// UTF-8 bytes of string values (None for every other type).
+    pub string_bytes: Option<Vec<u8>>,
 }
 
 /// Loads GGUF metadata asynchronously with progress tracking.
@@ -121,7 +174,8 @@ pub struct MetadataEntry {
 /// 1. **File Validation** (0-5%): Opens and validates file access
 /// 2. **Chunked Reading** (5-80%): Reads file in 256KB chunks with progress updates
 /// 3. **GGUF Parsing** (80-95%): Parses GGUF format using Candle library
-/// 4. **Metadata Processing** (95-100%): Extracts and formats metadata entries
+/// 4. **Metadata Processing** (95-100%): Extracts and formats metadata entries,
+///    advancing progress proportionally to the number of entries processed
 ///
 /// # Progress Reporting
 ///
@@ -170,7 +224,7 @@ pub struct MetadataEntry {
 ///     } else if current_progress >= 1.0 {
 ///         if let Some(load_result) = result.lock().unwrap().take() {
 ///             match load_result {
-///                 Ok(metadata) => println!("Loaded {} entries", metadata.len()),
+///                 Ok(file) => println!("Loaded {} entries", file.entries.len()),
 ///                 Err(e) => println!("Error: {}", e),
 ///             }
 ///         }
@@ -189,6 +243,7 @@ pub struct MetadataEntry {
 /// and the result container (Err variant). Common error scenarios include:
 ///
 /// - File not found or inaccessible
+/// - Empty or truncated file ([`LoadError::Format`])
 /// - Invalid GGUF format
 /// - Insufficient memory for large files
 /// - I/O errors during reading
@@ -196,13 +251,73 @@ pub fn load_gguf_metadata_async(
     path: std::path::PathBuf,
     progress: Arc<Mutex<f32>>,
     result: LoadingResult,
+) {
+    load_gguf_metadata_async_with_preview(path, progress, result, DEFAULT_PREVIEW_LIMIT);
+}
+
+/// Loads GGUF metadata asynchronously with a custom tokenizer preview length.
+///
+/// Identical to [`load_gguf_metadata_async`], except that display values of token
+/// and merge arrays show `preview_limit` items (`0` shows all) as described in
+/// [`crate::format::readable_value_for_key_with_limit`]. Full values used for
+/// export are unaffected.
+pub fn load_gguf_metadata_async_with_preview(
+    path: std::path::PathBuf,
+    progress: Arc<Mutex<f32>>,
+    result: LoadingResult,
+    preview_limit: usize,
+) {
+    let ticket = LoadTracker::default().begin(&result);
+    load_gguf_metadata_async_tracked(path, progress, result, preview_limit, ticket);
+}
+
+/// Loads GGUF metadata asynchronously as the load identified by `ticket`.
+///
+/// Identical to [`load_gguf_metadata_async_with_preview`], but progress and the
+/// final result are only published while `ticket` is still current. Once the
+/// [`LoadTracker`] that issued the ticket begins a newer load, this thread stops
+/// at the next checkpoint and its result is discarded, so the most recent load
+/// always wins.
+pub fn load_gguf_metadata_async_tracked(
+    path: std::path::PathBuf,
+    progress: Arc<Mutex<f32>>,
+    result: LoadingResult,
+    preview_limit: usize,
+    ticket: LoadTicket,
+) {
+    load_gguf_metadata_async_cached(path, progress, result, preview_limit, ticket, None);
+}
+
+/// Loads GGUF metadata asynchronously, reusing `cache` for files seen before.
+///
+/// Identical to [`load_gguf_metadata_async_tracked`] when `cache` is `None`.
+/// Otherwise the file contents are hashed once read; on a cache hit the stored
+/// metadata is published without parsing, and on a miss the freshly parsed
+/// metadata is added to the cache.
+pub fn load_gguf_metadata_async_cached(
+    path: std::path::PathBuf,
+    progress: Arc<Mutex<f32>>,
+    result: LoadingResult,
+    preview_limit: usize,
+    ticket: LoadTicket,
+    cache: Option<SharedMetadataCache>,
 ) {
     puffin::profile_scope!("load_gguf_metadata_async");
 
     thread::spawn(move || {
         puffin::profile_scope!("file_loading_thread");
+        let set_progress = |value: f32| {
+            if ticket.is_current() {
+                *progress.lock().unwrap() = value;
+            }
+        };
+        let fail = |error: LoadError| {
+            set_progress(-1.0);
+            ticket.publish(&result, Err(error));
+        };
+
         // Start loading
-        *progress.lock().unwrap() = 0.0;
+        set_progress(0.0);
 
         // Try to open file
         let mut f = {
@@ -210,8 +325,7 @@ pub fn load_gguf_metadata_async(
             match File::open(&path) {
                 Ok(file) => file,
                 Err(e) => {
-                    *progress.lock().unwrap() = -1.0;
-                    *result.lock().unwrap() = Some(Err(format!("Не удалось открыть файл: {}", e)));
+                    fail(LoadError::Io(e.to_string()));
                     return;
                 }
             }
@@ -223,15 +337,13 @@ pub fn load_gguf_metadata_async(
             match f.metadata() {
                 Ok(metadata) => metadata.len(),
                 Err(e) => {
-                    *progress.lock().unwrap() = -1.0;
-                    *result.lock().unwrap() =
-                        Some(Err(format!("Не удалось получить размер файла: {}", e)));
+                    fail(LoadError::Io(e.to_string()));
                     return;
                 }
             }
         };
 
-        *progress.lock().unwrap() = 0.05;
+        set_progress(0.05);
 
         // Read file into memory in chunks to show real progress
         let mut buf = Vec::new();
@@ -244,6 +356,11 @@ pub fn load_gguf_metadata_async(
         {
             puffin::profile_scope!("file_reading");
             loop {
+                // A newer load superseded this one: stop reading
+                if !ticket.is_current() {
+                    return;
+                }
+
                 match f.read(&mut chunk) {
                     Ok(0) => break, // EOF
                     Ok(n) => {
@@ -258,21 +375,43 @@ pub fn load_gguf_metadata_async(
                         if last_progress_update.elapsed() > Duration::from_millis(50)
                             || (current_progress - last_progress_value).abs() > 0.01
                         {
-                            *progress.lock().unwrap() = current_progress;
+                            set_progress(current_progress);
                             last_progress_value = current_progress;
                             last_progress_update = Instant::now();
                         }
                     }
                     Err(e) => {
-                        *progress.lock().unwrap() = -1.0;
-                        *result.lock().unwrap() = Some(Err(format!("Ошибка чтения файла: {}", e)));
+                        fail(LoadError::Io(e.to_string()));
                         return;
                     }
                 }
             }
         }
 
-        *progress.lock().unwrap() = 0.85;
+        // Empty and truncated files
+        if let Err(e) = check_gguf_size(buf.len() as u64) {
+            fail(e.into());
+            return;
+        }
+
+        if !ticket.is_current() {
+            return;
+        }
+
+        // Identical contents were parsed before: reuse that metadata
+        let cache_key = cache.as_ref().map(|_| {
+            puffin::profile_scope!("file_hashing");
+            hash_bytes(&buf)
+        });
+        if let (Some(cache), Some(hash)) = (&cache, &cache_key)
+            && let Some(file) = cache.lock().unwrap().get(hash, preview_limit)
+        {
+            set_progress(1.0);
+            ticket.publish(&result, Ok(file));
+            return;
+        }
+
+        set_progress(0.85);
 
         // GGUF parsing
         let content = {
@@ -281,27 +420,357 @@ pub fn load_gguf_metadata_async(
             match candle::quantized::gguf_file::Content::read(&mut cursor) {
                 Ok(content) => content,
                 Err(e) => {
-                    *progress.lock().unwrap() = -1.0;
-                    *result.lock().unwrap() = Some(Err(format!("Ошибка парсинга GGUF: {}", e)));
+                    fail(LoadError::Parse(e.to_string()));
                     return;
                 }
             }
         };
 
-        *progress.lock().unwrap() = 0.95;
+        if !ticket.is_current() {
+            return;
+        }
+        set_progress(PROCESSING_PROGRESS_START);
 
         // Process metadata
-        let mut out = Vec::new();
-        {
+        let entries = {
             puffin::profile_scope!("metadata_processing");
-            for (k, v) in content.metadata.iter() {
-                let s = readable_value_for_key(k, v);
-                let full_content = get_full_tokenizer_content(k, v);
-                out.push((k.clone(), s, full_content));
-            }
+            process_metadata_entries(&content.metadata, preview_limit, set_progress)
+        };
+        let report = ModelReport {
+            count_mismatch: check_header_counts(&buf, &content),
+            ..ModelReport::from_content(&content)
+        };
+        let file = LoadedFile { entries, report };
+
+        if let (Some(cache), Some(hash)) = (&cache, cache_key) {
+            cache.lock().unwrap().insert(hash, preview_limit, file.clone());
         }
 
+        set_progress(1.0);
+        ticket.publish(&result, Ok(file));
+    });
+}
+
+/// Shared container for the results of a background folder scan.
+pub type ScanResult = Arc<Mutex<Option<Vec<FileScanResult>>>>;
+
+/// Scans a directory for GGUF files on a background thread.
+///
+/// `progress` advances from 0.0 to 1.0 as files are summarized with
+/// [`crate::format::scan_directory_with_progress`]; the results are stored in
+/// `result` once every file has been processed.
+pub fn scan_directory_async(dir: std::path::PathBuf, progress: Arc<Mutex<f32>>, result: ScanResult) {
+    thread::spawn(move || {
+        puffin::profile_scope!("folder_scan_thread");
+        let results = scan_directory_with_progress(&dir, |done, total| {
+            *progress.lock().unwrap() = done as f32 / total.max(1) as f32;
+        });
         *progress.lock().unwrap() = 1.0;
-        *result.lock().unwrap() = Some(Ok(out));
+        *result.lock().unwrap() = Some(results);
     });
-}
\ No newline at end of file
+}
+
+/// Shared flag asking a background load to stop at its next checkpoint.
+pub type CancelFlag = Arc<AtomicBool>;
+
+/// Issues [`LoadTicket`]s and cancels the previous load whenever a new one begins.
+///
+/// Each call to [`LoadTracker::begin`] bumps a generation counter, so results
+/// from superseded loads are recognised as stale and discarded even if their
+/// thread finishes after the newer load started.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::loader::{LoadTracker, LoadedFile, LoadingResult};
+/// use std::sync::{Arc, Mutex};
+///
+/// let result: LoadingResult = Arc::new(Mutex::new(None));
+/// let mut tracker = LoadTracker::default();
+///
+/// let first = tracker.begin(&result);
+/// let second = tracker.begin(&result);
+///
+/// assert!(!first.publish(&result, Ok(LoadedFile::default())));
+/// assert!(result.lock().unwrap().is_none());
+/// assert!(second.publish(&result, Ok(LoadedFile::default())));
+/// ```
+#[derive(Debug, Default)]
+pub struct LoadTracker {
+    active: Arc<AtomicU64>,
+    cancel: Option<CancelFlag>,
+}
+
+impl LoadTracker {
+    /// Starts a new load: cancels the previous one, clears `result`, and returns its ticket.
+    pub fn begin(&mut self, result: &LoadingResult) -> LoadTicket {
+        if let Some(previous) = self.cancel.take() {
+            previous.store(true, Ordering::SeqCst);
+        }
+
+        // Bump the generation under the result lock so a stale thread cannot
+        // publish between the bump and the clear
+        let generation = {
+            let mut slot = result.lock().unwrap();
+            *slot = None;
+            self.active.fetch_add(1, Ordering::SeqCst) + 1
+        };
+
+        let cancel: CancelFlag = Arc::new(AtomicBool::new(false));
+        self.cancel = Some(Arc::clone(&cancel));
+        LoadTicket {
+            generation,
+            active: Arc::clone(&self.active),
+            cancel,
+        }
+    }
+
+    /// Cancels the in-flight load, if any, without starting a new one.
+    pub fn cancel(&mut self) {
+        if let Some(previous) = self.cancel.take() {
+            previous.store(true, Ordering::SeqCst);
+        }
+        self.active.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Returns the generation of the most recently started load.
+    pub fn generation(&self) -> u64 {
+        self.active.load(Ordering::SeqCst)
+    }
+}
+
+/// Identifies one load started by a [`LoadTracker`].
+#[derive(Debug, Clone)]
+pub struct LoadTicket {
+    generation: u64,
+    active: Arc<AtomicU64>,
+    cancel: CancelFlag,
+}
+
+impl LoadTicket {
+    /// Returns the generation number of this load.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns `true` while this load has been neither cancelled nor superseded.
+    pub fn is_current(&self) -> bool {
+        !self.cancel.load(Ordering::SeqCst) && self.active.load(Ordering::SeqCst) == self.generation
+    }
+
+    /// Stores `outcome` in `result` if this load is still current.
+    ///
+    /// Returns `false` and leaves `result` untouched for stale loads.
+    pub fn publish(&self, result: &LoadingResult, outcome: LoadOutcome) -> bool {
+        let mut slot = result.lock().unwrap();
+        if !self.is_current() {
+            return false;
+        }
+        *slot = Some(outcome);
+        true
+    }
+}
+
+/// Formats metadata entries while reporting progress from 0.95 towards 1.0.
+///
+/// Progress is spread proportionally to the number of entries processed and
+/// reported through `report` under the same throttling rules as the reading
+/// phase (at most every 50ms unless the value changed by more than 1%). The
+/// final 1.0 is never reported here, so callers can publish the result first.
+fn process_metadata_entries(
+    metadata: &HashMap<String, gguf_file::Value>,
+    preview_limit: usize,
+    mut report: impl FnMut(f32),
+) -> Vec<MetadataEntry> {
+    let total = metadata.len();
+    let mut out = Vec::with_capacity(total);
+    let mut last_progress_update = Instant::now();
+    let mut last_progress_value = PROCESSING_PROGRESS_START;
+
+    for (processed, (k, v)) in metadata.iter().enumerate() {
+        let s = readable_value_for_key_with_limit(k, v, preview_limit);
+        let full_content = get_full_tokenizer_content(k, v)
+            .or_else(|| image_data_uri(v))
+            .or_else(|| known_config_raw_value(k, v));
+
+        #[cfg(debug_assertions)]
+        {
+            let value_size = full_content.as_ref().map_or(s.len(), |full| full.len());
+            if value_size > LARGE_VALUE_THRESHOLD {
+                eprintln!("Debug: metadata value '{}' is large ({} bytes)", k, value_size);
+            }
+        }
+
+        out.push(MetadataEntry {
+            key: k.clone(),
+            display_value: s,
+            full_value: full_content,
+            raw_bytes: value_to_raw_bytes(v).filter(|bytes| !bytes.is_empty()),
+            string_bytes: match v {
+                gguf_file::Value::String(s) => Some(s.as_bytes().to_vec()),
+                _ => None,
+            },
+        });
+
+        let fraction = (processed + 1) as f32 / total as f32;
+        let current_progress = PROCESSING_PROGRESS_START + fraction * (1.0 - PROCESSING_PROGRESS_START);
+        if current_progress < 1.0
+            && (last_progress_update.elapsed() > Duration::from_millis(50)
+                || (current_progress - last_progress_value).abs() > 0.01)
+        {
+            report(current_progress);
+            last_progress_value = current_progress;
+            last_progress_update = Instant::now();
+        }
+    }
+
+    out
+}
+
+/// File name of the sample model bundled with the repository.
+pub const SAMPLE_MODEL_FILE: &str = "Qwen3-0.6B-Q5_K_M.gguf";
+
+/// Returns the first `root/model/`[`SAMPLE_MODEL_FILE`] that exists, in `roots` order.
+pub fn find_sample_model(roots: &[std::path::PathBuf]) -> Option<std::path::PathBuf> {
+    roots
+        .iter()
+        .map(|root| root.join("model").join(SAMPLE_MODEL_FILE))
+        .find(|path| path.is_file())
+}
+
+/// Locates the bundled sample model, if this build ships one.
+///
+/// The current directory is searched first, then the executable's directory and
+/// its two parents (covering `target/debug` and `target/release` builds run from
+/// elsewhere).
+pub fn locate_sample_model() -> Option<std::path::PathBuf> {
+    let mut roots = Vec::new();
+    if let Ok(dir) = std::env::current_dir() {
+        roots.push(dir);
+    }
+    if let Ok(exe) = std::env::current_exe() {
+        roots.extend(exe.ancestors().skip(1).take(3).map(|dir| dir.to_path_buf()));
+    }
+    find_sample_model(&roots)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_processing_reports_intermediate_progress() {
+        let metadata: HashMap<String, gguf_file::Value> = (0..1000)
+            .map(|i| (format!("test.key{}", i), gguf_file::Value::U32(i)))
+            .collect();
+
+        let mut reported = Vec::new();
+        let out = process_metadata_entries(&metadata, DEFAULT_PREVIEW_LIMIT, |value| reported.push(value));
+
+        assert_eq!(out.len(), 1000);
+        assert!(!reported.is_empty(), "Progress should be reported during processing");
+        assert!(
+            reported.iter().all(|p| *p > PROCESSING_PROGRESS_START && *p < 1.0),
+            "Intermediate progress should stay between 0.95 and 1.0: {:?}",
+            reported
+        );
+        assert!(
+            reported.windows(2).all(|w| w[0] <= w[1]),
+            "Progress should never go backwards"
+        );
+    }
+
+    #[test]
+    fn test_stale_generation_result_is_discarded() {
+        let result: LoadingResult = Arc::new(Mutex::new(None));
+        let mut tracker = LoadTracker::default();
+
+        let stale = tracker.begin(&result);
+        let current = tracker.begin(&result);
+        assert_eq!(current.generation(), stale.generation() + 1);
+        assert_eq!(tracker.generation(), current.generation());
+        assert!(!stale.is_current());
+        assert!(current.is_current());
+
+        let entry = MetadataEntry {
+            key: "general.name".to_string(),
+            display_value: "new".to_string(),
+            full_value: None,
+            raw_bytes: None,
+            string_bytes: None,
+        };
+        let file = LoadedFile { entries: vec![entry], ..LoadedFile::default() };
+        assert!(current.publish(&result, Ok(file)));
+        assert!(!stale.publish(&result, Err(LoadError::Io("old load failed".to_string()))));
+
+        let published = result.lock().unwrap().take().expect("Current result should be kept");
+        assert_eq!(published.unwrap().entries[0].display_value, "new");
+    }
+
+    #[test]
+    fn test_cancel_invalidates_ticket() {
+        let result: LoadingResult = Arc::new(Mutex::new(None));
+        let mut tracker = LoadTracker::default();
+
+        let ticket = tracker.begin(&result);
+        tracker.cancel();
+
+        assert!(!ticket.is_current());
+        assert!(!ticket.publish(&result, Ok(LoadedFile::default())));
+        assert!(result.lock().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_empty_file_fails_with_typed_error() {
+        let dir = tempfile::tempdir().expect("Temp dir should be created");
+        let path = dir.path().join("empty.gguf");
+        std::fs::write(&path, b"").unwrap();
+
+        let result: LoadingResult = Arc::new(Mutex::new(None));
+        load_gguf_metadata_async(path, Arc::new(Mutex::new(0.0)), Arc::clone(&result));
+
+        // The worker publishes exactly once; wait for it without a timing assertion
+        let outcome = loop {
+            if let Some(outcome) = result.lock().unwrap().take() {
+                break outcome;
+            }
+            thread::sleep(Duration::from_millis(5));
+        };
+        assert_eq!(outcome.err(), Some(LoadError::Format(GgufError::Empty)));
+    }
+
+    #[test]
+    fn test_processing_empty_metadata() {
+        let metadata = HashMap::new();
+        let mut reported = Vec::new();
+        let out = process_metadata_entries(&metadata, DEFAULT_PREVIEW_LIMIT, |value| reported.push(value));
+
+        assert!(out.is_empty());
+        assert!(reported.is_empty());
+    }
+
+    #[test]
+    fn test_find_sample_model_searches_roots_in_order() {
+        let first = tempfile::tempdir().expect("Temp dir should be created");
+        let second = tempfile::tempdir().expect("Temp dir should be created");
+        let roots = [first.path().to_path_buf(), second.path().to_path_buf()];
+        assert_eq!(find_sample_model(&roots), None);
+
+        let bundled = second.path().join("model").join(SAMPLE_MODEL_FILE);
+        std::fs::create_dir_all(bundled.parent().unwrap()).unwrap();
+        std::fs::write(&bundled, b"GGUF").unwrap();
+        assert_eq!(find_sample_model(&roots), Some(bundled));
+
+        let local = first.path().join("model").join(SAMPLE_MODEL_FILE);
+        std::fs::create_dir_all(local.parent().unwrap()).unwrap();
+        std::fs::write(&local, b"GGUF").unwrap();
+        assert_eq!(find_sample_model(&roots), Some(local));
+    }
+
+    #[test]
+    fn test_find_sample_model_ignores_directories() {
+        let root = tempfile::tempdir().expect("Temp dir should be created");
+        std::fs::create_dir_all(root.path().join("model").join(SAMPLE_MODEL_FILE)).unwrap();
+        assert_eq!(find_sample_model(&[root.path().to_path_buf()]), None);
+    }
+}
@@ -25,10 +25,14 @@
 //! ## Visual System
 //! - [`theme`]: Inspector Gadget color scheme and font management with [`apply_inspector_theme`] and [`load_custom_font`]
 //! - [`layout`]: Responsive sizing utilities for adaptive UI elements including [`get_sidebar_width`] and [`get_adaptive_font_size`]
+//! - [`highlight`]: JSON and Jinja syntax highlighting for the content viewers
 //!
 //! ## Data Processing
 //! - [`export`]: Multi-format export with functions like [`export_csv`], [`export_yaml`], [`export_markdown`], [`export_html`], and [`export_pdf_from_markdown`]
 //! - [`loader`]: Asynchronous GGUF file loading with [`load_gguf_metadata_async`] and progress tracking via [`LoadingResult`]
+//! - [`cache`]: Optional LRU cache of parsed metadata keyed by file hash ([`MetadataCache`])
+//! - [`changes`]: Hashed per-file snapshots that badge keys changed since the file was last opened
+//! - [`settings`]: GUI-only settings ([`GuiSettings`]) stored alongside [`crate::localization::AppSettings`]
 //!
 //! ## User Interface ([`panels`])
 //! Organized panel system for clean UI structure with functions like [`render_sidebar`], 
@@ -102,8 +106,13 @@ pub mod app;
 pub mod theme;
 pub mod export;
 pub mod loader;
+pub mod cache;
+pub mod changes;
+pub mod settings;
 pub mod updater;
+pub mod watcher;
 pub mod layout;
+pub mod highlight;
 pub mod panels;
 
 // Re-export main application struct and key functionality
@@ -112,7 +121,11 @@ pub use app::GgufApp;
 // Theme system re-exports
 pub use theme::{
     apply_inspector_theme, 
+    apply_high_contrast_theme,
     load_custom_font, 
+    load_fonts,
+    font_definitions,
+    DISPLAY_FONT_NAME,
     INSPECTOR_BLUE, 
     GADGET_YELLOW, 
     TECH_GRAY, 
@@ -124,33 +137,88 @@ pub use theme::{
 pub use layout::{
     get_sidebar_width, 
     get_adaptive_font_size, 
-    get_adaptive_button_width
+    get_adaptive_button_width,
+    set_font_scale,
+    font_scale,
+    FONT_SCALE_RANGE
 };
 
 // Export system re-exports (all public functions)
 pub use export::{
     ensure_extension,
+    sanitize_filename,
+    export_base_name,
+    MAX_FILENAME_CHARS,
     sanitize_for_markdown,
     escape_markdown_text,
+    markdown_heading_text,
+    EMPTY_KEY_HEADING,
     show_base64_dialog,
     export_csv,
+    export_csv_with_options,
+    neutralize_formula,
+    ExportOptions,
+    filter_keys,
+    key_matches,
     export_yaml,
+    export_yaml_with_options,
     export_markdown,
+    export_markdown_streaming,
     export_markdown_to_file,
+    export_markdown_to_file_with_options,
+    export_markdown_table,
+    MARKDOWN_TABLE_VALUE_LIMIT,
     export_html,
+    export_html_streaming,
     export_html_to_file,
-    export_pdf_from_markdown
+    export_html_to_file_with_options,
+    export_pdf_from_markdown,
+    export_json,
+    export_json_with_options,
+    export_json_value,
+    export_full_report,
+    export_sqlite,
+    full_report_value,
+    write_export,
+    write_export_streaming,
+    ExportCompression,
+    ExportFormat,
+    export_metadata,
+    export_metadata_async,
+    ExportOutcome,
+    ExportResult,
+    BackgroundExport
 };
 
 // File loader re-exports
 pub use loader::{
     load_gguf_metadata_async, 
+    load_gguf_metadata_async_with_preview,
+    load_gguf_metadata_async_tracked,
+    load_gguf_metadata_async_cached,
+    LoadTracker,
+    LoadTicket,
+    scan_directory_async,
+    ScanResult,
     LoadingResult, 
+    LoadError,
+    LoadedFile,
     MetadataEntry
 };
 
+// Metadata cache re-exports
+pub use cache::{
+    hash_bytes,
+    MetadataCache,
+    SharedMetadataCache,
+    DEFAULT_CACHE_CAPACITY
+};
+
+// GUI settings re-exports
+pub use settings::GuiSettings;
+
 // Update checker re-exports
-pub use updater::check_for_updates;
+pub use updater::{check_for_updates, current_version, update_status_for_tag, is_newer_release, version_info};
 
 // Panel system re-exports
 pub use panels::{
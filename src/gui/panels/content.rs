@@ -1,37 +1,34 @@
-//! Central content panel functionality.
+//! Central metadata list: [`render_content_panel`] and the helpers it shares with
+//! [`crate::gui::GgufApp`].
 //!
-//! This module implements the main content area of the Inspector GGUF application,
-//! responsible for displaying GGUF metadata, providing filtering capabilities,
-//! handling drag-and-drop file operations, and showing loading progress. The content
-//! panel serves as the primary information display and interaction area.
+//! The helpers are kept free of UI state so they can be tested directly.
 //!
-//! # Panel Features
+//! # Features
 //!
-//! ## Metadata Display
-//! - **Structured Layout**: Organized display of key-value metadata pairs
-//! - **Special Handling**: Custom viewers for large content (chat templates, tokens)
-//! - **Binary Data**: Base64 encoding and external viewer support
-//! - **Responsive Cards**: Grouped metadata entries with consistent styling
-//!
-//! ## Interactive Features
-//! - **Real-time Filtering**: Live search through metadata keys and values
-//! - **Drag & Drop**: Direct file loading by dropping GGUF files onto the panel
-//! - **Content Viewers**: Specialized panels for viewing large text content
-//! - **Progress Tracking**: Visual progress bars during file loading operations
-//!
-//! ## Data Handling
-//! - **Large Content**: Automatic detection and special handling of oversized values
-//! - **Binary Detection**: Identifies and handles binary data appropriately
-//! - **Tokenizer Content**: Special viewers for chat templates and token data
-//! - **Temporary Files**: Handles dropped file bytes through temporary file creation
+//! - **Filtering**: Substring, numeric (`>4096`) and fuzzy queries ([`filter_entries`])
+//! - **Per-file Filters**: Filters remembered per file in the settings ([`remember_filter`])
+//! - **Paging and Pins**: Page slicing and pinned keys kept on top
+//! - **Tokenizer Previews**: Inline token previews with special tokens highlighted
+//! - **Drag & Drop**: GGUF detection for dropped files and the hover overlay
 
 use eframe::egui;
+use fuzzy_matcher::FuzzyMatcher;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
-use crate::localization::LanguageProvider;
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+use crate::format::GGUF_MAGIC;
+use crate::localization::{AppSettings, LanguageProvider};
+use crate::gui::changes::path_hash;
 use crate::gui::layout::get_adaptive_font_size;
-use crate::gui::theme::{INSPECTOR_BLUE, GADGET_YELLOW, TECH_GRAY};
+use crate::gui::theme::{INSPECTOR_BLUE, GADGET_YELLOW, TECH_GRAY, DANGER_RED};
 use crate::gui::loader::{load_gguf_metadata_async, LoadingResult, MetadataEntry};
 use crate::gui::export::show_base64_dialog;
+use crate::gui::settings::GuiSettings;
 
 /// Renders the main content panel with metadata display and interactive features.
 ///
@@ -42,7 +39,7 @@ use crate::gui::export::show_base64_dialog;
 ///
 /// # Panel Sections
 ///
-/// 1. **Drop Zone**: Invisible area that handles drag-and-drop file operations
+/// 1. **Drop Zone**: Handles drag-and-drop file operations, with an overlay while files are hovered
 /// 2. **Progress Display**: Shows loading progress bar and status during file operations
 /// 3. **Filter Toolbar**: Text input for real-time metadata filtering
 /// 4. **Metadata Display**: Scrollable area with organized metadata entries
@@ -78,6 +75,7 @@ use crate::gui::export::show_base64_dialog;
 ///
 /// ## Drag and Drop
 /// - **File Detection**: Automatically detects dropped GGUF files
+/// - **Visual Feedback**: Overlay via [`render_drop_overlay`] while files are dragged over the window
 /// - **Path Handling**: Supports both file paths and raw file bytes
 /// - **Temporary Files**: Creates temporary files for byte data
 /// - **Loading Integration**: Automatically starts async loading for dropped files
@@ -130,9 +128,19 @@ pub fn render_content_panel<T: LanguageProvider>(
     selected_ggml_merges: &mut Option<String>,
 ) {
     // Drop zone: поддержка drag-n-drop файлов
+    render_drop_overlay(ctx, app);
     let dropped = ctx.input(|i| i.raw.dropped_files.clone());
     if !dropped.is_empty() {
         for df in dropped {
+            let accepted = match (&df.path, &df.bytes) {
+                (Some(path), _) => accept_dropped(DroppedInput::Path(path)),
+                (None, Some(bytes)) => accept_dropped(DroppedInput::Bytes(bytes)),
+                (None, None) => false,
+            };
+            if !accepted {
+                eprintln!("{}", app.t_with_args("errors.not_gguf_file", &[&df.name]));
+                continue;
+            }
             if !*loading {
                 if let Some(path) = df.path {
                     *loading = true;
@@ -171,7 +179,7 @@ pub fn render_content_panel<T: LanguageProvider>(
     if *loading {
         ui.add(
             egui::ProgressBar::new(current_progress)
-                .show_percentage()
+                .text(app.format_percent(current_progress))
                 .fill(INSPECTOR_BLUE),
         );
         ui.label(egui::RichText::new(app.t("messages.loading")).color(TECH_GRAY).size(get_adaptive_font_size(14.0, ctx)));
@@ -215,6 +223,7 @@ pub fn render_content_panel<T: LanguageProvider>(
     let no_metadata_text = app.t("messages.no_metadata");
     let binary_long_text = app.t("data.binary_long");
     let base64_text = app.t("data.base64");
+    let copy_text = app.t("buttons.copy");
     
     egui::ScrollArea::vertical()
         .auto_shrink([false; 2])
@@ -222,13 +231,24 @@ pub fn render_content_panel<T: LanguageProvider>(
             let mut first = true;
             for entry in metadata
                 .iter()
-                .filter(|entry| entry.key.contains(filter.as_str()) || entry.display_value.contains(filter.as_str()))
+                .filter(|entry| matches_filter(entry, filter))
             {
                 let k = &entry.key;
                 let v = &entry.display_value;
                 ui.group(|ui| {
                     ui.vertical(|ui| {
-                        ui.label(egui::RichText::new(k).color(GADGET_YELLOW).strong().size(get_adaptive_font_size(14.0, ctx)));
+                        ui.horizontal(|ui| {
+                            ui.label(egui::RichText::new(k).color(GADGET_YELLOW).strong().size(get_adaptive_font_size(14.0, ctx)));
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                if ui
+                                    .small_button(egui_phosphor::regular::COPY)
+                                    .on_hover_text(&copy_text)
+                                    .clicked()
+                                {
+                                    ctx.copy_text(entry_clipboard_text(entry));
+                                }
+                            });
+                        });
                         ui.add_space(get_adaptive_font_size(4.0, ctx));
                         if k == "tokenizer.chat_template" {
                             // Специальная обработка для chat template - показываем кнопку Select
@@ -296,9 +316,7 @@ pub fn render_content_panel<T: LanguageProvider>(
                                 }
                             });
                         } else {
-                            ui.label(
-                                egui::RichText::new(v).color(egui::Color32::WHITE).size(get_adaptive_font_size(12.0, ctx)),
-                            );
+                            render_plain_value(ui, ctx, k, v);
                         }
                     });
                 });
@@ -311,4 +329,1164 @@ pub fn render_content_panel<T: LanguageProvider>(
                 );
             }
         });
-}
\ No newline at end of file
+}
+
+/// Builds the clipboard text for a single metadata entry.
+///
+/// Produces `key: value`, preferring the complete `full_value` over the
+/// possibly truncated `display_value` so that copied tokenizer data is not cut short.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::loader::MetadataEntry;
+/// use inspector_gguf::gui::panels::content::entry_clipboard_text;
+///
+/// let entry = MetadataEntry {
+///     key: "general.name".to_string(),
+///     display_value: "llama-7b-chat".to_string(),
+///     full_value: None,
+///     raw_bytes: None,
+///     string_bytes: None,
+/// };
+/// assert_eq!(entry_clipboard_text(&entry), "general.name: llama-7b-chat");
+/// ```
+pub fn entry_clipboard_text(entry: &MetadataEntry) -> String {
+    let value = entry.full_value.as_deref().unwrap_or(&entry.display_value);
+    format!("{}: {}", entry.key, value)
+}
+
+/// Returns the bytes shown in the hex view of `entry`.
+///
+/// Byte arrays show their `raw_bytes` and strings their `string_bytes`, both taken
+/// from the parsed value. Other types have no hex view and return `None`.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::loader::MetadataEntry;
+/// use inspector_gguf::gui::panels::content::hex_view_bytes;
+///
+/// let entry = MetadataEntry {
+///     key: "general.name".to_string(),
+///     display_value: "Qwen3".to_string(),
+///     full_value: None,
+///     raw_bytes: None,
+///     string_bytes: Some(b"Qwen3".to_vec()),
+/// };
+/// assert_eq!(hex_view_bytes(&entry), Some(&b"Qwen3"[..]));
+/// ```
+pub fn hex_view_bytes(entry: &MetadataEntry) -> Option<&[u8]> {
+    entry.raw_bytes.as_deref().or(entry.string_bytes.as_deref())
+}
+
+/// Makes whitespace in `s` visible as escape sequences.
+///
+/// Newlines, tabs and carriage returns become `\n`, `\t` and `\r`, so templates
+/// full of raw whitespace can be read on a single monospace line. Used for display
+/// only; copy actions always use the raw value.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::panels::content::escape_whitespace;
+///
+/// assert_eq!(escape_whitespace("{% for m in messages %}\n\t{{ m }}"), "{% for m in messages %}\\n\\t{{ m }}");
+/// ```
+pub fn escape_whitespace(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Number of tokens previewed under `tokenizer.ggml.tokens` in the content panel.
+pub const INLINE_TOKEN_PREVIEW: usize = 8;
+
+/// Returns the first `n` tokens of a comma-joined token list such as the value of
+/// `tokenizer.ggml.tokens`.
+///
+/// Tokens are split on `", "`, so tokens that contain that separator are split
+/// too; the `…` marker of a truncated display value is not returned as a token.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::panels::content::preview_first_tokens;
+///
+/// assert_eq!(preview_first_tokens("<s>, </s>, hello, world", 3), ["<s>", "</s>", "hello"]);
+/// assert_eq!(preview_first_tokens("a, b, …", 8), ["a", "b"]);
+/// ```
+pub fn preview_first_tokens(full: &str, n: usize) -> Vec<String> {
+    if full.is_empty() {
+        return Vec::new();
+    }
+    full.split(", ")
+        .filter(|token| *token != "…")
+        .take(n)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Returns `true` for control tokens such as `<|endoftext|>`, `<s>` or `[CLS]`.
+///
+/// Byte-fallback tokens like `<0x0A>` are ordinary vocabulary entries and are not special.
+pub fn is_special_token(token: &str) -> bool {
+    let bracketed = (token.starts_with('<') && token.ends_with('>'))
+        || (token.starts_with('[') && token.ends_with(']'));
+    bracketed && token.len() > 2 && !token.starts_with("<0x") && !token.contains(char::is_whitespace)
+}
+
+/// Returns `true` if `haystack` contains `needle` after NFC normalization of both.
+///
+/// Composed and decomposed spellings of the same text match each other, so
+/// filtering for `café` finds `cafe\u{301}`. ASCII text is compared directly.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::panels::content::normalized_contains;
+///
+/// assert!(normalized_contains("Le cafe\u{301} noir", "caf\u{e9}"));
+/// assert!(!normalized_contains("coffee", "café"));
+/// ```
+pub fn normalized_contains(haystack: &str, needle: &str) -> bool {
+    if haystack.is_ascii() && needle.is_ascii() {
+        return haystack.contains(needle);
+    }
+    let haystack: String = haystack.nfc().collect();
+    let needle: String = needle.nfc().collect();
+    haystack.contains(&needle)
+}
+
+/// Returns `true` if the content panel `filter` matches the entry's key or displayed value.
+///
+/// This is the single place the filter is interpreted: numeric queries such as
+/// `>4096` compare the value (see [`numeric_match`]), anything else is a substring
+/// search (see [`normalized_contains`]).
+pub fn matches_filter(entry: &MetadataEntry, filter: &str) -> bool {
+    if parse_numeric_query(filter).is_some() {
+        return numeric_match(&entry.display_value, filter);
+    }
+    normalized_contains(&entry.key, filter) || normalized_contains(&entry.display_value, filter)
+}
+
+/// How the content panel filter selects and orders entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilterMode {
+    /// Substring and numeric queries, in file order ([`matches_filter`])
+    #[default]
+    Substring,
+    /// Fuzzy key matching, best matches first ([`fuzzy_score`])
+    Fuzzy,
+}
+
+/// Scores how well `query` fuzzy-matches the entry's key, or `None` if it does not match.
+///
+/// Uses the Skim algorithm, so `tkchat` matches `tokenizer.chat_template`. Only
+/// keys are scored: values such as chat templates are long and would match almost
+/// any query.
+pub fn fuzzy_score(matcher: &SkimMatcherV2, entry: &MetadataEntry, query: &str) -> Option<i64> {
+    matcher.fuzzy_match(&entry.key, query.trim())
+}
+
+/// Returns the entries shown for `filter` in `mode`.
+///
+/// [`FilterMode::Substring`] keeps the entries accepted by [`matches_filter`] in
+/// their original order. [`FilterMode::Fuzzy`] keeps the entries with a
+/// [`fuzzy_score`], highest score first; ties and an empty filter keep the
+/// original order.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::loader::MetadataEntry;
+/// use inspector_gguf::gui::panels::content::{filter_entries, FilterMode};
+///
+/// let entries: Vec<MetadataEntry> = ["general.name", "tokenizer.chat_template"]
+///     .iter()
+///     .map(|key| MetadataEntry { key: key.to_string(), display_value: String::new(), full_value: None, raw_bytes: None, string_bytes: None })
+///     .collect();
+///
+/// let matched = filter_entries(&entries, "tkchat", FilterMode::Fuzzy);
+/// assert_eq!(matched[0].key, "tokenizer.chat_template");
+/// assert!(filter_entries(&entries, "tkchat", FilterMode::Substring).is_empty());
+/// ```
+pub fn filter_entries<'a>(entries: &'a [MetadataEntry], filter: &str, mode: FilterMode) -> Vec<&'a MetadataEntry> {
+    match mode {
+        FilterMode::Fuzzy if !filter.trim().is_empty() => {
+            let matcher = SkimMatcherV2::default();
+            let mut scored: Vec<(i64, &MetadataEntry)> = entries
+                .iter()
+                .filter_map(|entry| fuzzy_score(&matcher, entry, filter).map(|score| (score, entry)))
+                .collect();
+            scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+            scored.into_iter().map(|(_, entry)| entry).collect()
+        }
+        FilterMode::Fuzzy => entries.iter().collect(),
+        FilterMode::Substring => entries.iter().filter(|entry| matches_filter(entry, filter)).collect(),
+    }
+}
+
+/// Comparison operators of numeric queries, longest first, with the orderings
+/// of value against operand that satisfy them.
+const NUMERIC_OPERATORS: &[(&str, &[std::cmp::Ordering])] = &[
+    (">=", &[std::cmp::Ordering::Greater, std::cmp::Ordering::Equal]),
+    ("<=", &[std::cmp::Ordering::Less, std::cmp::Ordering::Equal]),
+    (">", &[std::cmp::Ordering::Greater]),
+    ("<", &[std::cmp::Ordering::Less]),
+    ("=", &[std::cmp::Ordering::Equal]),
+];
+
+/// Splits a query such as `>= 4096` into the orderings it accepts and its operand.
+fn parse_numeric_query(query: &str) -> Option<(&'static [std::cmp::Ordering], f64)> {
+    let query = query.trim();
+    NUMERIC_OPERATORS.iter().find_map(|(operator, orderings)| {
+        let operand = query.strip_prefix(operator)?.trim().parse::<f64>().ok()?;
+        Some((*orderings, operand))
+    })
+}
+
+/// Returns `true` if `value` is a number satisfying the numeric `query`.
+///
+/// Queries are one of `>`, `<`, `>=`, `<=` or `=` followed by a number. Values
+/// that do not parse as a number never match, and neither does anything when
+/// `query` is not a numeric query.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::panels::content::numeric_match;
+///
+/// assert!(numeric_match("40960", ">4096"));
+/// assert!(numeric_match("1e-6", "<=0.001"));
+/// assert!(!numeric_match("qwen3", ">0"));
+/// assert!(!numeric_match("4096", "4096"));
+/// ```
+pub fn numeric_match(value: &str, query: &str) -> bool {
+    let Some((orderings, operand)) = parse_numeric_query(query) else {
+        return false;
+    };
+    value
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .and_then(|value| value.partial_cmp(&operand))
+        .is_some_and(|ordering| orderings.contains(&ordering))
+}
+
+/// Number of keys suggested under the filter box.
+pub const FILTER_SUGGESTION_LIMIT: usize = 8;
+
+/// Suggests metadata keys and key prefixes (such as `tokenizer.`) that complete the filter `input`.
+///
+/// Keys and prefixes starting with `input` come first, followed by those that only
+/// contain it; each group is sorted alphabetically. The input itself is not
+/// suggested, and an empty input suggests nothing.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::panels::content::suggest_keys;
+///
+/// let keys = ["general.name".to_string(), "tokenizer.ggml.model".to_string()];
+/// assert_eq!(suggest_keys(&keys, "tok", 8), ["tokenizer.", "tokenizer.ggml.", "tokenizer.ggml.model"]);
+/// assert_eq!(suggest_keys(&keys, "name", 8), ["general.name"]);
+/// ```
+pub fn suggest_keys(keys: &[String], input: &str, limit: usize) -> Vec<String> {
+    if input.is_empty() {
+        return Vec::new();
+    }
+    let mut candidates = BTreeSet::new();
+    for key in keys {
+        candidates.insert(key.as_str());
+        for (dot, _) in key.match_indices('.') {
+            candidates.insert(&key[..=dot]);
+        }
+    }
+    let (prefix, substring): (Vec<&str>, Vec<&str>) = candidates
+        .into_iter()
+        .filter(|candidate| *candidate != input && candidate.contains(input))
+        .partition(|candidate| candidate.starts_with(input));
+    prefix.into_iter().chain(substring).take(limit).map(str::to_string).collect()
+}
+
+/// Number of files whose last filter is remembered.
+pub const MAX_REMEMBERED_FILTERS: usize = 20;
+
+/// Last filter used for one file, stored while [`AppSettings::remember_filters`] is on.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileFilter {
+    /// Hash of the file path (see [`path_hash`]).
+    pub file: String,
+    /// Filter text, never empty.
+    pub filter: String,
+}
+
+/// Returns the filter remembered for `path`, or an empty string when there is
+/// none or [`AppSettings::remember_filters`] is off.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::panels::content::{remember_filter, restore_filter};
+/// use inspector_gguf::localization::AppSettings;
+/// use std::path::Path;
+///
+/// let mut settings = AppSettings { remember_filters: true, ..Default::default() };
+/// remember_filter(&mut settings, Path::new("model.gguf"), "tokenizer.");
+/// assert_eq!(restore_filter(&settings, Path::new("model.gguf")), "tokenizer.");
+/// assert_eq!(restore_filter(&settings, Path::new("other.gguf")), "");
+/// ```
+pub fn restore_filter(settings: &AppSettings, path: &Path) -> String {
+    if !settings.remember_filters {
+        return String::new();
+    }
+    let file = path_hash(path);
+    GuiSettings::from_settings(settings)
+        .file_filters
+        .into_iter()
+        .find(|stored| stored.file == file)
+        .map(|stored| stored.filter)
+        .unwrap_or_default()
+}
+
+/// Stores `filter` as the last filter of `path`, most recent file first.
+///
+/// An empty filter forgets the stored one. Beyond [`MAX_REMEMBERED_FILTERS`] files
+/// the oldest are dropped. Does nothing while [`AppSettings::remember_filters`] is off.
+pub fn remember_filter(settings: &mut AppSettings, path: &Path, filter: &str) {
+    if !settings.remember_filters {
+        return;
+    }
+    let file = path_hash(path);
+    GuiSettings::update(settings, |gui| {
+        gui.file_filters.retain(|stored| stored.file != file);
+        if !filter.is_empty() {
+            gui.file_filters.insert(0, FileFilter { file, filter: filter.to_string() });
+            gui.file_filters.truncate(MAX_REMEMBERED_FILTERS);
+        }
+    });
+}
+
+/// Metadata entries shown per page of the content panel unless configured otherwise.
+pub const DEFAULT_ENTRIES_PER_PAGE: usize = 100;
+
+/// Returns the number of pages needed to show `len` items, `per_page` at a time.
+///
+/// There is always at least one page, even when nothing matches the filter.
+/// A `per_page` of `0` disables pagination.
+pub fn page_count(len: usize, per_page: usize) -> usize {
+    if per_page == 0 {
+        return 1;
+    }
+    len.div_ceil(per_page).max(1)
+}
+
+/// Returns the items shown on the zero-based `page`.
+///
+/// Pages past the end are clamped to the last page, so a stale page number
+/// after the list shrinks still shows entries. A `per_page` of `0` returns
+/// all items.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::panels::content::paginate;
+///
+/// let items: Vec<u32> = (0..250).collect();
+/// assert_eq!(paginate(&items, 1, 100), &items[100..200]);
+/// assert_eq!(paginate(&items, 9, 100), &items[200..]);
+/// ```
+pub fn paginate<T>(items: &[T], page: usize, per_page: usize) -> &[T] {
+    if per_page == 0 {
+        return items;
+    }
+    let page = page.min(page_count(items.len(), per_page) - 1);
+    let start = page * per_page;
+    let end = (start + per_page).min(items.len());
+    &items[start..end]
+}
+
+/// Splits `entries` into the pinned entries and the rest, keeping each key's relative order.
+///
+/// Pinned entries follow the order of `pinned`, i.e. the order in which keys were
+/// pinned; keys that are pinned but absent from `entries` are skipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::loader::MetadataEntry;
+/// use inspector_gguf::gui::panels::content::partition_pinned;
+///
+/// let entry = |key: &str| MetadataEntry { key: key.to_string(), display_value: String::new(), full_value: None, raw_bytes: None, string_bytes: None };
+/// let entries = [entry("general.name"), entry("general.architecture")];
+/// let refs: Vec<&MetadataEntry> = entries.iter().collect();
+///
+/// let (pinned, rest) = partition_pinned(&refs, &["general.architecture".to_string()]);
+/// assert_eq!(pinned[0].key, "general.architecture");
+/// assert_eq!(rest[0].key, "general.name");
+/// ```
+pub fn partition_pinned<'a>(
+    entries: &[&'a MetadataEntry],
+    pinned: &[String],
+) -> (Vec<&'a MetadataEntry>, Vec<&'a MetadataEntry>) {
+    let pinned_entries = pinned
+        .iter()
+        .filter_map(|key| entries.iter().find(|entry| entry.key == *key).copied())
+        .collect();
+    let rest = entries
+        .iter()
+        .filter(|entry| !pinned.contains(&entry.key))
+        .copied()
+        .collect();
+    (pinned_entries, rest)
+}
+
+/// Pins `key` if it is not pinned yet, otherwise unpins it. Returns whether the key is now pinned.
+pub fn toggle_pinned(pinned: &mut Vec<String>, key: &str) -> bool {
+    if let Some(index) = pinned.iter().position(|k| k == key) {
+        pinned.remove(index);
+        false
+    } else {
+        pinned.push(key.to_string());
+        true
+    }
+}
+
+/// SPDX license identifiers recognized in `general.license`, in canonical casing.
+const KNOWN_SPDX_LICENSES: &[&str] = &[
+    "Apache-2.0",
+    "MIT",
+    "BSD-2-Clause",
+    "BSD-3-Clause",
+    "GPL-2.0",
+    "GPL-3.0",
+    "LGPL-2.1",
+    "LGPL-3.0",
+    "AGPL-3.0",
+    "MPL-2.0",
+    "CC0-1.0",
+    "CC-BY-4.0",
+    "CC-BY-SA-4.0",
+    "CC-BY-NC-4.0",
+    "CC-BY-NC-SA-4.0",
+    "Unlicense",
+];
+
+/// Returns `true` if `value` is a single http(s) URL.
+///
+/// Other schemes such as `ftp://` are not treated as links.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::panels::content::looks_like_url;
+///
+/// assert!(looks_like_url("https://huggingface.co/Qwen/Qwen3-0.6B"));
+/// assert!(!looks_like_url("ftp://example.com/model.gguf"));
+/// assert!(!looks_like_url("Qwen3 0.6B"));
+/// ```
+pub fn looks_like_url(value: &str) -> bool {
+    let value = value.trim();
+    let lower = value.to_ascii_lowercase();
+    let rest = match lower.strip_prefix("https://").or_else(|| lower.strip_prefix("http://")) {
+        Some(rest) => rest,
+        None => return false,
+    };
+    !rest.is_empty() && !rest.starts_with('/') && !rest.chars().any(char::is_whitespace)
+}
+
+/// Returns the canonical SPDX page for a recognized license identifier.
+///
+/// Matching is case-insensitive, so `apache-2.0` resolves to the `Apache-2.0` page.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::panels::content::license_url;
+///
+/// assert_eq!(license_url("apache-2.0").as_deref(), Some("https://spdx.org/licenses/Apache-2.0.html"));
+/// assert_eq!(license_url("proprietary"), None);
+/// ```
+pub fn license_url(license: &str) -> Option<String> {
+    let license = license.trim();
+    KNOWN_SPDX_LICENSES
+        .iter()
+        .find(|id| id.eq_ignore_ascii_case(license))
+        .map(|id| format!("https://spdx.org/licenses/{}.html", id))
+}
+
+/// Returns the link target for a metadata value, if it should be clickable.
+///
+/// URL values link to themselves; `general.license` links to its SPDX page
+/// when the identifier is recognized.
+pub fn metadata_link(key: &str, value: &str) -> Option<String> {
+    if looks_like_url(value) {
+        Some(value.trim().to_string())
+    } else if key == "general.license" {
+        license_url(value)
+    } else {
+        None
+    }
+}
+
+/// Keys that may hold the full text of the model license, in order of preference.
+pub const LICENSE_TEXT_KEYS: &[&str] = &["general.license.text", "general.license.content", "general.license_text"];
+
+/// License details gathered from the `general.license*` keys by [`extract_license_info`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LicenseInfo {
+    /// License identifier from `general.license`, usually an SPDX id such as `apache-2.0`
+    pub id: Option<String>,
+    /// Human-readable name from `general.license.name`
+    pub name: Option<String>,
+    /// `general.license.link`, or the SPDX page of a recognized [`LicenseInfo::id`]
+    pub link: Option<String>,
+    /// Full license text embedded under one of [`LICENSE_TEXT_KEYS`]
+    pub text: Option<String>,
+}
+
+impl LicenseInfo {
+    /// Returns the text copied by the license section: the embedded text if any,
+    /// otherwise the name, id and link on separate lines.
+    pub fn clipboard_text(&self) -> String {
+        match &self.text {
+            Some(text) => text.clone(),
+            None => [&self.name, &self.id, &self.link].into_iter().flatten().cloned().collect::<Vec<_>>().join("\n"),
+        }
+    }
+}
+
+/// Collects the license id, name, link and embedded text of a model.
+///
+/// Values are taken untruncated (`full_value` when present) and blank values are
+/// ignored. Returns `None` when the model declares no license at all.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::loader::MetadataEntry;
+/// use inspector_gguf::gui::panels::content::extract_license_info;
+///
+/// let entries = vec![MetadataEntry {
+///     key: "general.license".to_string(),
+///     display_value: "mit".to_string(),
+///     full_value: None,
+///     raw_bytes: None,
+///     string_bytes: None,
+/// }];
+/// let info = extract_license_info(&entries).unwrap();
+/// assert_eq!(info.id.as_deref(), Some("mit"));
+/// assert_eq!(info.link.as_deref(), Some("https://spdx.org/licenses/MIT.html"));
+/// assert_eq!(info.text, None);
+/// ```
+pub fn extract_license_info(entries: &[MetadataEntry]) -> Option<LicenseInfo> {
+    let value = |key: &str| {
+        entries
+            .iter()
+            .find(|entry| entry.key == key)
+            .map(|entry| entry.full_value.as_deref().unwrap_or(&entry.display_value).trim())
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    let id = value("general.license");
+    let info = LicenseInfo {
+        name: value("general.license.name"),
+        link: value("general.license.link").or_else(|| id.as_deref().and_then(license_url)),
+        text: LICENSE_TEXT_KEYS.iter().find_map(|key| value(key)),
+        id,
+    };
+    (info != LicenseInfo::default()).then_some(info)
+}
+
+/// Renders a plain metadata value, as a clickable link when [`metadata_link`] finds a target.
+///
+/// Links are opened in the system browser via `opener`.
+pub fn render_plain_value(ui: &mut egui::Ui, ctx: &egui::Context, key: &str, value: &str) {
+    let text = egui::RichText::new(value).size(get_adaptive_font_size(12.0, ctx));
+    match metadata_link(key, value) {
+        Some(url) => {
+            if ui.link(text).on_hover_text(&url).clicked()
+                && let Err(e) = opener::open(&url)
+            {
+                eprintln!("Failed to open {}: {}", url, e);
+            }
+        }
+        None => {
+            ui.label(text.color(egui::Color32::WHITE));
+        }
+    }
+}
+
+/// Message variant shown by the drag-and-drop overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropOverlay {
+    /// Every hovered file looks like a GGUF model.
+    Accepted,
+    /// At least one hovered file does not have a `.gguf` extension.
+    Unsupported,
+}
+
+impl DropOverlay {
+    /// Returns the translation key for the overlay message.
+    pub fn translation_key(self) -> &'static str {
+        match self {
+            DropOverlay::Accepted => "messages.drop_file",
+            DropOverlay::Unsupported => "messages.drop_unsupported",
+        }
+    }
+}
+
+pub use crate::format::has_gguf_extension;
+
+/// A file dropped onto the window.
+#[derive(Debug, Clone, Copy)]
+pub enum DroppedInput<'a> {
+    /// File on disk.
+    Path(&'a Path),
+    /// File contents, for platforms that deliver drops without a path.
+    Bytes(&'a [u8]),
+}
+
+/// Decides whether a dropped file should be loaded.
+///
+/// Paths need a `.gguf` extension and, when the file can be read, must start with
+/// the `GGUF` magic bytes; unreadable files are accepted so that the loader reports
+/// the actual I/O error. Byte drops are accepted when they start with the magic.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::panels::content::{accept_dropped, DroppedInput};
+/// use std::path::Path;
+///
+/// assert!(!accept_dropped(DroppedInput::Path(Path::new("notes.txt"))));
+/// assert!(accept_dropped(DroppedInput::Bytes(b"GGUF\x03\0\0\0")));
+/// assert!(!accept_dropped(DroppedInput::Bytes(b"PK\x03\x04")));
+/// ```
+pub fn accept_dropped(dropped: DroppedInput<'_>) -> bool {
+    match dropped {
+        DroppedInput::Path(path) => {
+            if !has_gguf_extension(path) {
+                return false;
+            }
+            // Unreadable or too short files are left to the loader, which reports the actual error
+            let mut magic = [0u8; 4];
+            let read = File::open(path).and_then(|mut file| file.read_exact(&mut magic));
+            read.is_err() || &magic == GGUF_MAGIC
+        }
+        DroppedInput::Bytes(bytes) => bytes.starts_with(GGUF_MAGIC),
+    }
+}
+
+/// Decides which overlay to show for the files currently dragged over the window.
+///
+/// Returns `None` when nothing is hovered, so the overlay disappears as soon as
+/// the drag leaves. Files without a known path (some platforms only report a MIME
+/// type while dragging) are assumed to be acceptable.
+///
+/// # Examples
+///
+/// ```rust
+/// use eframe::egui::HoveredFile;
+/// use inspector_gguf::gui::panels::content::{drop_overlay_for, DropOverlay};
+///
+/// let hovered = [HoveredFile { path: Some("model.gguf".into()), ..Default::default() }];
+/// assert_eq!(drop_overlay_for(&hovered), Some(DropOverlay::Accepted));
+/// assert_eq!(drop_overlay_for(&[]), None);
+/// ```
+pub fn drop_overlay_for(hovered: &[egui::HoveredFile]) -> Option<DropOverlay> {
+    if hovered.is_empty() {
+        return None;
+    }
+    let supported = hovered.iter().all(|file| match &file.path {
+        Some(path) => has_gguf_extension(path),
+        None => true,
+    });
+    Some(if supported { DropOverlay::Accepted } else { DropOverlay::Unsupported })
+}
+
+/// Paints a translucent full-window overlay while files are dragged over the app.
+///
+/// Does nothing when no files are hovered.
+pub fn render_drop_overlay<T: LanguageProvider>(ctx: &egui::Context, app: &T) {
+    let hovered = ctx.input(|i| i.raw.hovered_files.clone());
+    let Some(overlay) = drop_overlay_for(&hovered) else {
+        return;
+    };
+
+    let (icon, accent) = match overlay {
+        DropOverlay::Accepted => (egui_phosphor::regular::FILE_ARROW_DOWN, GADGET_YELLOW),
+        DropOverlay::Unsupported => (egui_phosphor::regular::PROHIBIT, DANGER_RED),
+    };
+
+    let painter = ctx.layer_painter(egui::LayerId::new(egui::Order::Foreground, egui::Id::new("drop_overlay")));
+    let screen_rect = ctx.screen_rect();
+    painter.rect_filled(screen_rect, 0.0, egui::Color32::from_black_alpha(180));
+    painter.rect_stroke(
+        screen_rect.shrink(get_adaptive_font_size(16.0, ctx)),
+        get_adaptive_font_size(12.0, ctx),
+        egui::Stroke::new(2.0, accent),
+        egui::StrokeKind::Inside,
+    );
+    painter.text(
+        screen_rect.center(),
+        egui::Align2::CENTER_CENTER,
+        format!("{} {}", icon, app.t(overlay.translation_key())),
+        egui::FontId::proportional(get_adaptive_font_size(24.0, ctx)),
+        accent,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hovered(path: Option<&str>) -> egui::HoveredFile {
+        egui::HoveredFile { path: path.map(std::path::PathBuf::from), ..Default::default() }
+    }
+
+    fn entry(key: &str) -> MetadataEntry {
+        MetadataEntry { key: key.to_string(), display_value: String::new(), full_value: None, raw_bytes: None, string_bytes: None }
+    }
+
+    fn keys(entries: &[&MetadataEntry]) -> Vec<String> {
+        entries.iter().map(|entry| entry.key.clone()).collect()
+    }
+
+    fn key_list(keys: &[&str]) -> Vec<String> {
+        keys.iter().map(|key| key.to_string()).collect()
+    }
+
+    #[test]
+    fn test_normalized_contains_matches_composed_and_decomposed() {
+        let composed = "caf\u{e9}";
+        let decomposed = "cafe\u{301}";
+        assert_ne!(composed, decomposed);
+
+        assert!(normalized_contains(&format!("tokens: {}, bar", decomposed), composed));
+        assert!(normalized_contains(&format!("tokens: {}, bar", composed), decomposed));
+        assert!(normalized_contains(decomposed, decomposed));
+        assert!(!normalized_contains(decomposed, "cafe\u{300}"));
+        assert!(normalized_contains("general.name", "name"));
+        assert!(normalized_contains("anything", ""));
+    }
+
+    #[test]
+    fn test_hex_view_bytes_only_for_strings_and_byte_arrays() {
+        let mut name = entry("general.name");
+        name.display_value = "caf\u{e9}".to_string();
+        name.string_bytes = Some("caf\u{e9}".as_bytes().to_vec());
+        assert_eq!(hex_view_bytes(&name), Some(&[b'c', b'a', b'f', 0xc3, 0xa9][..]));
+
+        // Formatter output is never used as the byte source
+        let mut template = entry("tokenizer.chat_template");
+        template.display_value = "{% for".to_string();
+        template.full_value = Some("{% for message in messages %}".to_string());
+        template.string_bytes = Some(b"{% for message in messages %}\n".to_vec());
+        assert_eq!(hex_view_bytes(&template), Some(&b"{% for message in messages %}\n"[..]));
+
+        let mut bytes = entry("some.bytes");
+        bytes.display_value = "[0, 255]".to_string();
+        bytes.raw_bytes = Some(vec![0, 0xff]);
+        assert_eq!(hex_view_bytes(&bytes), Some(&[0, 0xff][..]));
+
+        let mut count = entry("llama.block_count");
+        count.display_value = "32".to_string();
+        assert_eq!(hex_view_bytes(&count), None);
+    }
+
+    #[test]
+    fn test_matches_filter_checks_key_and_value() {
+        let mut entry = entry("general.name");
+        entry.display_value = "Cafe\u{301} model".to_string();
+
+        assert!(matches_filter(&entry, "general"));
+        assert!(matches_filter(&entry, "Caf\u{e9}"));
+        assert!(!matches_filter(&entry, "tokenizer"));
+    }
+
+    #[test]
+    fn test_fuzzy_filter_ranks_best_match_first() {
+        let entries: Vec<MetadataEntry> = [
+            "general.name",
+            "general.architecture",
+            "tokenizer.ggml.tokens",
+            "tokenizer.chat_template",
+            "qwen3.context_length",
+        ]
+        .iter()
+        .map(|key| entry(key))
+        .collect();
+        let matcher = SkimMatcherV2::default();
+
+        let ranked = filter_entries(&entries, "tkchat", FilterMode::Fuzzy);
+        assert_eq!(ranked[0].key, "tokenizer.chat_template");
+        assert!(fuzzy_score(&matcher, &entries[3], "tkchat") > fuzzy_score(&matcher, &entries[2], "tkchat"));
+
+        let ranked = filter_entries(&entries, "ctxlen", FilterMode::Fuzzy);
+        assert_eq!(keys(&ranked), ["qwen3.context_length"]);
+        assert!(filter_entries(&entries, "zzz", FilterMode::Fuzzy).is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_filter_empty_query_returns_all_entries() {
+        let entries: Vec<MetadataEntry> = ["general.name", "tokenizer.chat_template"].iter().map(|key| entry(key)).collect();
+
+        for query in ["", "  "] {
+            let all = filter_entries(&entries, query, FilterMode::Fuzzy);
+            assert_eq!(keys(&all), ["general.name", "tokenizer.chat_template"]);
+        }
+        assert_eq!(filter_entries(&entries, "", FilterMode::Substring).len(), 2);
+    }
+
+    #[test]
+    fn test_numeric_match_operators() {
+        assert!(numeric_match("40960", ">4096"));
+        assert!(!numeric_match("4096", ">4096"));
+        assert!(numeric_match("2", "<4"));
+        assert!(!numeric_match("4", "<4"));
+        assert!(numeric_match("4096", ">=4096"));
+        assert!(!numeric_match("4095", ">= 4096"));
+        assert!(numeric_match("2", "<=2"));
+        assert!(numeric_match("0.5", "<=2"));
+        assert!(!numeric_match("2.5", "<=2"));
+        assert!(numeric_match("1000000.0", "=1e6"));
+        assert!(!numeric_match("1000001", "=1e6"));
+        assert!(numeric_match(" -3 ", "< 0"));
+    }
+
+    #[test]
+    fn test_numeric_match_excludes_non_numeric() {
+        for value in ["qwen3", "", "[1, 2, 3]", "NaN", "4096 tokens"] {
+            assert!(!numeric_match(value, ">0"), "{:?} is not a number", value);
+        }
+        // Not numeric queries
+        assert!(!numeric_match("4096", "4096"));
+        assert!(!numeric_match("4096", ">"));
+        assert!(!numeric_match("4096", ">abc"));
+    }
+
+    #[test]
+    fn test_matches_filter_numeric_query() {
+        let mut context = entry("qwen3.context_length");
+        context.display_value = "40960".to_string();
+        let mut name = entry("general.name");
+        name.display_value = ">4096 context".to_string();
+
+        assert!(matches_filter(&context, ">4096"));
+        assert!(!matches_filter(&context, "<4096"));
+        assert!(!matches_filter(&name, ">4096"), "Numeric queries skip non-numeric values");
+        assert!(matches_filter(&name, ">4096 c"), "Other text starting with an operator is a substring search");
+    }
+
+    #[test]
+    fn test_suggest_keys_ranks_prefix_matches_first() {
+        let keys = key_list(&["general.name", "qwen3.block_count", "tokenizer.ggml.tokens", "general.tokenizer_hint"]);
+
+        assert_eq!(
+            suggest_keys(&keys, "tok", 8),
+            ["tokenizer.", "tokenizer.ggml.", "tokenizer.ggml.tokens", "general.tokenizer_hint"]
+        );
+        assert_eq!(suggest_keys(&keys, "general", 8), ["general.", "general.name", "general.tokenizer_hint"]);
+        assert_eq!(suggest_keys(&keys, "count", 8), ["qwen3.block_count"]);
+    }
+
+    #[test]
+    fn test_restore_filter_returns_stored_filter() {
+        let model = Path::new("models/model.gguf");
+        let mut settings = AppSettings { remember_filters: true, ..Default::default() };
+        assert_eq!(restore_filter(&settings, model), "", "Nothing stored yet");
+
+        remember_filter(&mut settings, model, "general.");
+        remember_filter(&mut settings, Path::new("models/other.gguf"), "tokenizer.");
+        assert_eq!(restore_filter(&settings, model), "general.");
+        let stored = GuiSettings::from_settings(&settings).file_filters;
+        assert_eq!(stored.len(), 2);
+        assert!(stored.iter().all(|stored| !stored.file.contains("model")), "Paths are hashed");
+
+        // Clearing the filter forgets it
+        remember_filter(&mut settings, model, "");
+        assert_eq!(restore_filter(&settings, model), "");
+        assert_eq!(GuiSettings::from_settings(&settings).file_filters.len(), 1);
+
+        remember_filter(&mut settings, model, "qwen3.");
+        settings.remember_filters = false;
+        assert_eq!(restore_filter(&settings, model), "", "Disabled setting restores nothing");
+        remember_filter(&mut settings, model, "ignored");
+        settings.remember_filters = true;
+        assert_eq!(restore_filter(&settings, model), "qwen3.");
+    }
+
+    #[test]
+    fn test_remember_filter_caps_stored_files() {
+        let mut settings = AppSettings { remember_filters: true, ..Default::default() };
+        for i in 0..=MAX_REMEMBERED_FILTERS {
+            remember_filter(&mut settings, Path::new(&format!("model-{}.gguf", i)), "general.");
+        }
+        assert_eq!(GuiSettings::from_settings(&settings).file_filters.len(), MAX_REMEMBERED_FILTERS);
+        assert_eq!(restore_filter(&settings, Path::new("model-0.gguf")), "", "Oldest file is dropped");
+    }
+
+    #[test]
+    fn test_suggest_keys_respects_limit_and_skips_input() {
+        let keys = key_list(&["general.name", "general.architecture", "general.file_type", "general.license"]);
+
+        assert_eq!(suggest_keys(&keys, "general", 2), ["general.", "general.architecture"]);
+        assert_eq!(suggest_keys(&keys, "general.name", 8), Vec::<String>::new());
+        assert!(suggest_keys(&keys, "", 8).is_empty());
+        assert!(suggest_keys(&keys, "general", 0).is_empty());
+        assert!(suggest_keys(&keys, "missing", 8).is_empty());
+    }
+
+    #[test]
+    fn test_preview_first_tokens_takes_leading_tokens() {
+        let full = "<|endoftext|>, !, \", #, $, %, &, ', (, )";
+        assert_eq!(preview_first_tokens(full, 8), ["<|endoftext|>", "!", "\"", "#", "$", "%", "&", "'"]);
+        assert_eq!(preview_first_tokens("a, b", 8), ["a", "b"]);
+        assert_eq!(preview_first_tokens("a, b, c, …", 8), ["a", "b", "c"]);
+        assert!(preview_first_tokens("", 8).is_empty());
+        assert!(preview_first_tokens("a, b", 0).is_empty());
+    }
+
+    #[test]
+    fn test_special_tokens_are_detected() {
+        for token in ["<|endoftext|>", "<s>", "</s>", "<unk>", "[CLS]", "<|im_start|>"] {
+            assert!(is_special_token(token), "{}", token);
+        }
+        for token in ["hello", "Ġthe", "<0x0A>", "<>", "<", "a<b>", "<not special>"] {
+            assert!(!is_special_token(token), "{}", token);
+        }
+    }
+
+    #[test]
+    fn test_partition_pinned_puts_pinned_first_in_pin_order() {
+        let entries = [entry("a"), entry("b"), entry("c"), entry("d")];
+        let refs: Vec<&MetadataEntry> = entries.iter().collect();
+        let pinned = vec!["c".to_string(), "missing".to_string(), "a".to_string()];
+
+        let (pinned_entries, rest) = partition_pinned(&refs, &pinned);
+        assert_eq!(keys(&pinned_entries), ["c", "a"]);
+        assert_eq!(keys(&rest), ["b", "d"]);
+
+        let ordered: Vec<&MetadataEntry> = pinned_entries.into_iter().chain(rest).collect();
+        assert_eq!(keys(&ordered), ["c", "a", "b", "d"]);
+    }
+
+    #[test]
+    fn test_partition_pinned_without_pins_keeps_all_entries() {
+        let entries = [entry("a"), entry("b")];
+        let refs: Vec<&MetadataEntry> = entries.iter().collect();
+
+        let (pinned_entries, rest) = partition_pinned(&refs, &[]);
+        assert!(pinned_entries.is_empty());
+        assert_eq!(keys(&rest), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_toggle_pinned() {
+        let mut pinned = Vec::new();
+        assert!(toggle_pinned(&mut pinned, "general.name"));
+        assert!(toggle_pinned(&mut pinned, "general.architecture"));
+        assert_eq!(pinned, ["general.name", "general.architecture"]);
+
+        assert!(!toggle_pinned(&mut pinned, "general.name"));
+        assert_eq!(pinned, ["general.architecture"]);
+    }
+
+    #[test]
+    fn test_looks_like_url() {
+        assert!(looks_like_url("http://example.com"));
+        assert!(looks_like_url("https://huggingface.co/Qwen/Qwen3-0.6B"));
+        assert!(looks_like_url("  HTTPS://github.com/ggml-org/llama.cpp  "));
+        assert!(!looks_like_url("ftp://example.com/model.gguf"));
+        assert!(!looks_like_url("https://"));
+        assert!(!looks_like_url("see https://example.com"));
+        assert!(!looks_like_url("https://example.com/a b"));
+        assert!(!looks_like_url("Qwen3-0.6B"));
+    }
+
+    #[test]
+    fn test_license_link() {
+        assert_eq!(metadata_link("general.license", "MIT").as_deref(), Some("https://spdx.org/licenses/MIT.html"));
+        assert_eq!(metadata_link("general.license", "other"), None);
+        assert_eq!(metadata_link("general.name", "MIT"), None);
+        assert_eq!(metadata_link("general.url", "https://example.com").as_deref(), Some("https://example.com"));
+    }
+
+    fn license_entry(key: &str, value: &str) -> MetadataEntry {
+        MetadataEntry { display_value: value.to_string(), ..entry(key) }
+    }
+
+    #[test]
+    fn test_extract_license_info_without_license_keys() {
+        assert_eq!(extract_license_info(&[]), None);
+        let entries = [license_entry("general.name", "Qwen3"), license_entry("general.license", "  ")];
+        assert_eq!(extract_license_info(&entries), None, "Blank values do not count as a license");
+    }
+
+    #[test]
+    fn test_extract_license_info_combinations() {
+        let entries = [
+            license_entry("general.license", "other"),
+            license_entry("general.license.name", "Llama 3 Community License"),
+            license_entry("general.license.link", "https://llama.meta.com/llama3/license"),
+        ];
+        let info = extract_license_info(&entries).unwrap();
+        assert_eq!(info.id.as_deref(), Some("other"));
+        assert_eq!(info.name.as_deref(), Some("Llama 3 Community License"));
+        assert_eq!(info.link.as_deref(), Some("https://llama.meta.com/llama3/license"));
+        assert_eq!(info.text, None);
+        assert_eq!(
+            info.clipboard_text(),
+            "Llama 3 Community License\nother\nhttps://llama.meta.com/llama3/license"
+        );
+
+        // An explicit link wins over the SPDX page
+        let entries = [license_entry("general.license", "MIT"), license_entry("general.license.link", "https://example.com/LICENSE")];
+        assert_eq!(extract_license_info(&entries).unwrap().link.as_deref(), Some("https://example.com/LICENSE"));
+    }
+
+    #[test]
+    fn test_extract_license_info_embedded_text() {
+        let text = "Permission is hereby granted, free of charge...\n\nTHE SOFTWARE IS PROVIDED \"AS IS\"";
+        let entries = [MetadataEntry {
+            display_value: "Permission is hereby granted…".to_string(),
+            full_value: Some(text.to_string()),
+            ..entry("general.license_text")
+        }];
+        let info = extract_license_info(&entries).unwrap();
+        assert_eq!(info.id, None);
+        assert_eq!(info.link, None);
+        assert_eq!(info.text.as_deref(), Some(text), "The untruncated value is used");
+        assert_eq!(info.clipboard_text(), text);
+
+        let entries = [license_entry("general.license.content", "second"), license_entry("general.license.text", "first")];
+        assert_eq!(extract_license_info(&entries).unwrap().text.as_deref(), Some("first"));
+    }
+
+    #[test]
+    fn test_drop_overlay_hidden_without_hovered_files() {
+        assert_eq!(drop_overlay_for(&[]), None);
+    }
+
+    #[test]
+    fn test_drop_overlay_accepts_gguf_files() {
+        let files = [hovered(Some("/models/a.gguf")), hovered(Some("B.GGUF")), hovered(None)];
+        assert_eq!(drop_overlay_for(&files), Some(DropOverlay::Accepted));
+    }
+
+    #[test]
+    fn test_drop_overlay_rejects_other_files() {
+        let files = [hovered(Some("model.gguf")), hovered(Some("notes.txt"))];
+        assert_eq!(drop_overlay_for(&files), Some(DropOverlay::Unsupported));
+        assert_eq!(drop_overlay_for(&[hovered(Some("model"))]), Some(DropOverlay::Unsupported));
+    }
+
+    #[test]
+    fn test_accept_dropped_paths() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let model = dir.path().join("model.GGUF");
+        std::fs::write(&model, b"GGUF\x03\0\0\0").unwrap();
+        let renamed = dir.path().join("renamed.gguf");
+        std::fs::write(&renamed, b"hello world").unwrap();
+        let notes = dir.path().join("notes.txt");
+        std::fs::write(&notes, b"GGUF but not really").unwrap();
+
+        assert!(accept_dropped(DroppedInput::Path(&model)));
+        assert!(!accept_dropped(DroppedInput::Path(&renamed)), "Wrong magic is rejected");
+        assert!(!accept_dropped(DroppedInput::Path(&notes)), "Other extensions are rejected");
+        assert!(accept_dropped(DroppedInput::Path(&dir.path().join("missing.gguf"))), "Load reports I/O errors");
+    }
+
+    #[test]
+    fn test_accept_dropped_bytes() {
+        assert!(accept_dropped(DroppedInput::Bytes(b"GGUF\x03\0\0\0\0\0\0\0")));
+        assert!(!accept_dropped(DroppedInput::Bytes(b"not a model")));
+        assert!(!accept_dropped(DroppedInput::Bytes(b"GGU")));
+        assert!(!accept_dropped(DroppedInput::Bytes(&[])));
+    }
+
+    #[test]
+    fn test_escape_whitespace_newlines() {
+        assert_eq!(escape_whitespace("a\nb\n"), "a\\nb\\n");
+        assert_eq!(escape_whitespace("no whitespace escapes"), "no whitespace escapes");
+    }
+
+    #[test]
+    fn test_escape_whitespace_tabs() {
+        assert_eq!(escape_whitespace("\tindented\t"), "\\tindented\\t");
+    }
+
+    #[test]
+    fn test_escape_whitespace_carriage_returns() {
+        assert_eq!(escape_whitespace("line\r\nnext"), "line\\r\\nnext");
+        assert_eq!(escape_whitespace("юникод\r"), "юникод\\r");
+    }
+
+    #[test]
+    fn test_paginate_first_page() {
+        let items: Vec<usize> = (0..250).collect();
+        assert_eq!(paginate(&items, 0, 100), &items[..100]);
+        assert_eq!(page_count(items.len(), 100), 3);
+    }
+
+    #[test]
+    fn test_paginate_middle_page() {
+        let items: Vec<usize> = (0..250).collect();
+        assert_eq!(paginate(&items, 1, 100), &items[100..200]);
+    }
+
+    #[test]
+    fn test_paginate_last_page_is_partial() {
+        let items: Vec<usize> = (0..250).collect();
+        assert_eq!(paginate(&items, 2, 100), &items[200..]);
+
+        let exact: Vec<usize> = (0..200).collect();
+        assert_eq!(page_count(exact.len(), 100), 2);
+        assert_eq!(paginate(&exact, 1, 100), &exact[100..]);
+    }
+
+    #[test]
+    fn test_paginate_out_of_range_clamps_to_last_page() {
+        let items: Vec<usize> = (0..250).collect();
+        assert_eq!(paginate(&items, 3, 100), &items[200..]);
+        assert_eq!(paginate(&items, usize::MAX, 100), &items[200..]);
+
+        let empty: Vec<usize> = Vec::new();
+        assert!(paginate(&empty, 5, 100).is_empty());
+        assert_eq!(page_count(0, 100), 1);
+        assert_eq!(paginate(&items, 7, 0).len(), items.len());
+    }
+
+    #[test]
+    fn test_clipboard_text_uses_display_value() {
+        let entry = MetadataEntry {
+            key: "general.architecture".to_string(),
+            display_value: "llama".to_string(),
+            full_value: None,
+            raw_bytes: None,
+            string_bytes: None,
+        };
+        assert_eq!(entry_clipboard_text(&entry), "general.architecture: llama");
+    }
+
+    #[test]
+    fn test_clipboard_text_prefers_full_value() {
+        let entry = MetadataEntry {
+            key: "tokenizer.ggml.tokens".to_string(),
+            display_value: "a, b, c, …".to_string(),
+            full_value: Some("a, b, c, d, e, f".to_string()),
+            raw_bytes: None,
+            string_bytes: None,
+        };
+        assert_eq!(entry_clipboard_text(&entry), "tokenizer.ggml.tokens: a, b, c, d, e, f");
+    }
+}
@@ -14,6 +14,7 @@
 //! ## Content Panels
 //! - **Chat Template Viewer**: Dedicated panel for viewing large chat templates
 //! - **Token Data Viewer**: Specialized viewer for GGML tokens and merges
+//! - **Hex View**: `xxd`-style dump of a byte-array or string value
 //! - **Right-Side Panels**: Resizable panels that don't block main content
 //!
 //! # Design Features
@@ -30,8 +31,11 @@
 //! - **Keyboard Navigation**: Standard dialog keyboard shortcuts
 
 use eframe::egui;
+use crate::format::{hex_dump, hex_dump_line, HEX_DUMP_WIDTH};
 use crate::localization::{LanguageProvider, LocalizationManager};
 use crate::gui::layout::get_adaptive_font_size;
+use crate::gui::panels::content::escape_whitespace;
+use crate::gui::highlight::{detect_content_kind, highlight_layout};
 use crate::gui::theme::{GADGET_YELLOW, TECH_GRAY};
 use crate::gui::updater::check_for_updates;
 
@@ -252,7 +256,7 @@ pub fn render_about_dialog<T: LanguageProvider>(
         .show(ctx, |ui| {
             ui.vertical(|ui| {
                 ui.heading(egui::RichText::new(app.t("app.title")).size(get_adaptive_font_size(18.0, ctx)));
-                ui.label(egui::RichText::new(format!("{}: 0.1.0", app.t("app.version"))).size(get_adaptive_font_size(14.0, ctx)));
+                ui.label(egui::RichText::new(format!("{}: {}", app.t("app.version"), crate::gui::updater::version_info())).size(get_adaptive_font_size(14.0, ctx)));
                 ui.label(egui::RichText::new(app.t("about.description")).size(get_adaptive_font_size(14.0, ctx)));
                 ui.label(egui::RichText::new(app.t("about.built_with")).size(get_adaptive_font_size(14.0, ctx)));
                 ui.add_space(get_adaptive_font_size(8.0, ctx));
@@ -355,9 +359,10 @@ pub fn render_about_dialog<T: LanguageProvider>(
 /// - **Resizable Interface**: Users can adjust panel width as needed
 ///
 /// ## User Interface
-/// - **Header Controls**: Copy button and close button in panel header
+/// - **Header Controls**: Copy button, escape toggle and close button in panel header
 /// - **Scrollable Content**: Vertical scrolling for large content
 /// - **Monospace Text**: Preserves formatting for structured data
+/// - **Syntax Highlighting**: JSON and Jinja content is colored (see [`crate::gui::highlight`])
 /// - **Consistent Styling**: Matches application theme and color scheme
 ///
 /// # Parameters
@@ -366,9 +371,11 @@ pub fn render_about_dialog<T: LanguageProvider>(
 /// * `selected_chat_template` - Mutable reference to chat template content
 /// * `selected_ggml_tokens` - Mutable reference to token data content
 /// * `selected_ggml_merges` - Mutable reference to merge data content
+/// * `show_escapes` - Shows newlines and tabs as `\n`/`\t` (see [`escape_whitespace`])
 /// * `t_chat_template` - Localized title for chat template panel
 /// * `t_ggml_tokens` - Localized title for tokens panel
 /// * `t_ggml_merges` - Localized title for merges panel
+/// * `t_show_escapes` - Localized tooltip of the escape toggle
 ///
 /// # Panel Management
 ///
@@ -395,30 +402,37 @@ pub fn render_about_dialog<T: LanguageProvider>(
 ///     selected_chat_template: &mut Option<String>,
 ///     selected_ggml_tokens: &mut Option<String>,
 ///     selected_ggml_merges: &mut Option<String>,
+///     show_escapes: &mut bool,
 /// ) {
 ///     let t_chat_template = "Chat Template";
 ///     let t_ggml_tokens = "GGML Tokens";
 ///     let t_ggml_merges = "GGML Merges";
+///     let t_show_escapes = "Show whitespace as escape sequences";
 ///
 ///     render_right_side_panels(
 ///         ctx,
 ///         selected_chat_template,
 ///         selected_ggml_tokens,
 ///         selected_ggml_merges,
+///         show_escapes,
 ///         &t_chat_template,
 ///         &t_ggml_tokens,
 ///         &t_ggml_merges,
+///         &t_show_escapes,
 ///     );
 /// }
 /// ```
+#[allow(clippy::too_many_arguments)]
 pub fn render_right_side_panels(
     ctx: &egui::Context,
     selected_chat_template: &mut Option<String>,
     selected_ggml_tokens: &mut Option<String>,
     selected_ggml_merges: &mut Option<String>,
+    show_escapes: &mut bool,
     t_chat_template: &str,
     t_ggml_tokens: &str,
     t_ggml_merges: &str,
+    t_show_escapes: &str,
 ) {
     // Панель для chat template
     if selected_chat_template.is_some() {
@@ -456,6 +470,8 @@ pub fn render_right_side_panels(
                                 ctx.copy_text(content.clone());
                             }
                         }
+                        // Raw whitespace or `\n`/`\t` escapes; copying always uses the raw value
+                        ui.toggle_value(show_escapes, "\\n").on_hover_text(t_show_escapes);
 
                         // Центрируем заголовок в оставшемся пространстве
                         let available_size = ui.available_size_before_wrap();
@@ -480,8 +496,10 @@ pub fn render_right_side_panels(
 
                     // ScrollArea для содержимого
                     if let Some(content) = selected_chat_template {
+                        let shown = if *show_escapes { escape_whitespace(content) } else { content.clone() };
+                        let font_id = egui::FontId::monospace(get_adaptive_font_size(12.0, ctx));
                         egui::ScrollArea::vertical().show(ui, |ui| {
-                            ui.label(egui::RichText::new(content.as_str()).monospace().color(TECH_GRAY).size(get_adaptive_font_size(12.0, ctx)));
+                            ui.label(highlight_layout(&shown, detect_content_kind(content), font_id, ui.visuals()));
                         });
                     }
                 });
@@ -524,6 +542,8 @@ pub fn render_right_side_panels(
                                 ctx.copy_text(content.clone());
                             }
                         }
+                        // Raw whitespace or `\n`/`\t` escapes; copying always uses the raw value
+                        ui.toggle_value(show_escapes, "\\n").on_hover_text(t_show_escapes);
 
                         // Центрируем заголовок в оставшемся пространстве
                         let available_size = ui.available_size_before_wrap();
@@ -548,8 +568,10 @@ pub fn render_right_side_panels(
 
                     // ScrollArea для содержимого
                     if let Some(content) = selected_ggml_tokens {
+                        let shown = if *show_escapes { escape_whitespace(content) } else { content.clone() };
+                        let font_id = egui::FontId::monospace(get_adaptive_font_size(12.0, ctx));
                         egui::ScrollArea::vertical().show(ui, |ui| {
-                            ui.label(egui::RichText::new(content.as_str()).monospace().color(TECH_GRAY).size(get_adaptive_font_size(12.0, ctx)));
+                            ui.label(highlight_layout(&shown, detect_content_kind(content), font_id, ui.visuals()));
                         });
                     }
                 });
@@ -592,6 +614,8 @@ pub fn render_right_side_panels(
                                 ctx.copy_text(content.clone());
                             }
                         }
+                        // Raw whitespace or `\n`/`\t` escapes; copying always uses the raw value
+                        ui.toggle_value(show_escapes, "\\n").on_hover_text(t_show_escapes);
 
                         // Центрируем заголовок в оставшемся пространстве
                         let available_size = ui.available_size_before_wrap();
@@ -616,11 +640,148 @@ pub fn render_right_side_panels(
 
                     // ScrollArea для содержимого
                     if let Some(content) = selected_ggml_merges {
+                        let shown = if *show_escapes { escape_whitespace(content) } else { content.clone() };
+                        let font_id = egui::FontId::monospace(get_adaptive_font_size(12.0, ctx));
                         egui::ScrollArea::vertical().show(ui, |ui| {
-                            ui.label(egui::RichText::new(content.as_str()).monospace().color(TECH_GRAY).size(get_adaptive_font_size(12.0, ctx)));
+                            ui.label(highlight_layout(&shown, detect_content_kind(content), font_id, ui.visuals()));
                         });
                     }
                 });
             });
     }
-}
\ No newline at end of file
+}
+/// Decodes a `data:image/...;base64,` URI into an egui image.
+///
+/// The URI is the full content stored for embedded preview images by
+/// [`crate::format::image_data_uri`].
+///
+/// # Errors
+///
+/// Returns an error if the URI is not a base64 image data URI or the image
+/// cannot be decoded.
+pub fn decode_image_data_uri(uri: &str) -> Result<egui::ColorImage, Box<dyn std::error::Error>> {
+    use base64::Engine;
+
+    let (_, encoded) = uri
+        .strip_prefix("data:image/")
+        .and_then(|rest| rest.split_once(";base64,"))
+        .ok_or("not a base64 image data URI")?;
+    let bytes = base64::engine::general_purpose::STANDARD.decode(encoded)?;
+    let rgba = image::load_from_memory(&bytes)?.to_rgba8();
+    let size = [rgba.width() as usize, rgba.height() as usize];
+    Ok(egui::ColorImage::from_rgba_unmultiplied(size, rgba.as_raw()))
+}
+
+/// Renders the right-side panel showing an embedded preview image.
+///
+/// The decoded texture is cached in egui memory, keyed by the data URI, so the
+/// image is decoded only once while the panel stays open.
+///
+/// # Parameters
+///
+/// * `ctx` - egui context
+/// * `selected_image` - Data URI of the image to show; set to `None` when the panel is closed
+/// * `title` - Localized panel title
+pub fn render_image_panel(ctx: &egui::Context, selected_image: &mut Option<String>, title: &str) {
+    let Some(uri) = selected_image.clone() else {
+        return;
+    };
+
+    let texture_id = egui::Id::new(("image_preview", &uri));
+    let texture = ctx.data(|d| d.get_temp::<Result<egui::TextureHandle, String>>(texture_id));
+    let texture = texture.unwrap_or_else(|| {
+        let loaded = decode_image_data_uri(&uri)
+            .map(|image| ctx.load_texture("image_preview", image, egui::TextureOptions::LINEAR))
+            .map_err(|e| e.to_string());
+        ctx.data_mut(|d| d.insert_temp(texture_id, loaded.clone()));
+        loaded
+    });
+
+    egui::SidePanel::right("image_preview_panel")
+        .resizable(true)
+        .default_width(400.0)
+        .min_width(300.0)
+        .show(ctx, |ui| {
+            ui.vertical(|ui| {
+                ui.add_space(4.0);
+
+                ui.horizontal(|ui| {
+                    ui.heading(egui::RichText::new(title).color(GADGET_YELLOW).size(get_adaptive_font_size(16.0, ctx)));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button(egui_phosphor::regular::X).clicked() {
+                            *selected_image = None;
+                        }
+                    });
+                });
+                ui.add_space(8.0);
+
+                match &texture {
+                    Ok(texture) => {
+                        egui::ScrollArea::both().show(ui, |ui| {
+                            ui.add(egui::Image::new(texture).max_width(ui.available_width()));
+                        });
+                    }
+                    Err(e) => {
+                        ui.label(egui::RichText::new(e).color(TECH_GRAY).size(get_adaptive_font_size(12.0, ctx)));
+                    }
+                }
+            });
+        });
+}
+
+/// Renders the right-side hex view of a value's bytes.
+///
+/// Rows are formatted with [`crate::format::hex_dump_line`] as they scroll into
+/// view, so large byte arrays stay responsive. The copy button copies the whole
+/// [`crate::format::hex_dump`].
+///
+/// # Parameters
+///
+/// * `ctx` - egui context
+/// * `selected_hex` - Key and bytes of the value to show; set to `None` when the panel is closed
+/// * `title` - Localized panel title
+/// * `copy_text` - Localized copy button label
+pub fn render_hex_panel(ctx: &egui::Context, selected_hex: &mut Option<(String, Vec<u8>)>, title: &str, copy_text: &str) {
+    let Some((key, bytes)) = selected_hex.as_ref() else {
+        return;
+    };
+    let mut close = false;
+
+    egui::SidePanel::right("hex_view_panel")
+        .resizable(true)
+        .default_width(560.0)
+        .min_width(300.0)
+        .show(ctx, |ui| {
+            ui.vertical(|ui| {
+                ui.add_space(4.0);
+
+                ui.horizontal(|ui| {
+                    ui.heading(egui::RichText::new(title).color(GADGET_YELLOW).size(get_adaptive_font_size(16.0, ctx)));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button(egui_phosphor::regular::X).clicked() {
+                            close = true;
+                        }
+                        if ui.button(format!("{} {}", egui_phosphor::regular::COPY, copy_text)).clicked() {
+                            ctx.copy_text(hex_dump(bytes));
+                        }
+                    });
+                });
+                ui.label(egui::RichText::new(key).color(TECH_GRAY).size(get_adaptive_font_size(12.0, ctx)));
+                ui.add_space(8.0);
+
+                let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+                let rows = bytes.len().div_ceil(HEX_DUMP_WIDTH);
+                egui::ScrollArea::both().auto_shrink([false, false]).show_rows(ui, row_height, rows, |ui, range| {
+                    for row in range {
+                        let start = row * HEX_DUMP_WIDTH;
+                        let chunk = &bytes[start..bytes.len().min(start + HEX_DUMP_WIDTH)];
+                        ui.add(egui::Label::new(egui::RichText::new(hex_dump_line(start, chunk)).monospace()).extend());
+                    }
+                });
+            });
+        });
+
+    if close {
+        *selected_hex = None;
+    }
+}
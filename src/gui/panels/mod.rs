@@ -107,6 +107,7 @@
 pub mod sidebar;
 pub mod content;
 pub mod dialogs;
+pub mod scan;
 
 // Re-export panel functionality for clean API access
 
@@ -133,4 +134,14 @@ pub use dialogs::render_about_dialog;
 /// Renders specialized right-side panels for viewing large content.
 ///
 /// See [`dialogs::render_right_side_panels`] for detailed documentation.
-pub use dialogs::render_right_side_panels;
\ No newline at end of file
+pub use dialogs::render_right_side_panels;
+/// Renders the right-side panel showing an embedded preview image.
+///
+/// See [`dialogs::render_image_panel`] for detailed documentation.
+pub use dialogs::render_image_panel;
+/// Renders the right-side hex view of a value's bytes.
+///
+/// See [`dialogs::render_hex_panel`] for detailed documentation.
+pub use dialogs::render_hex_panel;
+
+pub use scan::render_scan_window;
@@ -0,0 +1,280 @@
+//! Folder scan window listing every GGUF file in a directory.
+//!
+//! The "Scan folder" sidebar action summarizes all GGUF files of a directory on a
+//! background thread (see [`crate::gui::loader::scan_directory_async`]) and shows
+//! them in a sortable table with name, size, architecture, parameter count and
+//! quantization mix. Clicking a file name opens that file in the main view.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use eframe::egui;
+use crate::format::{format_count, human_bytes, FileScanResult};
+use crate::gui::layout::get_adaptive_font_size;
+use crate::gui::loader::{scan_directory_async, ScanResult};
+use crate::gui::theme::{DANGER_RED, GADGET_YELLOW, TECH_GRAY};
+use crate::localization::LanguageProvider;
+
+/// Column of the folder scan table used for sorting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScanSortColumn {
+    /// File name (case-insensitive).
+    #[default]
+    Name,
+    /// File size in bytes.
+    Size,
+    /// Model architecture.
+    Architecture,
+    /// Total parameter count.
+    Parameters,
+    /// Quantization summary text.
+    Quantization,
+}
+
+impl ScanSortColumn {
+    /// All columns in display order.
+    pub const ALL: [ScanSortColumn; 5] = [
+        ScanSortColumn::Name,
+        ScanSortColumn::Size,
+        ScanSortColumn::Architecture,
+        ScanSortColumn::Parameters,
+        ScanSortColumn::Quantization,
+    ];
+
+    /// Returns the translation key of the column header.
+    pub fn translation_key(&self) -> &'static str {
+        match self {
+            ScanSortColumn::Name => "scan.name",
+            ScanSortColumn::Size => "scan.size",
+            ScanSortColumn::Architecture => "scan.architecture",
+            ScanSortColumn::Parameters => "scan.parameters",
+            ScanSortColumn::Quantization => "scan.quantization",
+        }
+    }
+}
+
+/// Sorts scan results by `column`; files that failed to parse sort as missing values.
+pub fn sort_scan_results(results: &mut [FileScanResult], column: ScanSortColumn, ascending: bool) {
+    results.sort_by(|a, b| {
+        let ordering = match column {
+            ScanSortColumn::Name => a.file_name().to_lowercase().cmp(&b.file_name().to_lowercase()),
+            ScanSortColumn::Size => a.size.cmp(&b.size),
+            ScanSortColumn::Architecture => a.architecture().cmp(&b.architecture()),
+            ScanSortColumn::Parameters => a.parameter_count().cmp(&b.parameter_count()),
+            ScanSortColumn::Quantization => a.quant_summary().cmp(&b.quant_summary()),
+        };
+        if ascending { ordering } else { ordering.reverse() }
+    });
+}
+
+/// State of the folder scan window and its background scan.
+pub struct FolderScan {
+    /// Whether the window is visible.
+    pub open: bool,
+    /// Whether a background scan is running.
+    pub scanning: bool,
+    /// Directory of the current or last scan.
+    pub directory: Option<PathBuf>,
+    /// Progress of the running scan (0.0 to 1.0).
+    pub progress: Arc<Mutex<f32>>,
+    /// Result container filled by the scan thread.
+    pub result: ScanResult,
+    /// Results of the last finished scan, in display order.
+    pub results: Vec<FileScanResult>,
+    /// Column the table is sorted by.
+    pub sort_column: ScanSortColumn,
+    /// Whether the sort order is ascending.
+    pub ascending: bool,
+}
+
+impl Default for FolderScan {
+    fn default() -> Self {
+        Self {
+            open: false,
+            scanning: false,
+            directory: None,
+            progress: Arc::new(Mutex::new(0.0)),
+            result: Arc::new(Mutex::new(None)),
+            results: Vec::new(),
+            sort_column: ScanSortColumn::default(),
+            ascending: true,
+        }
+    }
+}
+
+impl FolderScan {
+    /// Opens the window and starts scanning `dir` in the background.
+    pub fn start(&mut self, dir: PathBuf) {
+        self.open = true;
+        self.scanning = true;
+        self.directory = Some(dir.clone());
+        self.results.clear();
+        // Fresh containers, so a scan that is still running cannot overwrite this one
+        self.progress = Arc::new(Mutex::new(0.0));
+        self.result = Arc::new(Mutex::new(None));
+        scan_directory_async(dir, Arc::clone(&self.progress), Arc::clone(&self.result));
+    }
+
+    /// Picks up the results of a finished scan.
+    pub fn poll(&mut self) {
+        if !self.scanning {
+            return;
+        }
+        let finished = self.result.lock().unwrap().take();
+        if let Some(mut results) = finished {
+            sort_scan_results(&mut results, self.sort_column, self.ascending);
+            self.results = results;
+            self.scanning = false;
+        }
+    }
+
+    /// Sorts by `column`, toggling the direction when it is already the sort column.
+    pub fn sort_by(&mut self, column: ScanSortColumn) {
+        if self.sort_column == column {
+            self.ascending = !self.ascending;
+        } else {
+            self.sort_column = column;
+            self.ascending = true;
+        }
+        sort_scan_results(&mut self.results, self.sort_column, self.ascending);
+    }
+}
+
+/// Renders the folder scan window.
+///
+/// Returns the path of the file whose name was clicked, which the caller should open.
+pub fn render_scan_window<T: LanguageProvider>(
+    ctx: &egui::Context,
+    scan: &mut FolderScan,
+    provider: &T,
+) -> Option<PathBuf> {
+    scan.poll();
+    if !scan.open {
+        return None;
+    }
+
+    let mut open = scan.open;
+    let mut selected = None;
+    let title = format!("{} {}", egui_phosphor::regular::FOLDERS, provider.t("scan.title"));
+
+    egui::Window::new(title)
+        .open(&mut open)
+        .default_size([720.0, 420.0])
+        .resizable(true)
+        .show(ctx, |ui| {
+            if let Some(dir) = &scan.directory {
+                ui.label(egui::RichText::new(dir.display().to_string())
+                    .color(TECH_GRAY)
+                    .size(get_adaptive_font_size(12.0, ctx)));
+                ui.add_space(get_adaptive_font_size(4.0, ctx));
+            }
+
+            if scan.scanning {
+                let progress = *scan.progress.lock().unwrap();
+                ui.add(egui::ProgressBar::new(progress)
+                    .text(provider.format_percent(progress))
+                    .fill(egui::Color32::from_rgb(30, 58, 138)));
+                ui.label(egui::RichText::new(provider.t("scan.scanning")).color(TECH_GRAY));
+                ctx.request_repaint();
+                return;
+            }
+
+            if scan.results.is_empty() {
+                ui.label(egui::RichText::new(provider.t("scan.empty")).color(TECH_GRAY));
+                return;
+            }
+
+            let mut clicked_column = None;
+            egui::ScrollArea::both().auto_shrink([false; 2]).show(ui, |ui| {
+                egui::Grid::new("folder_scan_table")
+                    .striped(true)
+                    .num_columns(ScanSortColumn::ALL.len())
+                    .spacing([16.0, 6.0])
+                    .show(ui, |ui| {
+                        for column in ScanSortColumn::ALL {
+                            let mut header = provider.t(column.translation_key());
+                            if column == scan.sort_column {
+                                let arrow = if scan.ascending {
+                                    egui_phosphor::regular::CARET_UP
+                                } else {
+                                    egui_phosphor::regular::CARET_DOWN
+                                };
+                                header = format!("{} {}", header, arrow);
+                            }
+                            if ui.button(egui::RichText::new(header).color(GADGET_YELLOW)).clicked() {
+                                clicked_column = Some(column);
+                            }
+                        }
+                        ui.end_row();
+
+                        for file in &scan.results {
+                            if ui.link(file.file_name()).on_hover_text(file.path.display().to_string()).clicked() {
+                                selected = Some(file.path.clone());
+                            }
+                            ui.label(human_bytes(file.size));
+                            match &file.error {
+                                Some(error) => {
+                                    ui.label(egui::RichText::new(provider.t("scan.error")).color(DANGER_RED))
+                                        .on_hover_text(error);
+                                    ui.label("");
+                                    ui.label("");
+                                }
+                                None => {
+                                    ui.label(file.architecture().unwrap_or("—"));
+                                    ui.label(file.parameter_count().map(format_count).unwrap_or_default());
+                                    ui.label(file.quant_summary());
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+
+            if let Some(column) = clicked_column {
+                scan.sort_by(column);
+            }
+        });
+
+    scan.open = open;
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(name: &str, size: u64) -> FileScanResult {
+        FileScanResult {
+            path: PathBuf::from(name),
+            size,
+            report: None,
+            error: Some("unreadable".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_sort_scan_results_by_column() {
+        let mut results = vec![result("b.gguf", 30), result("A.gguf", 10), result("c.gguf", 20)];
+
+        sort_scan_results(&mut results, ScanSortColumn::Name, true);
+        let names: Vec<_> = results.iter().map(FileScanResult::file_name).collect();
+        assert_eq!(names, ["A.gguf", "b.gguf", "c.gguf"]);
+
+        sort_scan_results(&mut results, ScanSortColumn::Size, false);
+        let sizes: Vec<_> = results.iter().map(|r| r.size).collect();
+        assert_eq!(sizes, [30, 20, 10]);
+    }
+
+    #[test]
+    fn test_sort_by_toggles_direction() {
+        let mut scan = FolderScan {
+            results: vec![result("a.gguf", 1), result("b.gguf", 2)],
+            ..FolderScan::default()
+        };
+
+        scan.sort_by(ScanSortColumn::Size);
+        assert!(scan.ascending);
+        scan.sort_by(ScanSortColumn::Size);
+        assert!(!scan.ascending);
+        assert_eq!(scan.results[0].size, 2);
+    }
+}
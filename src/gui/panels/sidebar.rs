@@ -37,7 +37,7 @@ use std::sync::{Arc, Mutex};
 use crate::localization::LanguageProvider;
 use crate::gui::layout::{get_sidebar_width, get_adaptive_font_size, get_adaptive_button_width};
 use crate::gui::theme::TECH_GRAY;
-use crate::gui::export::{export_csv, export_yaml, export_markdown_to_file, export_html_to_file, export_markdown, export_pdf_from_markdown};
+use crate::gui::export::{export_base_name, export_csv, export_yaml, export_markdown_to_file, export_html_to_file, export_markdown, export_pdf_from_markdown};
 use crate::gui::loader::{load_gguf_metadata_async, LoadingResult, MetadataEntry};
 
 /// Renders the left sidebar panel with action buttons and export controls.
@@ -177,6 +177,10 @@ pub fn render_sidebar<T: LanguageProvider>(
             .color(TECH_GRAY),
     );
     let small_button_height = get_adaptive_font_size(28.0, ctx);
+    // Default file names are derived from the model name
+    let export_name = export_base_name(
+        metadata.iter().find(|entry| entry.key == "general.name").map(|entry| entry.display_value.as_str()),
+    );
     
     // CSV Export button
     let csv_text = format!("{} {}", egui_phosphor::regular::FILE_CSV, app.t("export.csv"));
@@ -191,7 +195,7 @@ pub fn render_sidebar<T: LanguageProvider>(
             ),
         )
         .clicked()
-        && let Some(path) = FileDialog::new().save_file()
+        && let Some(path) = FileDialog::new().set_file_name(format!("{}.csv", export_name)).save_file()
         && let Err(e) = export_csv(&metadata.iter().map(|entry| (&entry.key, &entry.display_value)).collect::<Vec<_>>(), &path)
     {
         eprintln!("{}", app.t_with_args("messages.export_failed", &[&e.to_string()]));
@@ -210,7 +214,7 @@ pub fn render_sidebar<T: LanguageProvider>(
             ),
         )
         .clicked()
-        && let Some(path) = FileDialog::new().save_file()
+        && let Some(path) = FileDialog::new().set_file_name(format!("{}.yaml", export_name)).save_file()
         && let Err(e) = export_yaml(&metadata.iter().map(|entry| (&entry.key, &entry.display_value)).collect::<Vec<_>>(), &path)
     {
         eprintln!("{}", app.t_with_args("messages.export_failed", &[&e.to_string()]));
@@ -229,7 +233,7 @@ pub fn render_sidebar<T: LanguageProvider>(
             ),
         )
         .clicked()
-        && let Some(path) = FileDialog::new().save_file()
+        && let Some(path) = FileDialog::new().set_file_name(format!("{}.md", export_name)).save_file()
         && let Err(e) = export_markdown_to_file(&metadata.iter().map(|entry| (&entry.key, &entry.display_value)).collect::<Vec<_>>(), &path)
     {
         eprintln!("{}", app.t_with_args("messages.export_failed", &[&e.to_string()]));
@@ -244,7 +248,7 @@ pub fn render_sidebar<T: LanguageProvider>(
             ),
         )
         .clicked()
-        && let Some(path) = FileDialog::new().save_file()
+        && let Some(path) = FileDialog::new().set_file_name(format!("{}.html", export_name)).save_file()
         && let Err(e) = export_html_to_file(&metadata.iter().map(|entry| (&entry.key, &entry.display_value)).collect::<Vec<_>>(), &path)
     {
         eprintln!("{}", app.t_with_args("messages.export_failed", &[&e.to_string()]));
@@ -263,7 +267,7 @@ pub fn render_sidebar<T: LanguageProvider>(
             ),
         )
         .clicked()
-        && let Some(path) = FileDialog::new().save_file()
+        && let Some(path) = FileDialog::new().set_file_name(format!("{}.pdf", export_name)).save_file()
     {
         let md = export_markdown(&metadata.iter().map(|entry| (&entry.key, &entry.display_value)).collect::<Vec<_>>());
         if let Err(e) = export_pdf_from_markdown(&md, &path) {
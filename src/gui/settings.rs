@@ -0,0 +1,127 @@
+//! Settings used only by the GUI.
+//!
+//! [`AppSettings`] lives in [`crate::localization`] and is shared with the CLI, so
+//! it cannot name GUI types. The GUI keeps its own fields in [`GuiSettings`], which
+//! are stored in the same settings file: [`AppSettings::gui_fields`] holds them as
+//! raw JSON, and [`GuiSettings::from_settings`] and [`GuiSettings::store`] convert
+//! between the two.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use inspector_gguf::gui::settings::GuiSettings;
+//! use inspector_gguf::localization::AppSettings;
+//!
+//! let mut settings = AppSettings::default();
+//! GuiSettings::update(&mut settings, |gui| gui.entries_per_page = 50);
+//!
+//! assert_eq!(GuiSettings::from_settings(&settings).entries_per_page, 50);
+//! assert_eq!(serde_json::to_value(&settings).unwrap()["entries_per_page"], 50);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use crate::gui::cache::DEFAULT_CACHE_CAPACITY;
+use crate::gui::changes::FileSnapshot;
+use crate::gui::export::ExportOptions;
+use crate::gui::panels::content::{FileFilter, DEFAULT_ENTRIES_PER_PAGE};
+use crate::localization::AppSettings;
+
+/// GUI-only part of the application settings.
+///
+/// Missing fields are filled with their defaults, so settings files written by
+/// older releases or by a build without the `gui` feature keep working.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuiSettings {
+    /// Number of files kept by the metadata cache.
+    pub metadata_cache_capacity: usize,
+    /// Number of metadata entries shown per page of the content panel.
+    pub entries_per_page: usize,
+    /// Options applied to GUI exports, edited in the export options dialog.
+    pub export_options: ExportOptions,
+    /// Hashed metadata snapshots of recently opened files, most recent first (see [`crate::gui::changes`]).
+    pub metadata_snapshots: Vec<FileSnapshot>,
+    /// Last filters of recently opened files, most recent first (see [`crate::gui::panels::content::restore_filter`]).
+    pub file_filters: Vec<FileFilter>,
+}
+
+impl Default for GuiSettings {
+    fn default() -> Self {
+        Self {
+            metadata_cache_capacity: DEFAULT_CACHE_CAPACITY,
+            entries_per_page: DEFAULT_ENTRIES_PER_PAGE,
+            export_options: ExportOptions::default(),
+            metadata_snapshots: Vec::new(),
+            file_filters: Vec::new(),
+        }
+    }
+}
+
+impl GuiSettings {
+    /// Reads the GUI fields stored in `settings`.
+    ///
+    /// Falls back to the defaults when the stored fields have an incompatible type.
+    pub fn from_settings(settings: &AppSettings) -> Self {
+        serde_json::from_value(serde_json::Value::Object(settings.gui_fields.clone())).unwrap_or_default()
+    }
+
+    /// Writes these fields into `settings`, keeping any other fields stored there.
+    pub fn store(&self, settings: &mut AppSettings) {
+        if let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(self) {
+            settings.gui_fields.extend(fields);
+        }
+    }
+
+    /// Reads the GUI fields of `settings`, applies `update` and writes them back.
+    pub fn update<R>(settings: &mut AppSettings, update: impl FnOnce(&mut GuiSettings) -> R) -> R {
+        let mut gui = Self::from_settings(settings);
+        let result = update(&mut gui);
+        gui.store(settings);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::localization::settings::migrate_settings;
+
+    #[test]
+    fn test_old_settings_use_gui_defaults() {
+        let v3 = serde_json::json!({ "language": "English", "preview_limit": 10, "version": "3.0" });
+        let (settings, _) = migrate_settings(v3).expect("v3 settings should migrate");
+
+        assert_eq!(GuiSettings::from_settings(&settings), GuiSettings::default());
+    }
+
+    #[test]
+    fn test_stored_fields_are_read_back() {
+        let stored = serde_json::json!({
+            "language": "English",
+            "entries_per_page": 50,
+            "export_options": { "filtered_only": true },
+            "version": "9.0"
+        });
+        let (settings, _) = migrate_settings(stored).expect("v9 settings should migrate");
+        let gui = GuiSettings::from_settings(&settings);
+
+        assert_eq!(gui.entries_per_page, 50);
+        assert!(gui.export_options.filtered_only);
+        assert_eq!(gui.metadata_cache_capacity, DEFAULT_CACHE_CAPACITY);
+        assert!(gui.metadata_snapshots.is_empty());
+        assert!(gui.file_filters.is_empty());
+    }
+
+    #[test]
+    fn test_update_keeps_other_fields() {
+        let stored = serde_json::json!({ "language": "Russian", "some_future_field": true });
+        let (mut settings, _) = migrate_settings(stored).expect("Settings should load");
+
+        GuiSettings::update(&mut settings, |gui| gui.metadata_cache_capacity = 7);
+
+        let written = serde_json::to_value(&settings).unwrap();
+        assert_eq!(written["language"], "Russian");
+        assert_eq!(written["metadata_cache_capacity"], 7);
+        assert_eq!(written["some_future_field"], true);
+    }
+}
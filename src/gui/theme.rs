@@ -150,31 +150,50 @@ pub const SUCCESS_GREEN: egui::Color32 = egui::Color32::from_rgb(16, 185, 129);
 ///
 /// * `ctx` - The egui context where fonts will be registered
 pub fn load_custom_font(ctx: &egui::Context) {
+    load_fonts(ctx, true);
+}
+
+/// Name under which the Rubik Distressed display font is registered.
+pub const DISPLAY_FONT_NAME: &str = "rubik_distressed";
+
+/// Loads the application fonts, optionally without the display font.
+///
+/// With `use_display_font` set this is [`load_custom_font`]; otherwise egui's
+/// default proportional and monospace fonts are kept for readability. The
+/// Phosphor icons are registered in both cases.
+pub fn load_fonts(ctx: &egui::Context, use_display_font: bool) {
+    ctx.set_fonts(font_definitions(use_display_font));
+}
+
+/// Builds the font definitions used by [`load_fonts`].
+pub fn font_definitions(use_display_font: bool) -> FontDefinitions {
     let mut fonts = FontDefinitions::default();
 
-    fonts.font_data.insert(
-        "rubik_distressed".to_owned(),
-        std::sync::Arc::new(FontData::from_static(include_bytes!(
-            "../../assets/fonts/RubikDistressed-Regular.ttf"
-        ))),
-    );
+    if use_display_font {
+        fonts.font_data.insert(
+            DISPLAY_FONT_NAME.to_owned(),
+            std::sync::Arc::new(FontData::from_static(include_bytes!(
+                "../../assets/fonts/RubikDistressed-Regular.ttf"
+            ))),
+        );
 
-    fonts
-        .families
-        .entry(FontFamily::Proportional)
-        .or_default()
-        .insert(0, "rubik_distressed".to_owned());
+        fonts
+            .families
+            .entry(FontFamily::Proportional)
+            .or_default()
+            .insert(0, DISPLAY_FONT_NAME.to_owned());
 
-    fonts
-        .families
-        .entry(FontFamily::Monospace)
-        .or_default()
-        .insert(0, "rubik_distressed".to_owned());
+        fonts
+            .families
+            .entry(FontFamily::Monospace)
+            .or_default()
+            .insert(0, DISPLAY_FONT_NAME.to_owned());
+    }
 
     // Add Phosphor icons as fallback fonts
     egui_phosphor::add_to_fonts(&mut fonts, egui_phosphor::Variant::Regular);
 
-    ctx.set_fonts(fonts);
+    fonts
 }
 
 /// Applies the complete Inspector Gadget theme to the egui context.
@@ -336,4 +355,127 @@ pub fn apply_inspector_theme(ctx: &egui::Context) {
     // Применяем визуальные настройки через Style
     style.visuals = visuals;
     ctx.set_style(style);
-}
\ No newline at end of file
+}
+
+/// Accent color of the high-contrast theme, used for hovered and active widgets and links.
+pub const HIGH_CONTRAST_YELLOW: egui::Color32 = egui::Color32::from_rgb(255, 255, 0);
+
+/// Width of widget outlines and text strokes in the high-contrast theme.
+pub const HIGH_CONTRAST_STROKE_WIDTH: f32 = 2.0;
+
+/// Relative luminance of `color` as defined by WCAG 2.x, from `0.0` (black) to `1.0` (white).
+///
+/// Alpha is ignored.
+pub fn relative_luminance(color: egui::Color32) -> f32 {
+    let linear = |channel: u8| {
+        let c = f32::from(channel) / 255.0;
+        if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+    };
+    0.2126 * linear(color.r()) + 0.7152 * linear(color.g()) + 0.0722 * linear(color.b())
+}
+
+/// WCAG contrast ratio between two colors, from `1.0` (identical) to `21.0` (black on white).
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::theme::contrast_ratio;
+/// use eframe::egui::Color32;
+///
+/// assert!((contrast_ratio(Color32::WHITE, Color32::BLACK) - 21.0).abs() < 0.01);
+/// assert_eq!(contrast_ratio(Color32::BLACK, Color32::WHITE), contrast_ratio(Color32::WHITE, Color32::BLACK));
+/// ```
+pub fn contrast_ratio(a: egui::Color32, b: egui::Color32) -> f32 {
+    let (a, b) = (relative_luminance(a), relative_luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// Black, white and yellow visuals with thick outlines for low-vision users.
+///
+/// All text is white on black; hovered and active widgets are outlined in
+/// [`HIGH_CONTRAST_YELLOW`] instead of being filled, so their text keeps the
+/// full contrast. Strokes are [`HIGH_CONTRAST_STROKE_WIDTH`] wide (wider still
+/// for hovered and active widgets).
+pub fn high_contrast_visuals() -> egui::Visuals {
+    use egui::{Color32, Stroke};
+
+    let mut visuals = egui::Visuals::dark();
+    let outline = |width: f32, color: Color32| Stroke::new(HIGH_CONTRAST_STROKE_WIDTH * width, color);
+
+    visuals.override_text_color = Some(Color32::WHITE);
+    visuals.panel_fill = Color32::BLACK;
+    visuals.window_fill = Color32::BLACK;
+    visuals.faint_bg_color = Color32::BLACK;
+    visuals.extreme_bg_color = Color32::BLACK;
+    visuals.code_bg_color = Color32::BLACK;
+    visuals.window_stroke = outline(1.0, Color32::WHITE);
+    visuals.hyperlink_color = HIGH_CONTRAST_YELLOW;
+    visuals.warn_fg_color = HIGH_CONTRAST_YELLOW;
+    visuals.error_fg_color = Color32::from_rgb(255, 128, 128);
+    // White text stays readable on the dark blue selection
+    visuals.selection.bg_fill = Color32::from_rgb(0, 0, 160);
+    visuals.selection.stroke = outline(1.0, Color32::WHITE);
+
+    let widgets = &mut visuals.widgets;
+    for (state, width, color) in [
+        (&mut widgets.noninteractive, 1.0, Color32::WHITE),
+        (&mut widgets.inactive, 1.0, Color32::WHITE),
+        (&mut widgets.hovered, 1.5, HIGH_CONTRAST_YELLOW),
+        (&mut widgets.active, 2.0, HIGH_CONTRAST_YELLOW),
+        (&mut widgets.open, 1.5, HIGH_CONTRAST_YELLOW),
+    ] {
+        state.bg_fill = Color32::BLACK;
+        state.weak_bg_fill = Color32::BLACK;
+        state.bg_stroke = outline(width, color);
+        state.fg_stroke = outline(1.0, color);
+    }
+    visuals
+}
+
+/// Applies the Inspector typography and spacing with [`high_contrast_visuals`].
+///
+/// Used instead of [`apply_inspector_theme`] while the high-contrast setting is on.
+pub fn apply_high_contrast_theme(ctx: &egui::Context) {
+    apply_inspector_theme(ctx);
+    ctx.style_mut(|style| style.visuals = high_contrast_visuals());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uses_display_font(fonts: &FontDefinitions) -> bool {
+        fonts
+            .families
+            .values()
+            .any(|family| family.iter().any(|name| name == DISPLAY_FONT_NAME))
+    }
+
+    #[test]
+    fn test_display_font_is_primary_when_enabled() {
+        let fonts = font_definitions(true);
+        assert!(fonts.font_data.contains_key(DISPLAY_FONT_NAME));
+        assert_eq!(fonts.families[&FontFamily::Proportional][0], DISPLAY_FONT_NAME);
+        assert_eq!(fonts.families[&FontFamily::Monospace][0], DISPLAY_FONT_NAME);
+    }
+
+    #[test]
+    fn test_high_contrast_text_contrast() {
+        let visuals = high_contrast_visuals();
+        let text = visuals.override_text_color.expect("High contrast should override the text color");
+        // WCAG AAA asks for at least 7:1
+        assert!(contrast_ratio(text, visuals.panel_fill) >= 7.0);
+        assert!(contrast_ratio(HIGH_CONTRAST_YELLOW, visuals.panel_fill) >= 7.0);
+        assert!(contrast_ratio(text, visuals.selection.bg_fill) >= 7.0);
+        assert!(visuals.widgets.inactive.bg_stroke.width > egui::Visuals::dark().widgets.inactive.bg_stroke.width);
+    }
+
+    #[test]
+    fn test_minimal_fonts_omit_display_font() {
+        let fonts = font_definitions(false);
+        assert!(!fonts.font_data.contains_key(DISPLAY_FONT_NAME));
+        assert!(!uses_display_font(&fonts));
+        // Icons stay available
+        assert!(fonts.font_data.len() > FontDefinitions::default().font_data.len());
+    }
+}
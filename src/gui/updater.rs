@@ -71,11 +71,101 @@ use std::error::Error;
 /// automatically updated during the build process to ensure version consistency.
 const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// Short git commit hash of the build, set by the build script when git is available.
+pub const GIT_HASH: Option<&str> = option_env!("INSPECTOR_GGUF_GIT_HASH");
+
+/// Returns the version of the running binary, taken from Cargo.toml at compile time.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::updater::current_version;
+///
+/// assert_eq!(current_version().to_string(), env!("CARGO_PKG_VERSION"));
+/// ```
+pub fn current_version() -> Version {
+    Version::parse(CURRENT_VERSION).expect("CARGO_PKG_VERSION is valid semver")
+}
+
+/// Returns the version string printed by `--version-info` and shown in the about dialog.
+///
+/// The git hash is appended in parentheses when the build recorded one, e.g. `0.1.0 (3f2a9c1)`.
+pub fn version_info() -> String {
+    match GIT_HASH {
+        Some(hash) => format!("{} ({})", current_version(), hash),
+        None => current_version().to_string(),
+    }
+}
+
 /// GitHub repository identifier for update checking.
 ///
 /// Format: "owner/repository" - used to construct GitHub API URLs for
 /// querying release information and download links.
-const GITHUB_REPO: &str = "FerrisMind/inspector-gguf";
+pub const GITHUB_REPO: &str = "FerrisMind/inspector-gguf";
+
+/// Facts prefilled into a bug report by [`bug_report_url`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BugReportDiagnostics {
+    /// App version, see [`version_info`].
+    pub version: String,
+    /// Operating system and CPU architecture.
+    pub os: String,
+    /// `general.architecture` of the loaded model, if a file is loaded.
+    pub architecture: Option<String>,
+}
+
+/// Percent-encodes `s` for a URL query value, keeping only RFC 3986 unreserved characters.
+fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Builds a "new issue" URL for `repo` (`owner/repository`) with a prefilled title and body.
+///
+/// The body is a short template followed by `diagnostics`; it states that no
+/// file contents are included, since only the model architecture is reported.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::updater::{bug_report_url, BugReportDiagnostics};
+///
+/// let url = bug_report_url("FerrisMind/inspector-gguf", &BugReportDiagnostics {
+///     version: "0.1.0".to_string(),
+///     os: "linux (x86_64)".to_string(),
+///     architecture: Some("qwen3".to_string()),
+/// });
+/// assert!(url.starts_with("https://github.com/FerrisMind/inspector-gguf/issues/new?title=Bug%3A%20"));
+/// assert!(url.contains("qwen3"));
+/// ```
+pub fn bug_report_url(repo: &str, diagnostics: &BugReportDiagnostics) -> String {
+    let title = format!("Bug: <short description> (v{})", diagnostics.version);
+    let body = [
+        "**What happened?**".to_string(),
+        String::new(),
+        String::new(),
+        "**Steps to reproduce**".to_string(),
+        String::new(),
+        String::new(),
+        "**Environment**".to_string(),
+        format!("- Version: {}", diagnostics.version),
+        format!("- OS: {}", diagnostics.os),
+        format!("- Model architecture: {}", diagnostics.architecture.as_deref().unwrap_or("no file loaded")),
+        String::new(),
+        "No file contents are included in this report.".to_string(),
+    ]
+    .join("\n");
+    format!(
+        "https://github.com/{}/issues/new?title={}&body={}",
+        repo,
+        percent_encode(&title),
+        percent_encode(&body)
+    )
+}
 
 /// Checks for updates by querying the GitHub API for the latest release.
 ///
@@ -155,8 +245,8 @@ const GITHUB_REPO: &str = "FerrisMind/inspector-gguf";
 ///
 /// - **Network Failures**: No internet connection, DNS resolution failures
 /// - **API Errors**: GitHub API returns non-success HTTP status codes
-/// - **Parsing Errors**: Invalid JSON response or malformed version strings
-/// - **Version Errors**: Unable to parse current or remote version numbers
+/// - **Parsing Errors**: Invalid JSON response
+/// - **Version Errors**: The release tag is missing or not a semantic version
 ///
 /// # Error Types
 ///
@@ -164,7 +254,7 @@ const GITHUB_REPO: &str = "FerrisMind/inspector-gguf";
 ///
 /// - `reqwest::Error` - Network or HTTP request failures
 /// - `serde_json::Error` - JSON parsing failures
-/// - `semver::Error` - Version string parsing failures
+/// - `parse_tag_failed` - Missing or non-semver release tag (see [`is_newer_release`])
 /// - Custom errors for API-specific issues
 pub fn check_for_updates() -> Result<String, Box<dyn Error>> {
     let url = format!("https://api.github.com/repos/{}/releases/latest", GITHUB_REPO);
@@ -188,15 +278,140 @@ pub fn check_for_updates() -> Result<String, Box<dyn Error>> {
         .as_str()
         .ok_or("parse_tag_failed")?;
 
-    // Remove 'v' prefix if present
-    let latest_version_str = latest_tag.strip_prefix('v').unwrap_or(latest_tag);
+    update_status_for_tag(latest_tag, &current_version())
+}
 
-    let current_version = Version::parse(CURRENT_VERSION)?;
-    let latest_version = Version::parse(latest_version_str)?;
-
-    if latest_version > current_version {
+/// Compares a release tag such as `v0.2.0` with `current` and returns the update status.
+///
+/// Produces the `new_version_available:{tag}` and `latest_version` statuses of
+/// [`check_for_updates`], which passes [`current_version`]. See [`is_newer_release`]
+/// for how tags are compared.
+///
+/// # Errors
+///
+/// Returns a `parse_tag_failed` error if the tag is not a semantic version.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::gui::updater::update_status_for_tag;
+/// use semver::Version;
+///
+/// let current = Version::new(0, 1, 0);
+/// assert_eq!(update_status_for_tag("v0.2.0", &current)?, "new_version_available:v0.2.0");
+/// assert_eq!(update_status_for_tag("0.1.0", &current)?, "latest_version");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn update_status_for_tag(latest_tag: &str, current: &Version) -> Result<String, Box<dyn Error>> {
+    if is_newer_release(latest_tag, current)? {
         Ok(format!("new_version_available:{}", latest_tag))
     } else {
         Ok("latest_version".to_string())
     }
-}
\ No newline at end of file
+}
+
+/// Returns `true` if the release `tag` is a newer version than `current`.
+///
+/// A leading `v` or `V` is ignored and the comparison follows semver precedence,
+/// so prereleases sort before their release (`v0.2.0-beta.1` is older than `0.2.0`
+/// but newer than `0.1.0`) and build metadata is ignored.
+///
+/// # Errors
+///
+/// Returns a `parse_tag_failed` error if the tag is not a semantic version.
+pub fn is_newer_release(tag: &str, current: &Version) -> Result<bool, Box<dyn Error>> {
+    let tag = tag.trim();
+    let version_str = tag.strip_prefix(['v', 'V']).unwrap_or(tag);
+    let latest = Version::parse(version_str).map_err(|_| "parse_tag_failed")?;
+    Ok(latest.cmp_precedence(current).is_gt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_version_parses_and_is_non_zero() {
+        let version = current_version();
+        assert_ne!(version, Version::new(0, 0, 0));
+        assert!(version_info().starts_with(&version.to_string()));
+    }
+
+    #[test]
+    fn test_bug_report_url_encodes_query_parameters() {
+        let url = bug_report_url("FerrisMind/inspector-gguf", &BugReportDiagnostics {
+            version: "1.2.3 (abc1234)".to_string(),
+            os: "linux (x86_64)".to_string(),
+            architecture: Some("llama&mistral#1 ü".to_string()),
+        });
+
+        let (base, query) = url.split_once('?').expect("URL should have a query");
+        assert_eq!(base, "https://github.com/FerrisMind/inspector-gguf/issues/new");
+        let params: Vec<(&str, &str)> = query.split('&').map(|p| p.split_once('=').expect("Parameter should have a value")).collect();
+        assert_eq!(params.iter().map(|(k, _)| *k).collect::<Vec<_>>(), ["title", "body"]);
+
+        let (title, body) = (params[0].1, params[1].1);
+        assert_eq!(title, "Bug%3A%20%3Cshort%20description%3E%20%28v1.2.3%20%28abc1234%29%29");
+        assert!(body.contains("-%20Model%20architecture%3A%20llama%26mistral%231%20%C3%BC%0A"));
+        assert!(body.contains("No%20file%20contents%20are%20included"));
+        assert!(query.chars().all(|c| c.is_ascii_alphanumeric() || "-._~%&=".contains(c)));
+
+        let empty = bug_report_url("o/r", &BugReportDiagnostics { version: "1".into(), os: "x".into(), architecture: None });
+        assert!(empty.contains("no%20file%20loaded"));
+    }
+
+    #[test]
+    fn test_current_version_matches_package_version() {
+        assert_eq!(CURRENT_VERSION, env!("CARGO_PKG_VERSION"));
+        assert_eq!(current_version(), Version::parse(env!("CARGO_PKG_VERSION")).unwrap());
+    }
+
+    #[test]
+    fn test_update_comparison_uses_current_version() {
+        let current = current_version();
+        let same = format!("v{}", current);
+        assert_eq!(update_status_for_tag(&same, &current).unwrap(), "latest_version");
+
+        let next = Version::new(current.major, current.minor, current.patch + 1);
+        let next_tag = format!("v{}", next);
+        assert_eq!(
+            update_status_for_tag(&next_tag, &current).unwrap(),
+            format!("new_version_available:{}", next_tag)
+        );
+        assert_eq!(update_status_for_tag("not-a-version", &current).unwrap_err().to_string(), "parse_tag_failed");
+    }
+
+    #[test]
+    fn test_newer_tag_is_detected() {
+        let current = Version::parse("0.1.0").unwrap();
+        assert!(is_newer_release("v0.2.0", &current).unwrap());
+        assert!(is_newer_release("V0.1.1", &current).unwrap());
+    }
+
+    #[test]
+    fn test_equal_or_older_tag_is_not_newer() {
+        let current = Version::parse("0.1.0").unwrap();
+        assert!(!is_newer_release("v0.1.0", &current).unwrap());
+        assert!(!is_newer_release("0.0.9", &current).unwrap());
+        assert!(!is_newer_release("v0.1.0+build.5", &current).unwrap());
+    }
+
+    #[test]
+    fn test_prerelease_tags_follow_semver_precedence() {
+        let current = Version::parse("0.2.0").unwrap();
+        assert!(!is_newer_release("v0.2.0-beta.1", &current).unwrap());
+        assert!(is_newer_release("v0.3.0-rc.1", &current).unwrap());
+
+        let beta = Version::parse("0.2.0-beta.1").unwrap();
+        assert!(is_newer_release("v0.2.0", &beta).unwrap());
+    }
+
+    #[test]
+    fn test_malformed_tag_returns_parse_tag_failed() {
+        let current = Version::parse("0.1.0").unwrap();
+        for tag in ["latest", "v1.2", ""] {
+            let err = is_newer_release(tag, &current).unwrap_err();
+            assert_eq!(err.to_string(), "parse_tag_failed", "tag {:?}", tag);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! File watching for automatic reloads of the open GGUF file.
+//!
+//! When the `auto_reload` setting (or the `--watch` flag) is enabled, the application
+//! watches the loaded file and re-triggers the asynchronous load after it changes on
+//! disk. Model builds typically write a file in many small chunks, so change events
+//! are debounced: a reload only happens once no event has arrived for
+//! [`RELOAD_DEBOUNCE`].
+//!
+//! The parent directory is watched rather than the file itself, so files replaced
+//! by a rename (as many tools and editors do) keep being tracked.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use inspector_gguf::gui::watcher::should_reload;
+//! use std::time::{Duration, Instant};
+//!
+//! let last_event = Instant::now();
+//! let debounce = Duration::from_millis(500);
+//!
+//! assert!(!should_reload(last_event, last_event + Duration::from_millis(100), debounce));
+//! assert!(should_reload(last_event, last_event + Duration::from_millis(600), debounce));
+//! ```
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Quiet period after the last change event before the file is reloaded.
+pub const RELOAD_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Decides whether a debounced reload is due.
+///
+/// Returns `true` once at least `debounce` has elapsed between the most recent
+/// change event and `now`.
+pub fn should_reload(last_event: Instant, now: Instant, debounce: Duration) -> bool {
+    now.saturating_duration_since(last_event) >= debounce
+}
+
+/// Watches a single file and reports debounced changes.
+pub struct FileWatcher {
+    path: PathBuf,
+    last_event: Arc<Mutex<Option<Instant>>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl FileWatcher {
+    /// Starts watching `path`, requesting a repaint of `ctx` when it changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file system watcher cannot be created or the
+    /// parent directory cannot be watched.
+    pub fn new(path: &Path, ctx: egui::Context) -> notify::Result<Self> {
+        let target = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+        let directory = target.parent().map(Path::to_path_buf).unwrap_or_else(|| target.clone());
+
+        let last_event = Arc::new(Mutex::new(None));
+        let events = Arc::clone(&last_event);
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if let Ok(event) = res
+                && (event.kind.is_modify() || event.kind.is_create())
+                && event.paths.iter().any(|p| p == &target)
+            {
+                *events.lock().unwrap() = Some(Instant::now());
+                // Wake the UI once the quiet period is over so it can poll
+                ctx.request_repaint_after(RELOAD_DEBOUNCE);
+            }
+        })?;
+        watcher.watch(&directory, RecursiveMode::NonRecursive)?;
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            last_event,
+            _watcher: watcher,
+        })
+    }
+
+    /// Returns the watched path as it was passed to [`FileWatcher::new`].
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns `true` once per burst of changes, after the debounce period.
+    pub fn poll(&self, now: Instant) -> bool {
+        let mut last_event = self.last_event.lock().unwrap();
+        match *last_event {
+            Some(event) if should_reload(event, now, RELOAD_DEBOUNCE) => {
+                *last_event = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_reload_waits_for_debounce() {
+        let debounce = Duration::from_millis(500);
+        let last_event = Instant::now();
+
+        assert!(!should_reload(last_event, last_event, debounce));
+        assert!(!should_reload(last_event, last_event + Duration::from_millis(499), debounce));
+        assert!(should_reload(last_event, last_event + debounce, debounce));
+        assert!(should_reload(last_event, last_event + Duration::from_secs(5), debounce));
+    }
+
+    #[test]
+    fn test_rapid_events_postpone_reload() {
+        let debounce = Duration::from_millis(500);
+        let start = Instant::now();
+        // A newer event restarts the quiet period
+        let latest = start + Duration::from_millis(400);
+        let now = start + Duration::from_millis(600);
+
+        assert!(should_reload(start, now, debounce));
+        assert!(!should_reload(latest, now, debounce));
+    }
+
+    #[test]
+    fn test_event_after_now_does_not_reload() {
+        let now = Instant::now();
+        assert!(!should_reload(now + Duration::from_millis(10), now, Duration::from_millis(1)));
+    }
+}
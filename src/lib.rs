@@ -53,12 +53,36 @@
 //! # }
 //! ```
 //!
+//! ## Cargo Features
+//!
+//! - `gui` (default) - The [`gui`] and [`cli`] modules, the `inspector-gguf` binary,
+//!   and their dependencies (egui/eframe, file dialogs, exporters, the update checker)
+//! - `tokio` - `format::load_gguf_metadata` for Tokio runtimes
+//! - `http` - `format::fetch_gguf_head` and URL inputs in the CLI
+//!
+//! Without default features only [`mod@format`] and [`localization`] are built, for
+//! headless use of the GGUF parser:
+//!
+//! ```toml
+//! inspector-gguf = { version = "0.3", default-features = false }
+//! ```
+//!
 //! ## Module Organization
 //!
+//! - [`cli`] - Command-line error reporting and stdin input
+//!   - [`cli::CliError`] - CLI failure categories with `--json-errors` rendering
+//!   - [`cli::export_json_from_reader`] - JSON export of a GGUF file read from a stream (`--stdin`)
+//!   - [`cli::validate_metadata_dir`] - Per-file checks of a YAML metadata directory (`--metadata-dir`)
+//!   - [`cli::compare_to_reference`] - Metadata diff against a reference YAML snapshot (`--compare-ref`)
+//!   - [`cli::export_to_sqlite`] - Batch metadata export into an SQLite database (`--sqlite`)
 //! - [`mod@format`] - GGUF file parsing and metadata extraction using Candle
 //!   - [`format::load_gguf_metadata_sync`] - Synchronous GGUF metadata loading
+//!   - [`format::load_gguf_metadata_timed`] - The same, with read/parse/process timings ([`format::LoadTimings`])
 //!   - [`format::load_gguf_metadata_with_full_content_sync`] - Extended metadata loading with full tokenizer content
+//!   - `format::load_gguf_metadata` - Async loading for Tokio runtimes (`tokio` feature); loader errors are [`format::BoxError`] (`Send + Sync`)
 //!   - [`format::readable_value_for_key`] - Human-readable value formatting
+//!   - [`format::reconstruct_tokenizer_json`] - HuggingFace `tokenizer.json` rebuilt from BPE tokenizer metadata
+//!   - [`format::GgufReader`] - Keeps a parsed file open to read the raw bytes of individual tensors
 //! - [`gui`] - Graphical user interface components built with egui
 //!   - [`gui::GgufApp`] - Main application struct implementing [`eframe::App`]
 //!   - [`gui::apply_inspector_theme`] - Inspector Gadget theme application
@@ -106,7 +130,10 @@
 #![warn(rustdoc::missing_crate_level_docs)]
 #![warn(rustdoc::invalid_codeblock_attributes)]
 
+#[cfg(feature = "gui")]
+pub mod cli;
 pub mod format;
+#[cfg(feature = "gui")]
 pub mod gui;
 pub mod localization;
 
@@ -36,6 +36,13 @@ pub enum SettingsError {
     #[error("Failed to create settings directory")]
     DirectoryCreation,
     
+    /// The settings directory exists but the user may not write to it
+    #[error("Permission denied for settings directory {}", .path.display())]
+    PermissionDenied {
+        /// Directory or file that could not be written
+        path: std::path::PathBuf,
+    },
+    
     /// Failed to read the settings file
     #[error("Failed to read settings file")]
     ReadError,
@@ -55,4 +62,18 @@ pub enum SettingsError {
     /// JSON parsing or serialization error
     #[error("JSON parsing error: {0}")]
     JsonParsing(#[from] serde_json::Error),
+}
+
+impl SettingsError {
+    /// Maps an I/O error on `path`, keeping permission problems apart from `fallback`.
+    ///
+    /// Read-only file systems count as denied permission as well.
+    pub fn from_io(error: &std::io::Error, path: &std::path::Path, fallback: SettingsError) -> Self {
+        match error.kind() {
+            std::io::ErrorKind::PermissionDenied | std::io::ErrorKind::ReadOnlyFilesystem => {
+                SettingsError::PermissionDenied { path: path.to_path_buf() }
+            }
+            _ => fallback,
+        }
+    }
 }
\ No newline at end of file
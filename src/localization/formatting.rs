@@ -0,0 +1,160 @@
+//! Locale-aware formatting of numbers and dates.
+//!
+//! This module provides lightweight per-language formatting rules for human-facing
+//! numbers and timestamps. It deliberately avoids a full ICU dependency: each
+//! supported [`Language`] has a fixed thousands separator and date pattern.
+//!
+//! | Language | Number | Percent | Date |
+//! |----------|--------|---------|------|
+//! | English | `1,234,567` | `42%` | `10/15/2026 14:30` |
+//! | Russian | `1 234 567` | `42 %` | `15.10.2026 14:30` |
+//! | Portuguese (Brazilian) | `1.234.567` | `42%` | `15/10/2026 14:30` |
+//!
+//! # Examples
+//!
+//! ```rust
+//! use inspector_gguf::localization::{format_number, Language};
+//!
+//! assert_eq!(format_number(751_632_384, Language::English), "751,632,384");
+//! assert_eq!(format_number(751_632_384, Language::PortugueseBrazilian), "751.632.384");
+//! ```
+
+use chrono::{DateTime, TimeZone};
+use crate::localization::Language;
+
+impl Language {
+    /// Returns the digit group separator used for this language.
+    ///
+    /// Russian uses a non-breaking space so grouped numbers never wrap in the GUI.
+    pub fn thousands_separator(&self) -> char {
+        match self {
+            Language::English => ',',
+            Language::Russian => '\u{a0}',
+            Language::PortugueseBrazilian => '.',
+        }
+    }
+
+    /// Returns the `chrono` format pattern for dates with time in this language.
+    pub fn date_pattern(&self) -> &'static str {
+        match self {
+            Language::English => "%m/%d/%Y %H:%M",
+            Language::Russian => "%d.%m.%Y %H:%M",
+            Language::PortugueseBrazilian => "%d/%m/%Y %H:%M",
+        }
+    }
+}
+
+/// Formats an integer with the digit grouping of the given language.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::localization::{format_number, Language};
+///
+/// assert_eq!(format_number(999, Language::English), "999");
+/// assert_eq!(format_number(1_000, Language::English), "1,000");
+/// assert_eq!(format_number(1_000, Language::Russian), "1\u{a0}000");
+/// ```
+pub fn format_number(n: u64, lang: Language) -> String {
+    let digits = n.to_string();
+    let separator = lang.thousands_separator();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push(separator);
+        }
+        out.push(digit);
+    }
+    out
+}
+
+/// Formats a progress fraction (`0.0` to `1.0`) as a whole percentage.
+///
+/// Russian separates the sign with a non-breaking space, as in `42\u{a0}%`.
+/// Values outside the range are clamped, so a negative error marker never
+/// shows as a negative percentage.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::localization::{format_percent, Language};
+///
+/// assert_eq!(format_percent(0.424, Language::English), "42%");
+/// assert_eq!(format_percent(1.0, Language::Russian), "100\u{a0}%");
+/// ```
+pub fn format_percent(fraction: f32, lang: Language) -> String {
+    let percent = format_number((fraction.clamp(0.0, 1.0) * 100.0).round() as u64, lang);
+    match lang {
+        Language::Russian => format!("{}\u{a0}%", percent),
+        Language::English | Language::PortugueseBrazilian => format!("{}%", percent),
+    }
+}
+
+/// Formats a timestamp using the date order of the given language.
+///
+/// The timestamp is rendered in its own time zone; convert it with
+/// [`DateTime::with_timezone`] first to display local time.
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::{TimeZone, Utc};
+/// use inspector_gguf::localization::{format_date, Language};
+///
+/// let date = Utc.with_ymd_and_hms(2026, 10, 15, 14, 30, 0).unwrap();
+/// assert_eq!(format_date(&date, Language::English), "10/15/2026 14:30");
+/// assert_eq!(format_date(&date, Language::Russian), "15.10.2026 14:30");
+/// ```
+pub fn format_date<Tz: TimeZone>(datetime: &DateTime<Tz>, lang: Language) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    datetime.format(lang.date_pattern()).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_large_number_grouping_english_vs_russian() {
+        let n = 1_234_567_890;
+        assert_eq!(format_number(n, Language::English), "1,234,567,890");
+        assert_eq!(format_number(n, Language::Russian), "1\u{a0}234\u{a0}567\u{a0}890");
+        assert_ne!(format_number(n, Language::English), format_number(n, Language::Russian));
+    }
+
+    #[test]
+    fn test_four_digit_count_english_vs_russian() {
+        assert_eq!(format_number(4096, Language::English), "4,096");
+        assert_eq!(format_number(4096, Language::Russian), "4\u{a0}096");
+        assert_eq!(format_number(4096, Language::PortugueseBrazilian), "4.096");
+    }
+
+    #[test]
+    fn test_percent_per_language() {
+        assert_eq!(format_percent(0.5, Language::English), "50%");
+        assert_eq!(format_percent(0.5, Language::Russian), "50\u{a0}%");
+        assert_eq!(format_percent(0.5, Language::PortugueseBrazilian), "50%");
+        assert_eq!(format_percent(-1.0, Language::English), "0%");
+        assert_eq!(format_percent(1.5, Language::English), "100%");
+    }
+
+    #[test]
+    fn test_small_numbers_are_not_grouped() {
+        for lang in [Language::English, Language::Russian, Language::PortugueseBrazilian] {
+            assert_eq!(format_number(0, lang), "0");
+            assert_eq!(format_number(123, lang), "123");
+        }
+    }
+
+    #[test]
+    fn test_date_order_per_language() {
+        let date = Utc.with_ymd_and_hms(2026, 3, 7, 9, 5, 0).unwrap();
+        assert_eq!(format_date(&date, Language::English), "03/07/2026 09:05");
+        assert_eq!(format_date(&date, Language::Russian), "07.03.2026 09:05");
+        assert_eq!(format_date(&date, Language::PortugueseBrazilian), "07/03/2026 09:05");
+    }
+}
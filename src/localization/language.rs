@@ -59,6 +59,20 @@ pub enum Language {
 }
 
 impl Language {
+    /// Returns every supported language, English first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use inspector_gguf::localization::Language;
+    ///
+    /// assert_eq!(Language::all().len(), 3);
+    /// assert_eq!(Language::all()[0], Language::English);
+    /// ```
+    pub fn all() -> &'static [Language] {
+        &[Language::English, Language::Russian, Language::PortugueseBrazilian]
+    }
+
     /// Creates a Language variant from a locale string.
     ///
     /// This method parses various locale string formats and returns the corresponding
@@ -178,5 +192,32 @@ impl Language {
             Language::PortugueseBrazilian => "Português (Brasil)",
         }
     }
+
+    /// Returns whether the language is written right to left.
+    ///
+    /// None of the current languages are; UI code should still check the flag so
+    /// that adding such a language only needs a new match arm here.
+    pub fn is_rtl(&self) -> bool {
+        match self {
+            Language::English | Language::Russian | Language::PortugueseBrazilian => false,
+        }
+    }
+}
+
+/// Everything a language switcher needs to list one language.
+///
+/// Returned by [`crate::localization::LocalizationManager::language_infos`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageInfo {
+    /// The language itself.
+    pub language: Language,
+    /// Language code, see [`Language::to_code`].
+    pub code: &'static str,
+    /// Native name, see [`Language::display_name`].
+    pub display_name: &'static str,
+    /// Whether the language is written right to left.
+    pub is_rtl: bool,
+    /// Whether its loaded translation has every key of the English one.
+    pub is_complete: bool,
 }
 
@@ -292,10 +292,16 @@ impl TranslationLoader {
             ))?;
 
         for key in required_keys {
-            if !section_obj.contains_key(*key) {
+            let Some(value) = section_obj.get(*key) else {
                 return Err(LocalizationError::InvalidFormat(
                     format!("Missing required key '{}' in section '{}'", key, section)
                 ));
+            };
+            // Non-string leaves would silently fall back to the key at runtime
+            if !value.is_string() {
+                return Err(LocalizationError::InvalidFormat(
+                    format!("Key '{}.{}' must be a string, found {}", section, key, json_type_name(value))
+                ));
             }
         }
 
@@ -357,7 +363,7 @@ impl TranslationLoader {
         
         let english_keys = self.extract_all_keys(english_translation);
         
-        for (language, translation) in translations {
+        for (language, translation) in Self::languages_by_code(translations) {
             if *language == Language::English {
                 continue; // Skip English as it's the reference
             }
@@ -391,7 +397,16 @@ impl TranslationLoader {
         Ok(())
     }
 
-    /// Extract all translation keys from a translation map in dot notation
+    /// Orders translations by language code so reports and warnings are stable
+    fn languages_by_code(
+        translations: &HashMap<Language, TranslationMap>
+    ) -> Vec<(&Language, &TranslationMap)> {
+        let mut languages: Vec<_> = translations.iter().collect();
+        languages.sort_by_key(|(language, _)| language.to_code());
+        languages
+    }
+
+    /// Extract all translation keys from a translation map in dot notation (sorted)
     fn extract_all_keys(&self, translation: &TranslationMap) -> Vec<String> {
         let mut keys = Vec::new();
         Self::extract_keys_recursive(translation, String::new(), &mut keys);
@@ -452,6 +467,9 @@ impl TranslationLoader {
     }
 
     /// Validate and report on translation file completeness
+    ///
+    /// Languages are listed by [`Language::to_code`] and keys lexicographically,
+    /// so the report is byte-identical for identical input.
     pub fn generate_completeness_report(
         &self,
         translations: &HashMap<Language, TranslationMap>
@@ -467,7 +485,7 @@ impl TranslationLoader {
         
         report.push_str(&format!("Total keys in English reference: {}\n\n", english_keys.len()));
         
-        for (language, translation) in translations {
+        for (language, translation) in Self::languages_by_code(translations) {
             if *language == Language::English {
                 continue;
             }
@@ -515,6 +533,18 @@ impl Default for TranslationLoader {
     }
 }
 
+/// Describes the JSON type of a value for validation messages.
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -587,6 +617,26 @@ mod tests {
         assert!(validation_result.is_err());
     }
 
+    #[test]
+    fn test_validation_rejects_non_string_leaf() {
+        let loader = TranslationLoader::new();
+        let mut translation = loader
+            .load_translation(Language::English)
+            .expect("English translation should load");
+        translation.get_mut("buttons").expect("buttons section")["load"] = serde_json::json!(42);
+
+        match loader.validate_translation(&translation) {
+            Err(LocalizationError::InvalidFormat(message)) => {
+                assert_eq!(message, "Key 'buttons.load' must be a string, found a number");
+            }
+            other => panic!("Expected InvalidFormat, got {:?}", other),
+        }
+
+        translation.get_mut("buttons").expect("buttons section")["load"] = serde_json::json!({"text": "Load"});
+        let err = loader.validate_translation(&translation).unwrap_err();
+        assert!(err.to_string().contains("found an object"), "{}", err);
+    }
+
     #[test]
     fn test_load_all_translations() {
         let loader = TranslationLoader::new();
@@ -629,6 +679,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_completeness_report_is_deterministic() {
+        let loader = TranslationLoader::new();
+        let section = |keys: &[&str]| -> TranslationMap {
+            let object: serde_json::Map<String, serde_json::Value> = keys
+                .iter()
+                .map(|key| (key.to_string(), serde_json::Value::String(key.to_string())))
+                .collect();
+            HashMap::from([("app".to_string(), serde_json::Value::Object(object))])
+        };
+
+        let mut translations = HashMap::new();
+        translations.insert(Language::Russian, section(&["title", "zeta"]));
+        translations.insert(Language::English, section(&["title", "beta", "alpha"]));
+        translations.insert(Language::PortugueseBrazilian, section(&["title"]));
+
+        let first = loader.generate_completeness_report(&translations).expect("Report should build");
+        let second = loader.generate_completeness_report(&translations).expect("Report should build");
+        assert_eq!(first.as_bytes(), second.as_bytes());
+
+        // Languages in code order: pt-BR before ru
+        let pt = first.find("Language: PortugueseBrazilian").expect("pt-BR section");
+        let ru = first.find("Language: Russian").expect("ru section");
+        assert!(pt < ru);
+
+        // Missing keys sorted lexicographically
+        let alpha = first.find("    - app.alpha").expect("missing alpha");
+        let beta = first.find("    - app.beta").expect("missing beta");
+        assert!(alpha < beta);
+        assert!(first.contains("    + app.zeta"));
+    }
+
     #[test]
     fn test_completeness_report() {
         let loader = TranslationLoader::new();
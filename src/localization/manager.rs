@@ -1,8 +1,10 @@
 use crate::localization::{
-    Language, LocalizationError, SettingsManager, SystemLocaleDetector, TranslationLoader,
+    Language, LanguageInfo, LocalizationError, SettingsManager, SystemLocaleDetector, TranslationLoader,
 };
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Arc;
 
 /// Type alias for translation data structure containing nested key-value pairs.
 ///
@@ -23,9 +25,11 @@ pub type TranslationMap = HashMap<String, Value>;
 /// # Features
 ///
 /// - **Automatic Language Detection**: Detects system locale on initialization
-/// - **Fallback System**: Falls back to English, then to key names if translations are missing
+/// - **Fallback System**: Walks a configurable fallback chain (English by default), then falls
+///   back to key names if translations are missing
 /// - **Persistent Settings**: Integrates with settings system for user preferences
-/// - **Thread-Safe Design**: Can be safely shared across threads when wrapped appropriately
+/// - **Thread-Safe Design**: Lookups and language switches take `&self` without locking, so
+///   the manager can be shared as `Arc<LocalizationManager>` (see [`Translations`])
 /// - **Validation**: Ensures translation completeness and format correctness
 ///
 /// # Examples
@@ -36,7 +40,7 @@ pub type TranslationMap = HashMap<String, Value>;
 /// use inspector_gguf::localization::{LocalizationManager, Language};
 ///
 /// // Create manager with automatic language detection
-/// let mut manager = LocalizationManager::new()?;
+/// let manager = LocalizationManager::new()?;
 ///
 /// // Get translated text
 /// let app_title = manager.get_text("app.title");
@@ -53,7 +57,7 @@ pub type TranslationMap = HashMap<String, Value>;
 /// ```rust
 /// use inspector_gguf::localization::{LocalizationManager, Language};
 ///
-/// let mut manager = LocalizationManager::new()?;
+/// let manager = LocalizationManager::new()?;
 ///
 /// // Change language and save preference
 /// manager.set_language_with_persistence(Language::PortugueseBrazilian)?;
@@ -62,8 +66,60 @@ pub type TranslationMap = HashMap<String, Value>;
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub struct LocalizationManager {
-    current_language: Language,
-    translations: HashMap<Language, TranslationMap>,
+    current_language: AtomicU8,
+    translations: Arc<Translations>,
+    fallback_chain: Option<Vec<Language>>,
+}
+
+/// Loaded translations of every language, immutable once loaded.
+///
+/// [`LocalizationManager`] keeps them behind an [`Arc`]; background threads can
+/// take their own handle with [`LocalizationManager::translations`] and look up
+/// text without any lock.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::localization::{Language, LocalizationManager};
+///
+/// let translations = LocalizationManager::new()?.translations();
+/// let title = std::thread::spawn(move || translations.text(Language::English, "app.title"))
+///     .join()
+///     .unwrap();
+/// assert_eq!(title.as_deref(), Some("Inspector GGUF"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Translations {
+    maps: HashMap<Language, TranslationMap>,
+}
+
+impl Translations {
+    /// Wraps already loaded translation maps.
+    pub fn new(maps: HashMap<Language, TranslationMap>) -> Self {
+        Self { maps }
+    }
+
+    /// Returns the translation map of `language`, if loaded.
+    pub fn get(&self, language: Language) -> Option<&TranslationMap> {
+        self.maps.get(&language)
+    }
+
+    /// Looks up `key` (dot notation) in `language` only, without fallback.
+    pub fn text(&self, language: Language, key: &str) -> Option<String> {
+        self.get(language)
+            .and_then(|map| TranslationLoader::get_translation_value(map, key))
+    }
+}
+
+/// Stores a [`Language`] in the manager's `AtomicU8` as its position in [`Language::all`].
+fn language_index(language: Language) -> u8 {
+    Language::all().iter().position(|&l| l == language).unwrap_or(0) as u8
+}
+
+/// Reverses [`language_index`].
+fn language_from_index(index: u8) -> Language {
+    Language::all().get(index as usize).copied().unwrap_or_default()
 }
 
 impl LocalizationManager {
@@ -96,10 +152,7 @@ impl LocalizationManager {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn new() -> Result<Self, LocalizationError> {
-        let mut manager = LocalizationManager {
-            current_language: Language::English,
-            translations: HashMap::new(),
-        };
+        let mut translations = HashMap::new();
 
         // Load translations for all supported languages
         let loader = TranslationLoader::new();
@@ -109,8 +162,8 @@ impl LocalizationManager {
             Language::PortugueseBrazilian,
         ] {
             match loader.load_translation(language) {
-                Ok(translations) => {
-                    manager.translations.insert(language, translations);
+                Ok(map) => {
+                    translations.insert(language, map);
                 }
                 Err(e) => {
                     eprintln!(
@@ -118,7 +171,7 @@ impl LocalizationManager {
                         language, e
                     );
                     // Insert empty map as fallback
-                    manager.translations.insert(language, HashMap::new());
+                    translations.insert(language, HashMap::new());
                 }
             }
         }
@@ -130,17 +183,18 @@ impl LocalizationManager {
             .or_else(SystemLocaleDetector::detect)
             .unwrap_or(Language::English);
 
-        manager.current_language = initial_language;
-
-        Ok(manager)
+        Ok(LocalizationManager {
+            current_language: AtomicU8::new(language_index(initial_language)),
+            translations: Arc::new(Translations::new(translations)),
+            fallback_chain: None,
+        })
     }
 
     /// Retrieves translated text for the specified key with automatic fallback.
     ///
-    /// This method implements a three-tier fallback system:
-    /// 1. Try current language translation
-    /// 2. Fall back to English if key is missing in current language
-    /// 3. Return the key itself if no translation is found
+    /// This method walks the [`fallback_chain`] in order:
+    /// 1. Try each language of the chain (by default the current language, then English)
+    /// 2. Return the key itself if no translation is found
     ///
     /// Keys use dot notation to access nested translation structures
     /// (e.g., "buttons.load" accesses `translations["buttons"]["load"]`).
@@ -159,7 +213,7 @@ impl LocalizationManager {
     /// ```rust
     /// use inspector_gguf::localization::{LocalizationManager, Language};
     ///
-    /// let mut manager = LocalizationManager::new()?;
+    /// let manager = LocalizationManager::new()?;
     /// manager.set_language(Language::English)?;
     ///
     /// // Get simple translation
@@ -175,28 +229,76 @@ impl LocalizationManager {
     /// assert_eq!(missing, "non.existent.key");
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
+    ///
+    /// [`fallback_chain`]: LocalizationManager::fallback_chain
     pub fn get_text(&self, key: &str) -> String {
-        // Try to get translation from current language
-        if let Some(translation_map) = self.translations.get(&self.current_language)
-            && let Some(value) = self.get_nested_value(translation_map, key)
-            && let Some(text) = value.as_str()
-        {
-            return text.to_string();
-        }
-
-        // Fallback to English if current language doesn't have the key
-        if self.current_language != Language::English
-            && let Some(translation_map) = self.translations.get(&Language::English)
-            && let Some(value) = self.get_nested_value(translation_map, key)
-            && let Some(text) = value.as_str()
-        {
-            return text.to_string();
+        // Walk the fallback chain in order
+        for language in self.fallback_chain() {
+            if let Some(translation_map) = self.translations.get(language)
+                && let Some(value) = self.get_nested_value(translation_map, key)
+                && let Some(text) = value.as_str()
+            {
+                return text.to_string();
+            }
         }
 
         // Final fallback: return the key itself
         key.to_string()
     }
 
+    /// Returns the languages consulted by [`get_text`], in lookup order.
+    ///
+    /// Unless a custom chain was set with [`set_fallback_chain`], this is
+    /// `[current, English]` and follows language switches.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use inspector_gguf::localization::{LocalizationManager, Language};
+    ///
+    /// let manager = LocalizationManager::new()?;
+    /// manager.set_language(Language::Russian)?;
+    /// assert_eq!(manager.fallback_chain(), vec![Language::Russian, Language::English]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`get_text`]: LocalizationManager::get_text
+    /// [`set_fallback_chain`]: LocalizationManager::set_fallback_chain
+    pub fn fallback_chain(&self) -> Vec<Language> {
+        match &self.fallback_chain {
+            Some(chain) => chain.clone(),
+            None => vec![self.get_current_language(), Language::English],
+        }
+    }
+
+    /// Replaces the fallback chain walked by [`get_text`].
+    ///
+    /// Languages are tried in the given order and the key itself remains the final
+    /// fallback. An empty chain restores the default `[current, English]` behavior.
+    ///
+    /// # Arguments
+    ///
+    /// * `chain` - Languages to try, most preferred first
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use inspector_gguf::localization::{LocalizationManager, Language};
+    ///
+    /// let mut manager = LocalizationManager::new()?;
+    /// manager.set_fallback_chain(vec![
+    ///     Language::PortugueseBrazilian,
+    ///     Language::Russian,
+    ///     Language::English,
+    /// ]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// [`get_text`]: LocalizationManager::get_text
+    pub fn set_fallback_chain(&mut self, chain: Vec<Language>) {
+        self.fallback_chain = if chain.is_empty() { None } else { Some(chain) };
+    }
+
     /// Sets the current language without persisting the preference.
     ///
     /// Changes the active language for translation lookups. This change is temporary
@@ -217,7 +319,7 @@ impl LocalizationManager {
     /// ```rust
     /// use inspector_gguf::localization::{LocalizationManager, Language};
     ///
-    /// let mut manager = LocalizationManager::new()?;
+    /// let manager = LocalizationManager::new()?;
     ///
     /// // Temporarily switch to Russian
     /// manager.set_language(Language::Russian)?;
@@ -232,8 +334,8 @@ impl LocalizationManager {
     /// for settings management.
     ///
     /// [`set_language_with_persistence`]: LocalizationManager::set_language_with_persistence
-    pub fn set_language(&mut self, language: Language) -> Result<(), LocalizationError> {
-        self.current_language = language;
+    pub fn set_language(&self, language: Language) -> Result<(), LocalizationError> {
+        self.current_language.store(language_index(language), Ordering::Relaxed);
         Ok(())
     }
 
@@ -262,7 +364,7 @@ impl LocalizationManager {
     /// ```rust
     /// use inspector_gguf::localization::{LocalizationManager, Language};
     ///
-    /// let mut manager = LocalizationManager::new()?;
+    /// let manager = LocalizationManager::new()?;
     ///
     /// // Switch to Portuguese and save preference
     /// manager.set_language_with_persistence(Language::PortugueseBrazilian)?;
@@ -271,10 +373,10 @@ impl LocalizationManager {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn set_language_with_persistence(
-        &mut self,
+        &self,
         language: Language,
     ) -> Result<(), LocalizationError> {
-        self.current_language = language;
+        self.set_language(language)?;
 
         // Persist the language preference to settings
         let settings_manager = SettingsManager::new().unwrap_or_default();
@@ -303,7 +405,12 @@ impl LocalizationManager {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn get_current_language(&self) -> Language {
-        self.current_language
+        language_from_index(self.current_language.load(Ordering::Relaxed))
+    }
+
+    /// Returns a shared handle to the loaded translations, for use on other threads.
+    pub fn translations(&self) -> Arc<Translations> {
+        Arc::clone(&self.translations)
     }
 
     /// Returns a list of all supported languages.
@@ -329,11 +436,51 @@ impl LocalizationManager {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn get_available_languages(&self) -> Vec<Language> {
-        vec![
-            Language::English,
-            Language::Russian,
-            Language::PortugueseBrazilian,
-        ]
+        Language::all().to_vec()
+    }
+
+    /// Returns code, native name, text direction, and completeness of every supported language.
+    ///
+    /// A language is complete when its translation is loaded and contains every
+    /// key of the English translation; English is complete whenever it is loaded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use inspector_gguf::localization::{Language, LocalizationManager};
+    ///
+    /// let manager = LocalizationManager::new()?;
+    /// for info in manager.language_infos() {
+    ///     println!("{} ({}){}", info.display_name, info.code, if info.is_complete { "" } else { " *" });
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn language_infos(&self) -> Vec<LanguageInfo> {
+        let loader = TranslationLoader::new();
+        let english_keys = self
+            .translations
+            .get(Language::English)
+            .map(|english| loader.get_available_keys(english));
+
+        Language::all()
+            .iter()
+            .map(|&language| {
+                let is_complete = match (&english_keys, self.translations.get(language)) {
+                    (Some(english_keys), Some(translation)) => {
+                        let keys = loader.get_available_keys(translation);
+                        english_keys.iter().all(|key| keys.binary_search(key).is_ok())
+                    }
+                    _ => false,
+                };
+                LanguageInfo {
+                    language,
+                    code: language.to_code(),
+                    display_name: language.display_name(),
+                    is_rtl: language.is_rtl(),
+                    is_complete,
+                }
+            })
+            .collect()
     }
 
     /// Loads or replaces translations for a specific language.
@@ -364,7 +511,8 @@ impl LocalizationManager {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn load_translations(&mut self, language: Language, translations: TranslationMap) {
-        self.translations.insert(language, translations);
+        // Threads holding a handle from `translations()` keep the previous maps
+        Arc::make_mut(&mut self.translations).maps.insert(language, translations);
     }
 
     /// Retrieves nested values from translation map using dot notation.
@@ -423,8 +571,120 @@ impl LocalizationManager {
 impl Default for LocalizationManager {
     fn default() -> Self {
         Self::new().unwrap_or_else(|_| LocalizationManager {
-            current_language: Language::English,
-            translations: HashMap::new(),
+            current_language: AtomicU8::new(language_index(Language::English)),
+            translations: Arc::default(),
+            fallback_chain: None,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn manager_with(current: Language) -> LocalizationManager {
+        let mut translations = HashMap::new();
+        translations.insert(
+            Language::English,
+            HashMap::from([("app".to_string(), json!({"title": "Title", "only_en": "English"}))]),
+        );
+        translations.insert(
+            Language::Russian,
+            HashMap::from([("app".to_string(), json!({"title": "Заголовок", "only_ru": "Русский"}))]),
+        );
+        translations.insert(
+            Language::PortugueseBrazilian,
+            HashMap::from([("app".to_string(), json!({"title": "Título"}))]),
+        );
+        LocalizationManager {
+            current_language: AtomicU8::new(language_index(current)),
+            translations: Arc::new(Translations::new(translations)),
+            fallback_chain: None,
+        }
+    }
+
+    #[test]
+    fn test_default_chain_is_current_then_english() {
+        let manager = manager_with(Language::PortugueseBrazilian);
+        assert_eq!(
+            manager.fallback_chain(),
+            vec![Language::PortugueseBrazilian, Language::English]
+        );
+        assert_eq!(manager.get_text("app.title"), "Título");
+        assert_eq!(manager.get_text("app.only_en"), "English");
+        // Russian is not part of the default chain
+        assert_eq!(manager.get_text("app.only_ru"), "app.only_ru");
+    }
+
+    #[test]
+    fn test_custom_chain_resolves_from_second_fallback() {
+        let mut manager = manager_with(Language::PortugueseBrazilian);
+        manager.set_fallback_chain(vec![
+            Language::PortugueseBrazilian,
+            Language::Russian,
+            Language::English,
+        ]);
+
+        assert_eq!(manager.get_text("app.title"), "Título");
+        assert_eq!(manager.get_text("app.only_ru"), "Русский");
+        assert_eq!(manager.get_text("app.only_en"), "English");
+        assert_eq!(manager.get_text("app.missing"), "app.missing");
+    }
+
+    #[test]
+    fn test_concurrent_get_text_while_switching_language() {
+        let manager = Arc::new(manager_with(Language::English));
+        let valid = ["Title", "Заголовок", "Título"];
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let manager = Arc::clone(&manager);
+                std::thread::spawn(move || {
+                    for _ in 0..2000 {
+                        let title = manager.get_text("app.title");
+                        assert!(valid.contains(&title.as_str()), "Unexpected title {}", title);
+                        // Present only in English, which stays in the default chain
+                        assert_eq!(manager.get_text("app.only_en"), "English");
+                    }
+                })
+            })
+            .collect();
+        for i in 0..2000 {
+            manager.set_language(Language::all()[i % Language::all().len()]).unwrap();
+        }
+        for reader in readers {
+            reader.join().expect("Reader thread should not panic");
+        }
+
+        manager.set_language(Language::Russian).unwrap();
+        assert_eq!(manager.get_text("app.title"), "Заголовок");
+        assert_eq!(
+            manager.translations().text(Language::PortugueseBrazilian, "app.title").as_deref(),
+            Some("Título")
+        );
+    }
+
+    #[test]
+    fn test_language_infos_cover_all_languages() {
+        let manager = manager_with(Language::English);
+        let infos = manager.language_infos();
+
+        assert_eq!(infos.iter().map(|info| info.language).collect::<Vec<_>>(), Language::all());
+        let english = &infos[0];
+        assert_eq!((english.code, english.display_name), ("en", "English"));
+        assert!(english.is_complete && !english.is_rtl);
+        // Both lack "app.only_en"
+        assert!(infos[1..].iter().all(|info| !info.is_complete));
+    }
+
+    #[test]
+    fn test_empty_chain_restores_default() {
+        let mut manager = manager_with(Language::Russian);
+        manager.set_fallback_chain(vec![Language::English]);
+        assert_eq!(manager.get_text("app.title"), "Title");
+
+        manager.set_fallback_chain(Vec::new());
+        assert_eq!(manager.get_text("app.title"), "Заголовок");
+    }
+}
@@ -13,6 +13,7 @@
 //! - **[`SystemLocaleDetector`]** - Automatic detection of system locale preferences via [`SystemLocaleDetector::detect`]
 //! - **[`SettingsManager`]** - Persistent storage of user language preferences using [`SettingsManager::save_language_preference`] and [`SettingsManager::load_language_preference`]
 //! - **[`Language`]** - Enumeration of supported languages with [`Language::from_locale`], [`Language::to_code`], and [`Language::display_name`]
+//! - **[`format_number`] / [`format_percent`] / [`format_date`]** - Per-language digit grouping, percentages and date order for human-facing values
 //!
 //! # Supported Languages
 //!
@@ -49,7 +50,7 @@
 //! use inspector_gguf::localization::{LocalizationManager, Language};
 //!
 //! // Initialize the localization manager with automatic language detection
-//! let mut manager = LocalizationManager::new()?;
+//! let manager = LocalizationManager::new()?;
 //!
 //! // Get translated text using dot notation for nested keys
 //! let title = manager.get_text("app.title");
@@ -142,8 +143,9 @@
 //! # Thread Safety
 //!
 //! The localization system is designed to be thread-safe when used appropriately:
-//! - [`LocalizationManager`] should be wrapped in `Arc<Mutex<>>` for shared access
-//! - Translation data is immutable once loaded
+//! - [`LocalizationManager`] can be shared as `Arc<LocalizationManager>`: lookups and
+//!   language switches take `&self`, with the current language held in an atomic
+//! - Translation data is immutable once loaded ([`Translations`], shared as an `Arc`)
 //! - Settings operations use atomic file writes
 //!
 //! # Performance Considerations
@@ -167,11 +169,14 @@ pub mod detector;
 pub mod settings;
 /// Translation provider interface and implementations
 pub mod provider;
+/// Locale-aware number and date formatting
+pub mod formatting;
 
-pub use language::Language;
+pub use language::{Language, LanguageInfo};
 pub use error::{LocalizationError, SettingsError};
-pub use manager::LocalizationManager;
+pub use manager::{LocalizationManager, Translations};
 pub use loader::{TranslationLoader, TranslationMap};
 pub use detector::SystemLocaleDetector;
-pub use settings::{SettingsManager, AppSettings};
-pub use provider::LanguageProvider;
\ No newline at end of file
+pub use settings::{SettingsManager, AppSettings, migrate_settings, CURRENT_SETTINGS_VERSION};
+pub use provider::{LanguageProvider, MapLanguageProvider};
+pub use formatting::{format_number, format_percent, format_date};
\ No newline at end of file
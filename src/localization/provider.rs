@@ -22,6 +22,8 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
+use crate::localization::{TranslationLoader, TranslationMap};
+
 /// Trait for types that provide access to localized text.
 ///
 /// This trait defines the interface for accessing translated strings throughout
@@ -32,6 +34,8 @@
 ///
 /// The primary implementation is [`LocalizationManager`], which loads translations
 /// from JSON files and provides fallback mechanisms for missing keys.
+/// [`MapLanguageProvider`] serves a single [`TranslationMap`] without the rest of
+/// the application state.
 ///
 /// # Examples
 ///
@@ -40,7 +44,7 @@
 /// ```rust
 /// use inspector_gguf::localization::{LanguageProvider, LocalizationManager, Language};
 ///
-/// let mut manager = LocalizationManager::new()?;
+/// let manager = LocalizationManager::new()?;
 /// manager.set_language(Language::English)?;
 ///
 /// // Get simple translated text
@@ -86,7 +90,7 @@ pub trait LanguageProvider {
     /// ```rust
     /// use inspector_gguf::localization::{LanguageProvider, LocalizationManager, Language};
     ///
-    /// let mut manager = LocalizationManager::new()?;
+    /// let manager = LocalizationManager::new()?;
     ///
     /// // Get English translation
     /// manager.set_language(Language::English)?;
@@ -137,6 +141,32 @@ pub trait LanguageProvider {
         }
         text
     }
+
+    /// Returns the language used for numbers and percentages (English by default).
+    fn language(&self) -> crate::localization::Language {
+        crate::localization::Language::English
+    }
+
+    /// Formats a count with the digit grouping of [`LanguageProvider::language`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use inspector_gguf::localization::{LanguageProvider, LocalizationManager, Language};
+    ///
+    /// let manager = LocalizationManager::new()?;
+    /// manager.set_language(Language::Russian)?;
+    /// assert_eq!(manager.format_number(1234), "1\u{a0}234");
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    fn format_number(&self, n: u64) -> String {
+        crate::localization::format_number(n, self.language())
+    }
+
+    /// Formats a progress fraction with [`crate::localization::format_percent`].
+    fn format_percent(&self, fraction: f32) -> String {
+        crate::localization::format_percent(fraction, self.language())
+    }
 }
 
 /// Implementation of LanguageProvider for LocalizationManager.
@@ -147,4 +177,112 @@ impl LanguageProvider for crate::localization::LocalizationManager {
     fn t(&self, key: &str) -> String {
         self.get_text(key)
     }
+
+    fn language(&self) -> crate::localization::Language {
+        self.get_current_language()
+    }
+}
+
+/// Language provider backed by a single loaded translation map.
+///
+/// Useful for rendering panels with the shipped translations without constructing
+/// the full application or a [`LocalizationManager`]. There is no fallback
+/// language: keys missing from the map resolve to the key itself.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::localization::{Language, LanguageProvider, MapLanguageProvider, TranslationLoader};
+///
+/// let translations = TranslationLoader::new().load_translation(Language::English)?;
+/// let provider = MapLanguageProvider::new(translations);
+///
+/// assert_eq!(provider.t("buttons.load"), "Load");
+/// assert_eq!(provider.t("missing.key"), "missing.key");
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// [`LocalizationManager`]: crate::localization::LocalizationManager
+#[derive(Debug, Clone, Default)]
+pub struct MapLanguageProvider {
+    translations: TranslationMap,
+}
+
+impl MapLanguageProvider {
+    /// Creates a provider serving the given translations.
+    pub fn new(translations: TranslationMap) -> Self {
+        Self { translations }
+    }
+
+    /// Returns the underlying translation map.
+    pub fn translations(&self) -> &TranslationMap {
+        &self.translations
+    }
+}
+
+impl LanguageProvider for MapLanguageProvider {
+    fn t(&self, key: &str) -> String {
+        TranslationLoader::get_translation_value(&self.translations, key).unwrap_or_else(|| key.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::localization::Language;
+
+    fn english_provider() -> MapLanguageProvider {
+        let translations = TranslationLoader::new()
+            .load_translation(Language::English)
+            .expect("English translations should load");
+        MapLanguageProvider::new(translations)
+    }
+
+    #[test]
+    fn test_map_provider_resolves_shipped_translations() {
+        let provider = english_provider();
+        assert_eq!(provider.t("buttons.load"), "Load");
+        assert_eq!(provider.t("app.title"), "Inspector GGUF");
+    }
+
+    #[test]
+    fn test_map_provider_substitutes_args() {
+        let provider = english_provider();
+        assert_eq!(provider.t_with_args("messages.export_failed", &["disk full"]), "Export failed: disk full");
+    }
+
+    #[test]
+    fn test_map_provider_missing_key_returns_key() {
+        let provider = MapLanguageProvider::default();
+        assert_eq!(provider.t("buttons.load"), "buttons.load");
+    }
+
+    struct FixedLanguage(Language);
+
+    impl LanguageProvider for FixedLanguage {
+        fn t(&self, key: &str) -> String {
+            key.to_string()
+        }
+
+        fn language(&self) -> Language {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_provider_counts_follow_language() {
+        let english = FixedLanguage(Language::English);
+        let russian = FixedLanguage(Language::Russian);
+
+        assert_eq!(english.format_number(2048), "2,048");
+        assert_eq!(russian.format_number(2048), "2\u{a0}048");
+        assert_ne!(english.format_number(2048), russian.format_number(2048));
+        assert_eq!(english.format_percent(0.25), "25%");
+        assert_eq!(russian.format_percent(0.25), "25\u{a0}%");
+    }
+
+    #[test]
+    fn test_map_provider_defaults_to_english_numbers() {
+        assert_eq!(MapLanguageProvider::default().format_number(1234), "1,234");
+    }
 }
@@ -1,6 +1,8 @@
 use std::path::{Path, PathBuf};
 use std::fs;
+use std::sync::{Arc, Mutex, Once, OnceLock};
 use serde::{Deserialize, Serialize};
+use crate::format::DEFAULT_PREVIEW_LIMIT;
 use crate::localization::{Language, SettingsError};
 
 /// Application settings structure for persistent storage.
@@ -12,7 +14,17 @@ use crate::localization::{Language, SettingsError};
 /// # Fields
 ///
 /// - `language` - User's preferred interface language
-/// - `version` - Application version (for settings migration)
+/// - `font_scale` - Multiplier applied on top of the adaptive font sizes
+/// - `preview_limit` - Tokenizer tokens/merges shown in the content panel (`0` shows all)
+/// - `auto_reload` - Reload the open file when it changes on disk
+/// - `metadata_cache` - Keep parsed metadata of recently opened files in memory
+/// - `use_display_font` - Use the Rubik Distressed display font (off for egui's plain fonts)
+/// - `pinned_keys` - Metadata keys pinned to the top of the content panel, in pin order
+/// - `remember_filters` - Restore the last content panel filter when a file is reopened
+/// - `high_contrast` - Use the black, white and yellow high-contrast theme
+/// - `version` - Settings schema version (for settings migration)
+/// - `gui_fields` - Settings owned by the GUI (`metadata_cache_capacity`, `entries_per_page`,
+///   `export_options`, `metadata_snapshots` and `file_filters`), kept as raw JSON
 ///
 /// # Serialization
 ///
@@ -22,26 +34,144 @@ use crate::localization::{Language, SettingsError};
 /// ```json
 /// {
 ///   "language": "Russian",
-///   "version": "1.0"
+///   "font_scale": 1.0,
+///   "preview_limit": 3,
+///   "auto_reload": false,
+///   "metadata_cache": false,
+///   "metadata_cache_capacity": 3,
+///   "use_display_font": true,
+///   "entries_per_page": 100,
+///   "pinned_keys": ["general.architecture"],
+///   "export_options": {
+///     "sanitize_formulas": true,
+///     "formula_prefix": "'",
+///     "include_keys": null,
+///     "exclude_keys": ["tokenizer.*"],
+///     "filtered_only": false,
+///     "verify": false
+///   },
+///   "metadata_snapshots": [
+///     { "file": "3f2a9c1e8b7d6a50", "values": { "general.name": "9b1c0d2e3f4a5b6c" } }
+///   ],
+///   "remember_filters": true,
+///   "file_filters": [{ "file": "3f2a9c1e8b7d6a50", "filter": "tokenizer." }],
+///   "high_contrast": false,
+///   "version": "12.0"
 /// }
 /// ```
+///
+/// # Versioning
+///
+/// Missing fields are filled with their defaults on load, so files written by
+/// older releases keep working. See [`migrate_settings`] for how the `version`
+/// field is upgraded.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AppSettings {
     /// User's preferred interface language.
     pub language: Language,
-    /// Application version for settings migration tracking.
+    /// Multiplier applied on top of the adaptive font sizes.
+    pub font_scale: f32,
+    /// Number of tokenizer tokens/merges previewed in the content panel (`0` shows all).
+    pub preview_limit: usize,
+    /// Whether the open file is reloaded automatically when it changes on disk.
+    pub auto_reload: bool,
+    /// Whether parsed metadata of recently opened files is cached in memory.
+    pub metadata_cache: bool,
+    /// Whether the Rubik Distressed display font is used instead of egui's default fonts.
+    pub use_display_font: bool,
+    /// Metadata keys pinned above the other entries in the content panel, in pin order.
+    pub pinned_keys: Vec<String>,
+    /// Whether the last content panel filter of each file is restored when it is reopened.
+    pub remember_filters: bool,
+    /// Whether the high-contrast theme replaces the Inspector theme (see `gui::theme::apply_high_contrast_theme`).
+    pub high_contrast: bool,
+    /// Settings schema version for migration tracking.
     pub version: String,
+    /// Fields this module does not know, kept as raw JSON so that saving the settings does not drop them.
+    ///
+    /// This holds the GUI-only settings, which the GUI reads and writes through
+    /// `gui::settings::GuiSettings`, and fields written by newer releases.
+    #[serde(flatten)]
+    pub gui_fields: serde_json::Map<String, serde_json::Value>,
 }
 
 impl Default for AppSettings {
     fn default() -> Self {
         Self {
             language: Language::English,
-            version: "1.0".to_string(),
+            font_scale: 1.0,
+            preview_limit: DEFAULT_PREVIEW_LIMIT,
+            auto_reload: false,
+            metadata_cache: false,
+            use_display_font: true,
+            pinned_keys: Vec::new(),
+            remember_filters: false,
+            high_contrast: false,
+            version: CURRENT_SETTINGS_VERSION.to_string(),
+            gui_fields: serde_json::Map::new(),
         }
     }
 }
 
+/// Settings schema version written by this build.
+///
+/// Bump the major component whenever a field is added to [`AppSettings`].
+pub const CURRENT_SETTINGS_VERSION: &str = "12.0";
+
+/// Extracts the major schema number from a version string such as `"1.0"`.
+fn settings_major_version(version: &str) -> Option<u32> {
+    version.split('.').next()?.trim().parse().ok()
+}
+
+/// Upgrades raw settings JSON to the current schema.
+///
+/// Fields missing from older files are filled with their defaults and the
+/// `version` field is bumped to [`CURRENT_SETTINGS_VERSION`]. Files written by a
+/// newer release are loaded as far as the known fields allow, but their version
+/// is left untouched so they are not downgraded on the next save.
+///
+/// # Returns
+///
+/// The migrated settings and a flag telling whether the file should be written
+/// back to disk.
+///
+/// # Errors
+///
+/// Returns an error if a recognised field has an incompatible type.
+///
+/// # Examples
+///
+/// ```rust
+/// use inspector_gguf::localization::settings::{migrate_settings, CURRENT_SETTINGS_VERSION};
+/// use inspector_gguf::localization::Language;
+///
+/// let old = serde_json::json!({ "language": "Russian", "version": "1.0" });
+/// let (settings, upgraded) = migrate_settings(old)?;
+///
+/// assert!(upgraded);
+/// assert_eq!(settings.language, Language::Russian);
+/// assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub fn migrate_settings(value: serde_json::Value) -> Result<(AppSettings, bool), serde_json::Error> {
+    let current = settings_major_version(CURRENT_SETTINGS_VERSION).unwrap_or(0);
+    let stored = value
+        .get("version")
+        .and_then(|v| v.as_str())
+        .and_then(settings_major_version)
+        .unwrap_or(1);
+
+    let mut settings: AppSettings = serde_json::from_value(value)?;
+
+    if stored < current {
+        settings.version = CURRENT_SETTINGS_VERSION.to_string();
+        Ok((settings, true))
+    } else {
+        Ok((settings, false))
+    }
+}
+
 /// Manages persistent storage of application settings across sessions.
 ///
 /// The `SettingsManager` handles reading, writing, and validating application
@@ -93,7 +223,7 @@ impl Default for AppSettings {
 /// // Load complete settings
 /// let mut settings = settings_manager.load_settings()?;
 /// settings.language = Language::PortugueseBrazilian;
-/// settings.version = "2.0".to_string();
+/// settings.font_scale = 1.25;
 ///
 /// // Save complete settings
 /// settings_manager.save_settings(&settings)?;
@@ -116,8 +246,13 @@ impl Default for AppSettings {
 /// ```
 pub struct SettingsManager {
     settings_path: PathBuf,
+    /// Session-only settings used when the settings directory is not writable
+    memory: Option<Arc<Mutex<Option<AppSettings>>>>,
 }
 
+/// Settings shared by every in-memory [`SettingsManager`] of this process.
+static IN_MEMORY_SETTINGS: OnceLock<Arc<Mutex<Option<AppSettings>>>> = OnceLock::new();
+
 impl SettingsManager {
     /// Creates a new SettingsManager with platform-appropriate settings directory.
     ///
@@ -153,8 +288,12 @@ impl SettingsManager {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn new() -> Result<Self, SettingsError> {
-        let settings_path = Self::get_platform_settings_path()?;
-        let manager = SettingsManager { settings_path };
+        Self::with_path(Self::get_platform_settings_path()?)
+    }
+
+    /// Creates a manager for `settings_path`, checking that its directory is writable.
+    fn with_path(settings_path: PathBuf) -> Result<Self, SettingsError> {
+        let manager = SettingsManager { settings_path, memory: None };
         
         // Ensure the settings directory exists and is writable
         manager.ensure_settings_directory()?;
@@ -162,6 +301,24 @@ impl SettingsManager {
         Ok(manager)
     }
 
+    /// Creates a manager that keeps settings in memory for the current session only.
+    ///
+    /// [`Default`] falls back to this when the settings directory cannot be
+    /// written, so the application still runs; all in-memory managers share
+    /// the same settings. Nothing is written to disk.
+    pub fn in_memory() -> Self {
+        let memory = IN_MEMORY_SETTINGS.get_or_init(|| Arc::new(Mutex::new(None)));
+        SettingsManager {
+            settings_path: Self::get_platform_settings_path().unwrap_or_else(|_| PathBuf::from("settings.json")),
+            memory: Some(Arc::clone(memory)),
+        }
+    }
+
+    /// Returns `true` if settings are kept in memory instead of on disk.
+    pub fn is_in_memory(&self) -> bool {
+        self.memory.is_some()
+    }
+
     /// Loads the user's language preference from the settings file with error recovery.
     ///
     /// This method attempts to load the saved language preference from the settings
@@ -171,7 +328,8 @@ impl SettingsManager {
     /// # Returns
     ///
     /// Returns `Some(Language)` if a preference is found or defaults are successfully
-    /// created, or `None` if all recovery attempts fail.
+    /// created, or `None` if all recovery attempts fail. In-memory managers return
+    /// `None` until a language is saved during the session.
     ///
     /// # Error Recovery
     ///
@@ -198,6 +356,9 @@ impl SettingsManager {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn load_language_preference(&self) -> Option<Language> {
+        if let Some(memory) = &self.memory {
+            return memory.lock().ok()?.as_ref().map(|settings| settings.language);
+        }
         match self.load_settings() {
             Ok(settings) => Some(settings.language),
             Err(_) => {
@@ -285,8 +446,15 @@ impl SettingsManager {
         &self.settings_path
     }
 
-    /// Load complete settings from file with error recovery
+    /// Load complete settings from file with error recovery.
+    ///
+    /// Files written by older releases are migrated with [`migrate_settings`]
+    /// and the upgraded settings are written back.
     pub fn load_settings(&self) -> Result<AppSettings, SettingsError> {
+        if let Some(memory) = &self.memory {
+            let stored = memory.lock().map_err(|_| SettingsError::ReadError)?;
+            return Ok(stored.clone().unwrap_or_default());
+        }
         if !self.settings_path.exists() {
             // Create default settings file if it doesn't exist
             let default_settings = AppSettings::default();
@@ -299,8 +467,13 @@ impl SettingsManager {
 
         match fs::read_to_string(&self.settings_path) {
             Ok(content) => {
-                match serde_json::from_str::<AppSettings>(&content) {
-                    Ok(settings) => Ok(settings),
+                match serde_json::from_str::<serde_json::Value>(&content).and_then(migrate_settings) {
+                    Ok((settings, upgraded)) => {
+                        if upgraded && self.save_settings(&settings).is_err() {
+                            eprintln!("Warning: Failed to write migrated settings");
+                        }
+                        Ok(settings)
+                    }
                     Err(_) => {
                         // Settings file is corrupted, create backup and use defaults
                         self.backup_corrupted_settings()?;
@@ -319,9 +492,14 @@ impl SettingsManager {
 
     /// Save complete settings to file with atomic write
     pub fn save_settings(&self, settings: &AppSettings) -> Result<(), SettingsError> {
+        if let Some(memory) = &self.memory {
+            *memory.lock().map_err(|_| SettingsError::WriteError)? = Some(settings.clone());
+            return Ok(());
+        }
         // Ensure parent directory exists
         if let Some(parent) = self.settings_path.parent() {
-            fs::create_dir_all(parent).map_err(|_| SettingsError::DirectoryCreation)?;
+            fs::create_dir_all(parent)
+                .map_err(|e| SettingsError::from_io(&e, parent, SettingsError::DirectoryCreation))?;
         }
 
         let content = serde_json::to_string_pretty(settings)
@@ -331,7 +509,7 @@ impl SettingsManager {
         let temp_path = self.settings_path.with_extension("tmp");
         
         fs::write(&temp_path, &content)
-            .map_err(|_| SettingsError::WriteError)?;
+            .map_err(|e| SettingsError::from_io(&e, &temp_path, SettingsError::WriteError))?;
         
         fs::rename(&temp_path, &self.settings_path)
             .map_err(|_| SettingsError::WriteError)?;
@@ -350,10 +528,14 @@ impl SettingsManager {
     }
 
     /// Validate settings directory permissions and create if necessary
+    ///
+    /// Returns [`SettingsError::PermissionDenied`] if the directory cannot be
+    /// written because of its permissions.
     pub fn ensure_settings_directory(&self) -> Result<(), SettingsError> {
         if let Some(parent) = self.settings_path.parent() {
             if !parent.exists() {
-                fs::create_dir_all(parent).map_err(|_| SettingsError::DirectoryCreation)?;
+                fs::create_dir_all(parent)
+                    .map_err(|e| SettingsError::from_io(&e, parent, SettingsError::DirectoryCreation))?;
             }
             
             // Test write permissions by creating a temporary file
@@ -363,7 +545,7 @@ impl SettingsManager {
                     let _ = fs::remove_file(&test_file); // Clean up test file
                     Ok(())
                 }
-                Err(_) => Err(SettingsError::DirectoryCreation),
+                Err(e) => Err(SettingsError::from_io(&e, parent, SettingsError::DirectoryCreation)),
             }
         } else {
             Err(SettingsError::DirectoryCreation)
@@ -418,9 +600,254 @@ impl SettingsManager {
 }
 
 impl Default for SettingsManager {
+    /// Uses the platform settings file, or [`SettingsManager::in_memory`] if it
+    /// cannot be written; the cause is logged once per process.
     fn default() -> Self {
-        Self::new().unwrap_or_else(|_| SettingsManager {
-            settings_path: PathBuf::from("settings.json"),
+        Self::new().unwrap_or_else(|e| {
+            static WARN_ONCE: Once = Once::new();
+            WARN_ONCE.call_once(|| eprintln!("Warning: Settings will not be saved: {}", e));
+            Self::in_memory()
         })
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manager_in(dir: &Path) -> SettingsManager {
+        SettingsManager {
+            settings_path: dir.join("settings.json"),
+            memory: None,
+        }
+    }
+
+    #[test]
+    fn test_permission_errors_are_typed() {
+        let path = Path::new("/settings");
+        let denied = std::io::Error::from(std::io::ErrorKind::PermissionDenied);
+        assert!(matches!(
+            SettingsError::from_io(&denied, path, SettingsError::WriteError),
+            SettingsError::PermissionDenied { path } if path == Path::new("/settings")
+        ));
+        let missing = std::io::Error::from(std::io::ErrorKind::NotFound);
+        assert!(matches!(
+            SettingsError::from_io(&missing, path, SettingsError::WriteError),
+            SettingsError::WriteError
+        ));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_unwritable_directory_is_permission_denied() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let settings_dir = dir.path().join("readonly");
+        fs::create_dir(&settings_dir).unwrap();
+        fs::set_permissions(&settings_dir, fs::Permissions::from_mode(0o500)).unwrap();
+
+        // Privileged users (e.g. root in CI containers) may write regardless of the mode
+        if fs::write(settings_dir.join("probe"), b"").is_ok() {
+            fs::set_permissions(&settings_dir, fs::Permissions::from_mode(0o700)).unwrap();
+            eprintln!("skipping test_unwritable_directory_is_permission_denied: a read-only directory is writable by this user");
+            return;
+        }
+        let result = SettingsManager::with_path(settings_dir.join("settings.json"));
+        fs::set_permissions(&settings_dir, fs::Permissions::from_mode(0o700)).unwrap();
+        assert!(matches!(result, Err(SettingsError::PermissionDenied { path }) if path == settings_dir));
+    }
+
+    #[test]
+    fn test_in_memory_settings_are_not_persisted() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let manager = SettingsManager {
+            settings_path: dir.path().join("settings.json"),
+            memory: Some(Arc::new(Mutex::new(None))),
+        };
+        assert!(manager.is_in_memory());
+        assert_eq!(manager.load_language_preference(), None);
+        manager.save_language_preference(Language::Russian).expect("Should save in memory");
+        assert_eq!(manager.load_language_preference(), Some(Language::Russian));
+        assert!(!manager.get_settings_path().exists());
+    }
+
+    #[test]
+    fn test_migrate_v1_settings() {
+        let v1 = serde_json::json!({ "language": "Russian", "version": "1.0" });
+        let (settings, upgraded) = migrate_settings(v1).expect("v1 settings should migrate");
+
+        assert!(upgraded);
+        assert_eq!(settings.language, Language::Russian);
+        assert_eq!(settings.font_scale, 1.0);
+        assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+    }
+
+    #[test]
+    fn test_load_v1_file_is_upgraded_on_disk() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        let manager = manager_in(dir.path());
+        fs::write(
+            manager.get_settings_path(),
+            r#"{ "language": "PortugueseBrazilian", "version": "1.0" }"#,
+        )
+        .unwrap();
+
+        let settings = manager.load_settings().expect("Should load v1 settings");
+        assert_eq!(settings.language, Language::PortugueseBrazilian);
+        assert_eq!(settings.font_scale, 1.0);
+        assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+
+        let written: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(manager.get_settings_path()).unwrap()).unwrap();
+        assert_eq!(written["version"], CURRENT_SETTINGS_VERSION);
+        assert_eq!(written["font_scale"], 1.0);
+    }
+
+    #[test]
+    fn test_migrate_v2_settings_adds_preview_limit() {
+        let v2 = serde_json::json!({ "language": "English", "font_scale": 1.25, "version": "2.0" });
+        let (settings, upgraded) = migrate_settings(v2).expect("v2 settings should migrate");
+
+        assert!(upgraded);
+        assert_eq!(settings.font_scale, 1.25);
+        assert_eq!(settings.preview_limit, DEFAULT_PREVIEW_LIMIT);
+        assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_v3_settings_disables_auto_reload() {
+        let v3 = serde_json::json!({ "language": "Russian", "preview_limit": 10, "version": "3.0" });
+        let (settings, upgraded) = migrate_settings(v3).expect("v3 settings should migrate");
+
+        assert!(upgraded);
+        assert_eq!(settings.preview_limit, 10);
+        assert!(!settings.auto_reload);
+        assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_v4_settings_disables_metadata_cache() {
+        let v4 = serde_json::json!({ "language": "English", "auto_reload": true, "version": "4.0" });
+        let (settings, upgraded) = migrate_settings(v4).expect("v4 settings should migrate");
+
+        assert!(upgraded);
+        assert!(settings.auto_reload);
+        assert!(!settings.metadata_cache);
+        assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_v5_settings_keeps_display_font() {
+        let v5 = serde_json::json!({ "language": "English", "metadata_cache": true, "version": "5.0" });
+        let (settings, upgraded) = migrate_settings(v5).expect("v5 settings should migrate");
+
+        assert!(upgraded);
+        assert!(settings.metadata_cache);
+        assert!(settings.use_display_font);
+        assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_v6_settings_keeps_display_font_choice() {
+        let v6 = serde_json::json!({ "language": "English", "use_display_font": false, "version": "6.0" });
+        let (settings, upgraded) = migrate_settings(v6).expect("v6 settings should migrate");
+
+        assert!(upgraded);
+        assert!(!settings.use_display_font);
+        assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_v7_settings_has_no_pinned_keys() {
+        let v7 = serde_json::json!({ "language": "English", "entries_per_page": 50, "version": "7.0" });
+        let (settings, upgraded) = migrate_settings(v7).expect("v7 settings should migrate");
+
+        assert!(upgraded);
+        assert!(settings.pinned_keys.is_empty());
+        assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_v8_settings_keeps_pinned_keys() {
+        let v8 = serde_json::json!({ "language": "English", "pinned_keys": ["general.name"], "version": "8.0" });
+        let (settings, upgraded) = migrate_settings(v8).expect("v8 settings should migrate");
+
+        assert!(upgraded);
+        assert_eq!(settings.pinned_keys, ["general.name"]);
+        assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_v9_settings_keeps_gui_fields() {
+        let v9 = serde_json::json!({ "language": "English", "export_options": { "filtered_only": true }, "version": "9.0" });
+        let (settings, upgraded) = migrate_settings(v9).expect("v9 settings should migrate");
+
+        assert!(upgraded);
+        assert_eq!(settings.gui_fields["export_options"]["filtered_only"], true);
+        assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_v10_settings_does_not_remember_filters() {
+        let v10 = serde_json::json!({ "language": "English", "metadata_snapshots": [], "version": "10.0" });
+        let (settings, upgraded) = migrate_settings(v10).expect("v10 settings should migrate");
+
+        assert!(upgraded);
+        assert!(!settings.remember_filters);
+        assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_v11_settings_uses_regular_theme() {
+        let v11 = serde_json::json!({ "language": "English", "remember_filters": true, "version": "11.0" });
+        let (settings, upgraded) = migrate_settings(v11).expect("v11 settings should migrate");
+
+        assert!(upgraded);
+        assert!(settings.remember_filters);
+        assert!(!settings.high_contrast);
+        assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+    }
+
+    #[test]
+    fn test_saving_keeps_gui_fields() {
+        let stored = serde_json::json!({
+            "language": "English",
+            "entries_per_page": 50,
+            "export_options": { "filtered_only": true },
+            "version": CURRENT_SETTINGS_VERSION
+        });
+        let (mut settings, _) = migrate_settings(stored).expect("Settings should load");
+        settings.language = Language::Russian;
+
+        let written = serde_json::to_value(&settings).unwrap();
+        assert_eq!(written["language"], "Russian");
+        assert_eq!(written["entries_per_page"], 50);
+        assert_eq!(written["export_options"]["filtered_only"], true);
+    }
+
+    #[test]
+    fn test_future_version_is_tolerated() {
+        let future = serde_json::json!({
+            "language": "Russian",
+            "font_scale": 1.5,
+            "version": "99.0",
+            "some_future_field": true
+        });
+        let (settings, upgraded) = migrate_settings(future).expect("Future settings should load");
+
+        assert!(!upgraded);
+        assert_eq!(settings.language, Language::Russian);
+        assert_eq!(settings.font_scale, 1.5);
+        assert_eq!(settings.version, "99.0");
+    }
+
+    #[test]
+    fn test_missing_version_is_treated_as_v1() {
+        let legacy = serde_json::json!({ "language": "English" });
+        let (settings, upgraded) = migrate_settings(legacy).expect("Legacy settings should load");
+
+        assert!(upgraded);
+        assert_eq!(settings.version, CURRENT_SETTINGS_VERSION);
+    }
+}
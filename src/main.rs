@@ -1,7 +1,11 @@
 use inspector_gguf::gui;
+use inspector_gguf::gui::loader::LoadError;
 use structopt::StructOpt;
 
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use inspector_gguf::cli::{compare_to_reference, export_to_sqlite, find_files, is_http_url, metadata_keys, render_key_list, report_missing_keys, select_metadata_keys, url_output_path, validate_metadata_dir_with_options, CliError, ScanOptions};
 use image::GenericImageView;
 use egui::IconData;
 
@@ -49,17 +53,93 @@ struct Opt {
     #[structopt(long, parse(from_os_str))]
     metadata_dir: Option<PathBuf>,
 
-    /// Path to GGUF file for CLI export
+    /// Also check files in subdirectories when scanning a directory
+    #[structopt(long)]
+    recursive: bool,
+
+    /// Descend into symlinked subdirectories with --recursive (each directory is visited once)
+    #[structopt(long, requires = "recursive")]
+    follow_symlinks: bool,
+
+    /// Path to GGUF file for CLI export, or an HTTP(S) URL with the `http` feature
     #[structopt(parse(from_os_str))]
     input: Option<PathBuf>,
 
     /// Output JSON file (CLI only)
     #[structopt(short, long, parse(from_os_str))]
     output: Option<PathBuf>,
+
+    /// Print a concise model report instead of exporting metadata
+    #[structopt(long)]
+    info: bool,
+
+    /// Print the metadata keys only, one per line (a JSON array with --json)
+    #[structopt(long, conflicts_with_all = &["info", "stdin"])]
+    keys: bool,
+
+    /// Print the --info report or the --keys list as JSON
+    #[structopt(long)]
+    json: bool,
+
+    /// Reference YAML of expected metadata; prints differences and fails on mismatch
+    #[structopt(long, parse(from_os_str), conflicts_with_all = &["info", "stdin"])]
+    compare_ref: Option<PathBuf>,
+
+    /// Write a HuggingFace tokenizer.json rebuilt from the tokenizer metadata (BPE models only)
+    #[structopt(long, parse(from_os_str), conflicts_with_all = &["info", "stdin", "compare-ref"])]
+    tokenizer_json: Option<PathBuf>,
+
+    /// Append metadata of the input GGUF file (or of every .gguf file in the input directory) to an SQLite database
+    #[structopt(long, parse(from_os_str), conflicts_with_all = &["info", "stdin", "compare-ref", "tokenizer-json"])]
+    sqlite: Option<PathBuf>,
+
+    /// Export only this metadata key; repeat for several keys (`*` wildcards allowed)
+    #[structopt(long, number_of_values = 1, conflicts_with_all = &["info", "keys", "stdin", "compare-ref", "tokenizer-json", "sqlite"])]
+    metadata_key: Vec<String>,
+
+    /// Fail when a key given with --metadata-key is not in the file
+    #[structopt(long, requires = "metadata-key")]
+    strict: bool,
+
+    /// Read the GGUF file from stdin; the JSON export goes to stdout unless --output is given
+    #[structopt(long, conflicts_with_all = &["input", "info"])]
+    stdin: bool,
+
+    /// Reload the file open in the GUI whenever it changes on disk
+    #[structopt(long)]
+    watch: bool,
+
+    /// Suppress progress and "OK" messages (CLI only)
+    #[structopt(short, long)]
+    quiet: bool,
+
+    /// Print failures to stderr as a single JSON object {"error", "kind"}
+    #[structopt(long)]
+    json_errors: bool,
+
+    /// Print the version of this build (with git hash when known) and exit
+    #[structopt(long)]
+    version_info: bool,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+fn main() -> ExitCode {
     let opt = Opt::from_args();
+    let json_errors = opt.json_errors;
+
+    match run(opt) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e.render(json_errors));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(opt: Opt) -> Result<(), CliError> {
+    if opt.version_info {
+        println!("{}", gui::updater::version_info());
+        return Ok(());
+    }
 
     // Устанавливаем заголовок консольного окна
     set_console_title("Inspector GGUF");
@@ -103,29 +183,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         puffin::profile_scope!("profiling_test");
 
-        // File reading phase
-        let file_read_start = std::time::Instant::now();
         let file_size = match std::fs::metadata(&model_path) {
             Ok(metadata) => metadata.len(),
             Err(_) => 0,
         };
 
-        let mut f = std::fs::File::open(&model_path)?;
-        let mut buf = Vec::new();
-        use std::io::Read;
-        f.read_to_end(&mut buf)?;
-        let file_read_duration = file_read_start.elapsed();
-
-        // GGUF parsing phase
-        let parsing_start = std::time::Instant::now();
-        let mut cursor = std::io::Cursor::new(&buf);
-        let _content = candle::quantized::gguf_file::Content::read(&mut cursor)?;
-        let parsing_duration = parsing_start.elapsed();
-
-        // Metadata processing phase
-        let metadata_start = std::time::Instant::now();
-        let metadata_result = inspector_gguf::format::load_gguf_metadata_with_full_content_sync(&model_path);
-        let metadata_duration = metadata_start.elapsed();
+        // Чтение, парсинг и обработка метаданных с замером каждой фазы
+        let metadata_result = inspector_gguf::format::load_gguf_metadata_timed(&model_path);
 
         let total_duration = profiling_start.elapsed();
 
@@ -139,13 +203,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         // Save profiling results and metadata to file
         let profiling_results = match &metadata_result {
-            Ok(metadata) => {
+            Ok((metadata, timings)) => {
                 println!(
                     "Successfully loaded {} metadata entries from real model",
                     metadata.len()
                 );
                 // Print some sample metadata
-                for (key, value, _) in metadata.iter().take(5) {
+                for (key, value) in metadata.iter().take(5) {
                     println!("  {}: {}", key, value.chars().take(50).collect::<String>());
                 }
 
@@ -153,7 +217,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 let sample_metadata: std::collections::HashMap<String, String> = metadata
                     .iter()
                     .take(10)
-                    .map(|(k, v, _)| (k.clone(), v.clone()))
+                    .map(|(k, v)| (k.clone(), v.clone()))
                     .collect();
 
                 serde_json::json!({
@@ -167,9 +231,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     "performance_metrics": {
                         "total_duration_ms": total_duration.as_millis(),
                         "total_duration_secs": total_duration.as_secs_f64(),
-                        "file_read_duration_ms": file_read_duration.as_millis(),
-                        "gguf_parsing_duration_ms": parsing_duration.as_millis(),
-                        "metadata_processing_duration_ms": metadata_duration.as_millis(),
+                        "file_read_duration_ms": timings.read.as_millis(),
+                        "gguf_parsing_duration_ms": timings.parse.as_millis(),
+                        "metadata_processing_duration_ms": timings.process.as_millis(),
                         "memory_used_kb": memory_used_kb,
                         "initial_memory_kb": initial_memory,
                         "final_memory_kb": final_memory,
@@ -237,16 +301,31 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             ..Default::default()
         };
         
+        let watch = opt.watch;
         let _ = eframe::run_native(
             "Inspector GGUF",
             native_options,
-            Box::new(|_cc| Ok(Box::new(gui::GgufApp::default()))),
+            Box::new(move |_cc| {
+                let mut app = gui::GgufApp::default();
+                // --watch enables auto-reload for this session without saving it
+                app.auto_reload |= watch;
+                Ok(Box::new(app))
+            }),
         );
         return Ok(());
     }
 
+    let scan_options = ScanOptions { recursive: opt.recursive, follow_symlinks: opt.follow_symlinks };
+
     // Если не указаны входные аргументы, по умолчанию проверим каталог GGUF в корне проекта
-    if opt.input.is_none() && opt.metadata_dir.is_none() {
+    if opt.input.is_none()
+        && opt.metadata_dir.is_none()
+        && !opt.info
+        && !opt.stdin
+        && opt.compare_ref.is_none()
+        && opt.tokenizer_json.is_none()
+        && opt.sqlite.is_none()
+    {
         // Try to detect repository root by looking for README.md or .git at current or parent directories
         let mut cwd = std::env::current_dir()?;
         let mut repo_root: Option<PathBuf> = None;
@@ -262,7 +341,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         if let Some(root) = repo_root {
             let default_gguf_dir = root.join("models/gguf");
             if default_gguf_dir.exists() {
-                check_gguf_dir(&default_gguf_dir)?;
+                check_gguf_dir(&default_gguf_dir, scan_options, opt.quiet, &mut std::io::stdout())?;
                 return Ok(());
             }
         }
@@ -270,134 +349,303 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // CLI mode: если указана директория с YAML метаданными — проверим её
     if let Some(ref dir) = opt.metadata_dir {
-        check_metadata_dir(dir)?;
+        check_metadata_dir(dir, scan_options, opt.quiet, &mut std::io::stdout())?;
+        return Ok(());
+    }
+
+    if opt.json && !opt.info && !opt.keys {
+        return Err(CliError::Usage("--json requires --info or --keys".to_string()));
+    }
+
+    // CLI mode: только список ключей метаданных, без значений
+    if opt.keys {
+        let Some(input) = opt.input else {
+            return Err(CliError::Usage("--keys requires an input GGUF file".to_string()));
+        };
+        println!("{}", render_key_list(&metadata_keys(&input)?, opt.json));
+        return Ok(());
+    }
+
+    // CLI mode: краткий отчёт о модели вместо полного экспорта
+    if opt.info {
+        let Some(input) = opt.input else {
+            return Err(CliError::Usage("--info requires an input GGUF file".to_string()));
+        };
+        let report = inspector_gguf::format::build_model_report(&input)?;
+        if let Some(mismatch) = &report.count_mismatch {
+            eprintln!("Warning: {}", mismatch);
+        }
+        if opt.json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            print!("{}", report);
+        }
+        return Ok(());
+    }
+
+    // CLI mode: сравнение метаданных с эталонным YAML
+    if let Some(reference) = opt.compare_ref {
+        let Some(input) = opt.input else {
+            return Err(CliError::Usage("--compare-ref requires an input GGUF file".to_string()));
+        };
+        let diffs = compare_to_reference(&input, &reference)?;
+        if diffs.is_empty() {
+            if !opt.quiet {
+                println!("OK");
+            }
+            return Ok(());
+        }
+        for diff in &diffs {
+            println!("{}", diff);
+        }
+        return Err(CliError::Mismatch(format!(
+            "{} metadata difference(s) from {}",
+            diffs.len(),
+            reference.display()
+        )));
+    }
+
+    // CLI mode: восстановление tokenizer.json из метаданных токенизатора
+    if let Some(out) = opt.tokenizer_json {
+        let Some(input) = opt.input else {
+            return Err(CliError::Usage("--tokenizer-json requires an input GGUF file".to_string()));
+        };
+        // The reason is printed by reconstruct_tokenizer_json
+        let Some(json) = inspector_gguf::format::reconstruct_tokenizer_json(&input)? else {
+            return Err("tokenizer.json can only be rebuilt for BPE (gpt2) tokenizers".into());
+        };
+        let out = inspector_gguf::gui::export::ensure_extension(&out, "json");
+        inspector_gguf::gui::export::write_export(&out, json.as_bytes())?;
+        if !opt.quiet {
+            println!("OK");
+        }
+        return Ok(());
+    }
+
+    // CLI mode: пакетный экспорт метаданных в SQLite
+    if let Some(db) = opt.sqlite {
+        let Some(input) = opt.input else {
+            return Err(CliError::Usage("--sqlite requires an input GGUF file or directory".to_string()));
+        };
+        let exported = export_to_sqlite(&input, &db)?;
+        if !opt.quiet {
+            for path in &exported {
+                println!("Exported {}", path.display());
+            }
+            println!("OK");
+        }
+        return Ok(());
+    }
+
+    // CLI mode: GGUF piped through stdin (pipes cannot seek, so it is read whole)
+    if opt.stdin {
+        let (export, mismatch) = inspector_gguf::cli::export_json_from_reader(std::io::stdin().lock())?;
+        if let Some(mismatch) = &mismatch {
+            eprintln!("Warning: {}", mismatch);
+        }
+        let json = serde_json::to_string_pretty(&export)?;
+        match opt.output {
+            Some(path) => {
+                let path = inspector_gguf::gui::export::ensure_extension(&path, "json");
+                inspector_gguf::gui::export::write_export(&path, json.as_bytes())?;
+                if !opt.quiet {
+                    println!("OK");
+                }
+            }
+            None => println!("{}", json),
+        }
         return Ok(());
     }
 
     // CLI mode: fallback to previous behavior if input provided
     if let Some(input) = opt.input {
-        // Use our improved metadata loading function
-        let metadata = inspector_gguf::format::load_gguf_metadata_with_full_content_sync(&input)?;
-
-        let mut map = serde_json::Map::new();
-        let mut keys = Vec::new();
-
-        for (k, v, _) in &metadata {
-            keys.push(k.clone());
-            // Try to parse as JSON, fallback to string
-            if let Ok(json) = serde_json::from_str::<serde_json::Value>(v) {
-                map.insert(k.clone(), json);
-            } else {
-                map.insert(k.clone(), serde_json::Value::String(v.clone()));
+        // URL: скачиваем только начало файла с метаданными
+        let url = input.to_str().filter(|input| is_http_url(input));
+        let metadata = match url {
+            Some(url) => load_url_metadata(url)?,
+            None => {
+                let buf = std::fs::read(&input)?;
+                let (metadata, mismatch) = inspector_gguf::format::load_gguf_metadata_from_bytes_checked(&buf)?;
+                if let Some(mismatch) = &mismatch {
+                    eprintln!("Warning: {}", mismatch);
+                }
+                metadata
             }
-        }
+        };
 
-        let exported = serde_json::json!({"keys": keys, "raw": serde_json::Value::Object(map)});
-        let out_path = match opt.output {
-            Some(p) => p,
-            None => input.with_extension("gguf.metadata.json"),
+        let out_path = match (opt.output, url) {
+            (Some(p), _) => p,
+            (None, Some(url)) => url_output_path(url),
+            (None, None) => input.with_extension("gguf.metadata.json"),
         };
-        std::fs::write(out_path, serde_json::to_string_pretty(&exported)?)?;
-        println!("OK");
+        let metadata_refs: Vec<_> = metadata.iter().map(|(k, v, _)| (k, v)).collect();
+        let metadata_refs = if opt.metadata_key.is_empty() {
+            metadata_refs
+        } else {
+            // Отсутствующие ключи — предупреждение, с --strict — ошибка
+            let (selected, missing) = select_metadata_keys(&metadata_refs, &opt.metadata_key);
+            report_missing_keys(&missing, opt.strict)?;
+            selected
+        };
+        // Пути с суффиксом .gz/.zst сжимаются автоматически
+        inspector_gguf::gui::export::export_json(&metadata_refs, &out_path)?;
+        if !opt.quiet {
+            println!("OK");
+        }
     } else {
-        eprintln!("No input provided. Use --gui to run the GUI or provide input path for CLI.");
+        return Err(CliError::Usage(
+            "No input provided. Use --gui to run the GUI or provide input path for CLI.".to_string(),
+        ));
     }
     Ok(())
 }
 
-fn check_metadata_dir(dir: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
-    if !dir.is_dir() {
-        return Err(format!("Not a directory: {}", dir.display()).into());
-    }
-    for entry in std::fs::read_dir(dir)? {
-        let ent = entry?;
-        let path = ent.path();
-        if let Some(ext) = path.extension().and_then(|s| s.to_str())
-            && (ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"))
-        {
-            println!("Checking {}...", path.display());
-            let bytes = std::fs::read(&path)?;
-            let yaml: serde_yaml::Value = serde_yaml::from_slice(&bytes)?;
-            // Flatten to map of strings for basic validation
-            if let Some(map) = yaml.as_mapping() {
-                let mut has_tokenizer = false;
-                let mut has_config = false;
-                for (k, _v) in map.iter() {
-                    if let Some(kstr) = k.as_str() {
-                        if kstr.contains("tokenizer") {
-                            has_tokenizer = true;
-                        }
-                        if kstr.contains("config") {
-                            has_config = true;
-                        }
-                    }
-                }
-                println!(
-                    "  keys: {} entries, tokenizer_in_metadata={}, config_in_metadata={}",
-                    map.len(),
-                    has_tokenizer,
-                    has_config
-                );
-            } else {
-                println!("  not a mapping — skipping");
-            }
+#[allow(clippy::type_complexity)]
+#[cfg(feature = "http")]
+fn load_url_metadata(url: &str) -> Result<Vec<(String, String, Option<String>)>, CliError> {
+    Ok(inspector_gguf::format::load_gguf_metadata_from_url(url)?)
+}
+
+#[allow(clippy::type_complexity)]
+#[cfg(not(feature = "http"))]
+fn load_url_metadata(_url: &str) -> Result<Vec<(String, String, Option<String>)>, CliError> {
+    Err(CliError::Usage("URL inputs require building with the `http` feature".to_string()))
+}
+
+fn check_metadata_dir(dir: &PathBuf, options: ScanOptions, quiet: bool, out: &mut dyn Write) -> Result<(), CliError> {
+    // --quiet leaves only the exit status and the final error
+    let mut sink = std::io::sink();
+    let out: &mut dyn Write = if quiet { &mut sink } else { out };
+    for result in validate_metadata_dir_with_options(dir, options)? {
+        writeln!(out, "Checking {}...", result.path.display())?;
+        match result.error {
+            Some(error) => writeln!(out, "  {} — skipping", error)?,
+            None => writeln!(
+                out,
+                "  keys: {} entries, tokenizer_in_metadata={}, config_in_metadata={}",
+                result.key_count, result.has_tokenizer, result.has_config
+            )?,
         }
     }
     Ok(())
 }
 
-fn check_gguf_dir(dir: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+fn check_gguf_dir(dir: &PathBuf, options: ScanOptions, quiet: bool, out: &mut dyn Write) -> Result<(), CliError> {
     if !dir.is_dir() {
         return Err(format!("Not a directory: {}", dir.display()).into());
     }
-    for entry in std::fs::read_dir(dir)? {
-        let ent = entry?;
-        let path = ent.path();
-        if path
-            .extension()
-            .and_then(|s| s.to_str())
-            .map(|s| s.eq_ignore_ascii_case("gguf"))
-            .unwrap_or(false)
-        {
-            println!("Reading {}...", path.display());
-            let mut f = std::fs::File::open(&path)?;
-            use std::io::Read;
-            let mut buf = Vec::new();
-            f.read_to_end(&mut buf)?;
-            let mut cursor = std::io::Cursor::new(&buf);
-            let content = candle::quantized::gguf_file::Content::read(&mut cursor)?;
-            let keys: Vec<String> = content.metadata.keys().cloned().collect();
-            let _joined = keys.join(", ");
-            // Проверяем наличие токенизатора/конфига в метаданных
-            let has_tokenizer = keys.iter().any(|k| {
-                k.contains("tokenizer")
-                    || k.contains("tokenizer.json")
-                    || k.contains("tokenizer.ggml")
-            });
-            let has_config = keys.iter().any(|k| {
-                k.contains("config")
-                    || k.contains("config.json")
-                    || k.contains("general.config_json")
-            });
-            println!(
-                "  keys_count={}, tokenizer_in_metadata={}, config_in_metadata={}",
-                keys.len(),
-                has_tokenizer,
-                has_config
-            );
-            if !has_tokenizer {
-                println!(
-                    "  WARNING: tokenizer not found in GGUF metadata for {}",
-                    path.display()
-                );
-            }
-            if !has_config {
-                println!(
-                    "  WARNING: config not found in GGUF metadata for {}",
-                    path.display()
-                );
+    // --quiet leaves only the exit status and the final error
+    let mut sink = std::io::sink();
+    let out: &mut dyn Write = if quiet { &mut sink } else { out };
+    for path in find_files(dir, &["gguf"], options)? {
+        writeln!(out, "Reading {}...", path.display())?;
+        // One unreadable or malformed file is reported and does not stop the scan
+        let keys = match read_gguf_metadata_keys(&path) {
+            Ok(keys) => keys,
+            Err(e) => {
+                writeln!(out, "  WARNING: skipping {}: {}", path.display(), e)?;
+                continue;
             }
+        };
+        let _joined = keys.join(", ");
+        // Проверяем наличие токенизатора/конфига в метаданных
+        let has_tokenizer = keys.iter().any(|k| {
+            k.contains("tokenizer")
+                || k.contains("tokenizer.json")
+                || k.contains("tokenizer.ggml")
+        });
+        let has_config = keys.iter().any(|k| {
+            k.contains("config")
+                || k.contains("config.json")
+                || k.contains("general.config_json")
+        });
+        writeln!(
+            out,
+            "  keys_count={}, tokenizer_in_metadata={}, config_in_metadata={}",
+            keys.len(),
+            has_tokenizer,
+            has_config
+        )?;
+        if !has_tokenizer {
+            writeln!(
+                out,
+                "  WARNING: tokenizer not found in GGUF metadata for {}",
+                path.display()
+            )?;
+        }
+        if !has_config {
+            writeln!(
+                out,
+                "  WARNING: config not found in GGUF metadata for {}",
+                path.display()
+            )?;
         }
     }
     Ok(())
 }
+
+/// Reads the metadata key names of one GGUF file for [`check_gguf_dir`].
+fn read_gguf_metadata_keys(path: &Path) -> Result<Vec<String>, LoadError> {
+    let buf = std::fs::read(path).map_err(|e| LoadError::Io(e.to_string()))?;
+    inspector_gguf::format::check_gguf_size(buf.len() as u64)?;
+    let mut cursor = std::io::Cursor::new(&buf);
+    let content = candle::quantized::gguf_file::Content::read(&mut cursor)
+        .map_err(|e| LoadError::Parse(e.to_string()))?;
+    Ok(content.metadata.keys().cloned().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Header of a GGUF v3 file with no tensors and no metadata.
+    fn empty_gguf() -> Vec<u8> {
+        let mut buf = b"GGUF".to_vec();
+        buf.extend_from_slice(&3u32.to_le_bytes());
+        buf.extend_from_slice(&0u64.to_le_bytes());
+        buf.extend_from_slice(&0u64.to_le_bytes());
+        buf
+    }
+
+    #[test]
+    fn test_quiet_gguf_dir_check_prints_nothing() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        std::fs::write(dir.path().join("model.gguf"), empty_gguf()).unwrap();
+        std::fs::write(dir.path().join("empty.gguf"), b"").unwrap();
+        std::fs::write(dir.path().join("broken.gguf"), b"GGUF definitely not a valid header").unwrap();
+        let dir = dir.path().to_path_buf();
+
+        let mut loud = Vec::new();
+        check_gguf_dir(&dir, ScanOptions::default(), false, &mut loud).expect("Scan should succeed");
+        let loud = String::from_utf8(loud).unwrap();
+        assert!(loud.contains("Reading"), "{}", loud);
+        assert!(loud.contains("WARNING: skipping"), "{}", loud);
+        assert!(loud.contains("WARNING: tokenizer not found"), "{}", loud);
+        // The malformed file does not abort the scan of the others
+        assert_eq!(loud.matches("Reading").count(), 3, "{}", loud);
+        assert_eq!(loud.matches("WARNING: skipping").count(), 2, "{}", loud);
+
+        let mut quiet = Vec::new();
+        check_gguf_dir(&dir, ScanOptions::default(), true, &mut quiet).expect("Scan should succeed");
+        assert!(quiet.is_empty(), "{}", String::from_utf8_lossy(&quiet));
+    }
+
+    #[test]
+    fn test_quiet_metadata_dir_check_prints_nothing() {
+        let dir = tempfile::tempdir().expect("Should create temp dir");
+        std::fs::write(dir.path().join("good.yaml"), "general.name: tiny
+").unwrap();
+        std::fs::write(dir.path().join("bad.yaml"), "- not a mapping
+").unwrap();
+        let dir = dir.path().to_path_buf();
+
+        let mut quiet = Vec::new();
+        check_metadata_dir(&dir, ScanOptions::default(), true, &mut quiet).expect("Check should succeed");
+        assert!(quiet.is_empty(), "{}", String::from_utf8_lossy(&quiet));
+
+        // Failures still surface as the error and exit status
+        let missing = dir.join("missing");
+        assert!(check_metadata_dir(&missing, ScanOptions::default(), true, &mut quiet).is_err());
+        assert!(quiet.is_empty());
+    }
+}